@@ -108,6 +108,10 @@ export async function transformAll(
     transformedCodeToDisplay: string;
     css: string;
   }[] = [];
+  // Cross-file references between the files are resolved by yak-swc
+  const files = Object.fromEntries(
+    otherFiles.map(({ name, content }) => [name, content]),
+  );
   for (const file of otherFiles) {
     const { name, content } = file;
 
@@ -116,6 +120,7 @@ export async function transformAll(
         transformCode,
         name + ".tsx",
         content,
+        files,
         options,
       );
 
@@ -139,6 +144,7 @@ export async function transformAll(
       transformCode,
       mainFileName + ".tsx",
       mainFileCodeString,
+      files,
       options,
     );
 
@@ -190,6 +196,7 @@ async function transform(
   transformCode: typeof WasmTransform,
   fileName: string,
   codeString: string,
+  files: Record<string, string>,
   options?: {
     minify?: boolean;
     showComments?: boolean;
@@ -215,6 +222,7 @@ async function transform(
     },
     {
       minify: options?.minify ?? false, // minify the class names and don't add display names
+      files,
     },
   ).code;
 
@@ -235,6 +243,7 @@ async function transform(
     },
     {
       minify: options?.minify ?? false, // minify the class names and don't add display names
+      files,
     },
   ).code;
 
@@ -3,13 +3,17 @@ mod types;
 use either::{Left, Right};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::rc::Rc;
 use swc_core::binding_macros::wasm;
 use swc_core::binding_macros::wasm::{SingleThreadedComments, compiler};
-use swc_core::common::{FileName, SourceFile};
+use swc_core::common::{FileName, SourceFile, SourceMap, sync::Lrc};
+use swc_core::ecma::parser::{Syntax, TsSyntax, parse_file_as_module};
 use swc_core::ecma::visit::swc_ecma_ast::*;
 use swc_core::ecma::visit::{VisitMutWith, visit_mut_pass};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
+use yak_swc::module_graph::{ModuleGraphProvider, ProvidedModule};
 use yak_swc::yak_file::{YakFileVisitor, is_yak_file};
 
 // Copied from build_transform_sync so that we can pass the same comments structure to
@@ -88,25 +92,63 @@ fn yak_pass(
     yak_opts: Option<YakConfig>,
 ) -> impl Pass + use<> {
     let file_name = file_name.unwrap_or("anon.ts".into()).to_string();
-    let config = yak_opts.unwrap_or_default();
+    let mut config = yak_opts.unwrap_or_default();
+    let playground_files = Rc::new(PlaygroundFiles(config.files.take().unwrap_or_default()));
 
     fn_pass(move |program: &mut Program| {
-        let mut transformer = yak_swc::TransformVisitor::new(
+        let transformer = yak_swc::TransformVisitor::new(
             Some(comments.clone()),
             &file_name,
-            config.minify.unwrap_or_default(),
-            None,
-            config.minify.unwrap_or_default(),
-            config
-                .transpilation_mode
-                .clone()
-                .unwrap_or(TranspilationMode::Css)
-                .into(),
+            yak_swc::Config {
+                minify: config.minify.unwrap_or_default(),
+                display_names: config.minify.unwrap_or_default(),
+                transpilation_mode: config
+                    .transpilation_mode
+                    .clone()
+                    .unwrap_or(TranspilationMode::Css)
+                    .into(),
+                ..Default::default()
+            },
         );
+        let mut transformer = transformer.with_module_graph(playground_files.clone());
         program.visit_mut_with(&mut transformer);
     })
 }
 
+/// The other files of the playground, which resolve cross-file references
+/// at compile time instead of the mocked loader
+/// e.g. `import { colors } from "./theme"` -> the file "theme"
+struct PlaygroundFiles(HashMap<String, String>);
+
+impl ModuleGraphProvider for PlaygroundFiles {
+    fn load_module(&self, _importer: &str, import_source: &str) -> Option<ProvidedModule> {
+        let name = import_source.strip_prefix("./")?;
+        let name = name.strip_suffix(".tsx").unwrap_or(name);
+        let content = self.0.get(name)?;
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+            Lrc::new(FileName::Custom(import_source.into())),
+            content.clone(),
+        );
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(TsSyntax {
+                tsx: true,
+                ..Default::default()
+            }),
+            EsVersion::latest(),
+            None,
+            &mut vec![],
+        )
+        .ok()?;
+        Some(ProvidedModule {
+            // Same file name as the playground uses to transform the file itself
+            filename: format!("{name}.tsx"),
+            module,
+        })
+    }
+}
+
 fn real_file_name(file: &SourceFile) -> Option<Cow<str>> {
     match &*file.name {
         FileName::Real(path) => path.file_name().map(|s| s.to_string_lossy()),
@@ -149,6 +191,10 @@ pub struct YakConfig {
     minify: Option<bool>,
     #[tsify(optional)]
     transpilation_mode: Option<TranspilationMode>,
+    /// The other files of the playground by name which can be imported by the transformed file
+    /// e.g. `{ theme: "export const colors = { primary: 'red' };" }`
+    #[tsify(optional)]
+    files: Option<HashMap<String, String>>,
 }
 
 impl Default for YakConfig {
@@ -156,6 +202,7 @@ impl Default for YakConfig {
         Self {
             minify: Default::default(),
             transpilation_mode: Some(TranspilationMode::Css),
+            files: None,
         }
     }
 }
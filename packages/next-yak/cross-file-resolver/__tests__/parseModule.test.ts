@@ -135,6 +135,54 @@ color: blue;
   assert.deepEqual(parsedModule.styledComponents, {});
 });
 
+test("parse regular module with dynamic mixins", async () => {
+  const path = "/path/to/module.js";
+
+  const moduleExports: ModuleExports = {
+    importYak: true,
+    named: {},
+    all: [],
+  };
+
+  const transformed = {
+    code: `
+      export const highlight =
+        /*YAK EXPORTED MIXIN:highlight
+color: black;
+*/ /*YAK DYNAMIC MIXIN:highlight*/ /*YAK Extracted CSS:
+.highlight__$active {
+  color: red;
+}
+*/ /*#__PURE__*/ css(({ $active }) => $active && /*#__PURE__*/ css("highlight__$active"));
+    `,
+    map: "map",
+  };
+
+  const parsedModule = await parseModule(
+    {
+      extractExports() {
+        return moduleExports;
+      },
+      getTransformed() {
+        return transformed;
+      },
+    },
+    path,
+  );
+
+  if (parsedModule.type !== "regular") {
+    assert.fail("module should be parsed as regular");
+  }
+  assert.deepEqual(parsedModule.mixins, {
+    highlight: {
+      nameParts: ["highlight"],
+      type: "mixin",
+      value: "color: black;\n",
+      isDynamic: true,
+    },
+  });
+});
+
 test("parse regular module with styled components", async () => {
   const path = "/path/to/module.js";
 
@@ -266,6 +314,52 @@ test("parse regular module with styled component in Css transpilation mode", asy
   });
 });
 
+test("parse regular module with styled component in Scoped transpilation mode", async () => {
+  const path = "/path/to/module.js";
+
+  const moduleExports: ModuleExports = {
+    importYak: true,
+    named: {},
+    all: [],
+  };
+
+  const transformed = {
+    code: `
+      export const simple =
+        /*YAK EXPORTED STYLED:simple:other_simple_aPIQqJ*/ /*YAK Extracted CSS:
+.other_simple_aPIQqJ:where([data-yak-scope], [data-yak-scope] *) {
+  color: red;
+}
+      */ /*#__PURE__*/ __yak.__yak_div("other_simple_aPIQqJ");
+    `,
+    map: "map",
+  };
+
+  const parsedModule = await parseModule(
+    {
+      transpilationMode: "Scoped",
+      extractExports() {
+        return moduleExports;
+      },
+      getTransformed() {
+        return transformed;
+      },
+    },
+    path,
+  );
+
+  if (parsedModule.type !== "regular") {
+    assert.fail("module should be parsed as regular");
+  }
+  assert.deepEqual(parsedModule.styledComponents, {
+    simple: {
+      nameParts: ["simple"],
+      type: "styled-component",
+      value: ".other_simple_aPIQqJ:where([data-yak-scope], [data-yak-scope] *)",
+    },
+  });
+});
+
 test("parse regular module with cache multiple times extract/transform once", async () => {
   const path = "/path/to/module.js";
 
@@ -391,6 +391,144 @@ test("Error: resolving non styled-component as a selector", async () => {
   Caused by: Found "mixin" but expected a selector - did you forget a semicolon after "Main"?`);
 });
 
+const dynamicMixinModules: Record<string, ParsedModule> = {
+  "/foo/mixins.js": {
+    path: "/foo/mixins.js",
+    type: "regular",
+    mixins: {
+      highlight: {
+        type: "mixin",
+        value: "color: black;",
+        nameParts: ["highlight"],
+        isDynamic: true,
+      },
+    },
+    exports: {
+      importYak: true,
+      named: {
+        highlight: { type: "tag-template" },
+      },
+      all: [],
+    },
+  },
+};
+
+test("resolve css with a dynamic mixin which is passed to the runtime", async () => {
+  const css = `--yak-css-import: url("./mixins.js:highlight",mixin);`;
+
+  const { resolved, dependencies } = await resolveCrossFileConstant(
+    {
+      parse(modulePath) {
+        return dynamicMixinModules[modulePath];
+      },
+      resolve(specifier, importer) {
+        return path.resolve(path.dirname(importer), specifier);
+      },
+    },
+    "/foo/bar.js",
+    css,
+  );
+
+  assert.strictEqual(resolved, "color: black;");
+  assert.deepEqual(dependencies, ["/foo/mixins.js"]);
+});
+
+test("Error: resolving a dynamic mixin which is not passed to the runtime", async () => {
+  const css = `&:hover { --yak-css-import: url("./mixins.js:highlight",static-mixin); }`;
+
+  await expect(() =>
+    resolveCrossFileConstant(
+      {
+        parse(modulePath) {
+          return dynamicMixinModules[modulePath];
+        },
+        resolve(specifier, importer) {
+          return path.resolve(path.dirname(importer), specifier);
+        },
+      },
+      "/foo/bar.js",
+      css,
+    ),
+  ).rejects
+    .toThrow(`Error while resolving cross-file selectors in file "/foo/bar.js"
+  Caused by: "highlight" is a dynamic mixin which needs the props of the importing component - it can't be used inside a selector, an at-rule or with zero runtime`);
+});
+
+test("resolve css with cross-file constant used as a value", async () => {
+  const css = `color: --yak-css-import: url("./constant.js:color",value);`;
+
+  const parsed: Record<string, ParsedModule> = {
+    "/foo/constant.js": {
+      path: "/foo/constant.js",
+      type: "regular",
+      exports: {
+        importYak: false,
+        named: {
+          color: { type: "constant", value: "red" },
+        },
+        all: [],
+      },
+    },
+  };
+
+  const { resolved } = await resolveCrossFileConstant(
+    {
+      parse(modulePath) {
+        return parsed[modulePath];
+      },
+      resolve(specifier, importer) {
+        return path.resolve(path.dirname(importer), specifier);
+      },
+    },
+    "/foo/bar.js",
+    css,
+  );
+
+  assert.strictEqual(resolved, "color: red;");
+});
+
+test("Error: resolving styled-component as a value", async () => {
+  const css = `color: --yak-css-import: url("./components.js:Main",value);`;
+
+  const parsed: Record<string, ParsedModule> = {
+    "/foo/components.js": {
+      path: "/foo/components.js",
+      type: "regular",
+      styledComponents: {
+        Main: {
+          type: "styled-component",
+          value: "className",
+          nameParts: ["Main"],
+        },
+      },
+      exports: {
+        importYak: false,
+        named: {
+          Main: { type: "tag-template" },
+        },
+        all: [],
+      },
+    },
+  };
+
+  await expect(() =>
+    resolveCrossFileConstant(
+      {
+        parse(modulePath) {
+          return parsed[modulePath];
+        },
+        resolve(specifier, importer) {
+          return path.resolve(path.dirname(importer), specifier);
+        },
+      },
+      "/foo/bar.js",
+      css,
+    ),
+  ).rejects
+    .toThrow(`Error while resolving cross-file selectors in file "/foo/bar.js"
+  Caused by: Found "styled-component" but expected a value - "Main" can only be used as a selector`);
+});
+
 test("Error: mismatching types between mixin and record export", async () => {
   const css = `--yak-css-import: url("./components.js:foo:bar",selector) {}`;
 
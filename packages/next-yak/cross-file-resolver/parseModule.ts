@@ -88,11 +88,16 @@ function parseMixins(sourceContents: string): Record<string, Mixin> {
   // /*YAK EXPORTED MIXIN:fancy:aspectRatio:16:9
   // css
   // */
+  // Mixins which need the props of the importing component are marked with
+  // /*YAK DYNAMIC MIXIN:fancy:aspectRatio:16:9*/
   const mixinParts = sourceContents.split("/*YAK EXPORTED MIXIN:");
-  let mixins: Record<
-    string,
-    { type: "mixin"; value: string; nameParts: string[] }
-  > = {};
+  const dynamicMixins = new Set(
+    sourceContents
+      .split("/*YAK DYNAMIC MIXIN:")
+      .slice(1)
+      .map((part) => part.split("*/", 1)[0]),
+  );
+  let mixins: Record<string, Mixin> = {};
 
   for (let i = 1; i < mixinParts.length; i++) {
     const [comment] = mixinParts[i].split("*/", 1);
@@ -104,13 +109,16 @@ function parseMixins(sourceContents: string): Record<string, Mixin> {
       value,
       nameParts: name.split(":").map((part) => decodeURIComponent(part)),
     };
+    if (dynamicMixins.has(name)) {
+      mixins[name].isDynamic = true;
+    }
   }
   return mixins;
 }
 
 function parseStyledComponents(
   sourceContents: string,
-  transpilationMode?: "Css" | "CssModule",
+  transpilationMode?: TranspilationMode,
 ): Record<string, StyledComponent> {
   // cross-file Styled Components are always in the following format:
   // /*YAK EXPORTED STYLED:ComponentName:ClassName*/
@@ -123,16 +131,30 @@ function parseStyledComponents(
     styledComponents[componentName] = {
       type: "styled-component",
       nameParts: componentName.split("."),
-      value:
-        transpilationMode === "Css"
-          ? `.${className}`
-          : `:global(.${className})`,
+      value: toClassSelector(className, transpilationMode),
     };
   }
 
   return styledComponents;
 }
 
+/**
+ * Mirrors `TranspilationMode::css_class_name` of the swc plugin
+ */
+function toClassSelector(
+  className: string,
+  transpilationMode?: TranspilationMode,
+) {
+  switch (transpilationMode) {
+    case "Css":
+      return `.${className}`;
+    case "Scoped":
+      return `.${className}:where([data-yak-scope], [data-yak-scope] *)`;
+    default:
+      return `:global(.${className})`;
+  }
+}
+
 function objectToModuleExport(object: object) {
   return Object.fromEntries(
     Object.entries(object).map(([key, value]): [string, ModuleExport] => {
@@ -150,9 +172,11 @@ function objectToModuleExport(object: object) {
   );
 }
 
+type TranspilationMode = "Css" | "CssModule" | "Atomic" | "Scoped";
+
 export type ParseContext = {
   cache?: { parse?: Cache<ParsedModule> };
-  transpilationMode?: "Css" | "CssModule";
+  transpilationMode?: TranspilationMode;
   evaluateYakModule?: (
     modulePath: string,
   ) => Promise<Record<string, unknown>> | Record<string, unknown>;
@@ -209,4 +233,10 @@ export type StyledComponent = {
   nameParts: string[];
 };
 
-export type Mixin = { type: "mixin"; value: string; nameParts: string[] };
+export type Mixin = {
+  type: "mixin";
+  value: string;
+  nameParts: string[];
+  /** The mixin needs the props of the importing component */
+  isDynamic?: boolean;
+};
@@ -9,8 +9,8 @@ import { Cache } from "./types.js";
 import { CauseError, CircularDependencyError, ResolveError } from "./Errors.js";
 
 const yakCssImportRegex =
-  // Make mixin and selector non optional once we dropped support for the babel plugin
-  /--yak-css-import\:\s*url\("([^"]+)",?(|mixin|selector)\)(;?)/g;
+  // Make mixin, selector and value non optional once we dropped support for the babel plugin
+  /--yak-css-import\:\s*url\("([^"]+)",?(|mixin|static-mixin|selector|value)\)(;?)/g;
 
 /**
  * Resolves cross-file selectors in css files
@@ -120,10 +120,10 @@ export async function uncachedResolveCrossFileConstant(
       if (resolved.type === "unresolved-tag") {
         // tag that could not be resolved to styled-components or mixins are
         // interpolated to produce valid CSS with minimal impact (since we don't
-        // know what the value should actually be). For mixins (CSS rules) we
-        // interpolate an empty string, and for selectors we interpolate
+        // know what the value should actually be). For mixins (CSS rules) and
+        // values we interpolate an empty string, and for selectors we interpolate
         // "undefined" (a selector that would match nothing)
-        replacement = importKind === "mixin" ? "" : "undefined";
+        replacement = importKind === "selector" ? "undefined" : "";
       } else {
         if (importKind === "selector") {
           if (
@@ -139,6 +139,29 @@ export async function uncachedResolveCrossFileConstant(
             );
           }
         }
+        if (
+          resolved.type === "mixin" &&
+          resolved.isDynamic &&
+          importKind === "static-mixin"
+        ) {
+          // Only mixins which are passed to the runtime receive the props of the importing component
+          throw new Error(
+            `"${specifier.join(
+              ".",
+            )}" is a dynamic mixin which needs the props of the importing component - it can't be used inside a selector, an at-rule or with zero runtime`,
+          );
+        }
+        if (importKind === "value") {
+          if (resolved.type === "styled-component") {
+            throw new Error(
+              `Found "${
+                resolved.type
+              }" but expected a value - "${specifier.join(
+                ".",
+              )}" can only be used as a selector`,
+            );
+          }
+        }
 
         replacement =
           resolved.type === "styled-component"
@@ -305,11 +328,16 @@ async function uncachedResolveModule(
           dependencies.add(dep);
         }
 
+        const mixinExport: ResolvedMixin = {
+          type: "mixin",
+          value: resolved,
+        };
+        if (mixin.isDynamic) {
+          mixinExport.isDynamic = true;
+        }
+
         if (mixin.nameParts.length === 1) {
-          exports.named[mixin.nameParts[0]] = {
-            type: "mixin",
-            value: resolved,
-          };
+          exports.named[mixin.nameParts[0]] = mixinExport;
         } else {
           let exportEntry = exports.named[mixin.nameParts[0]];
 
@@ -338,10 +366,7 @@ async function uncachedResolveModule(
             }
             current = next.value;
           }
-          current[mixin.nameParts[mixin.nameParts.length - 1]] = {
-            type: "mixin",
-            value: resolved,
-          };
+          current[mixin.nameParts[mixin.nameParts.length - 1]] = mixinExport;
         }
       }),
     );
@@ -549,6 +574,7 @@ async function resolveModuleExport(
           from: [filePath],
           source: filePath,
           value: moduleExport.value,
+          isDynamic: moduleExport.isDynamic,
         };
       }
     }
@@ -638,7 +664,13 @@ type ResolvedCssImport =
       from: string[];
       name: string;
     }
-  | { type: "mixin"; source: string; from: string[]; value: string | number }
+  | {
+      type: "mixin";
+      source: string;
+      from: string[];
+      value: string | number;
+      isDynamic?: boolean;
+    }
   | {
       type: "constant";
       source: string;
@@ -660,7 +692,7 @@ export type ResolveContext = {
   resolve: (specifier: string, importer: string) => Promise<string> | string;
 };
 
-type YakImportKind = "mixin" | "selector";
+type YakImportKind = "mixin" | "static-mixin" | "selector" | "value";
 
 type YakCssImport = {
   encodedArguments: string;
@@ -677,7 +709,12 @@ export type ExtendedRecordExport = {
   value: Record<string, ResolvedExport>;
 };
 
-export type ResolvedMixin = { type: "mixin"; value: string };
+export type ResolvedMixin = {
+  type: "mixin";
+  value: string;
+  /** The mixin needs the props of the importing component */
+  isDynamic?: boolean;
+};
 export type ResolvedStyledComponent = {
   type: "styled-component";
   className: string;
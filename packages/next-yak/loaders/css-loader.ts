@@ -2,6 +2,13 @@ import { relative } from "path";
 import type { LoaderContext } from "webpack";
 import type { YakConfigOptions } from "../withYak/index.js";
 import { resolveCrossFileConstant } from "./lib/resolveCrossFileSelectors.js";
+import { NameManifest, parseGeneratedNames } from "./lib/uniqueNames.js";
+
+/**
+ * Generated names of all files of a compiler (only for `checkUniqueNames`)
+ * Kept across compilations as watch mode only transforms changed files again
+ */
+const nameManifests = new WeakMap<object, NameManifest>();
 
 /**
  * Transform typescript to css
@@ -27,10 +34,13 @@ export default async function cssExtractLoader(
         new Error(`Source code for ${this.resourcePath} is empty`),
       );
     }
-    const { experiments } = this.getOptions();
+    const { experiments, checkUniqueNames } = this.getOptions();
     const debugLog = createDebugLogger(this, experiments?.debug);
 
     debugLog("ts", source);
+    if (checkUniqueNames) {
+      reportNameCollisions(this, source);
+    }
     const css = extractCss(source, experiments?.transpilationMode);
     debugLog("css", css);
 
@@ -41,6 +51,32 @@ export default async function cssExtractLoader(
   });
 }
 
+/**
+ * Merges the generated names of this file with the names of all other files
+ * and reports names which are generated by more than one file
+ */
+function reportNameCollisions(
+  loaderContext: LoaderContext<YakConfigOptions>,
+  code: string | Buffer<ArrayBufferLike>,
+) {
+  const compiler = loaderContext._compiler;
+  if (!compiler) {
+    return;
+  }
+  let nameManifest = nameManifests.get(compiler);
+  if (!nameManifest) {
+    nameManifest = new NameManifest();
+    nameManifests.set(compiler, nameManifest);
+  }
+  const collisions = nameManifest.register(
+    relative(compiler.context, loaderContext.resourcePath),
+    parseGeneratedNames(code.toString()),
+  );
+  for (const collision of collisions) {
+    loaderContext.emitError(new Error(collision));
+  }
+}
+
 function extractCss(
   code: string | Buffer<ArrayBufferLike>,
   transpilationMode: NonNullable<
@@ -67,7 +103,12 @@ function extractCss(
     const codeUntilEnd = codeParts[i].split("*/")[0];
     result += codeUntilEnd;
   }
-  if (result && transpilationMode !== "Css") {
+  // Css and Scoped output plain global css instead of css modules
+  if (
+    result &&
+    transpilationMode !== "Css" &&
+    transpilationMode !== "Scoped"
+  ) {
     result = "/* cssmodules-pure-no-check */\n" + result;
   }
 
@@ -0,0 +1,78 @@
+import { test, assert } from "vitest";
+import { NameManifest, parseGeneratedNames } from "../uniqueNames.js";
+
+test("parse the generated names of a transformed file", () => {
+  const code = `
+const fadeIn = /*YAK GENERATED NAMES:[{"column":16,"line":3,"name":"ym7uBBu","readableName":"fadeIn"}]*/ /*YAK Extracted CSS:
+@keyframes :global(ym7uBBu) {}
+*/ keyframes("ym7uBBu");
+export const Button = /*YAK GENERATED NAMES:[{"column":23,"line":9,"name":"ym7uBBu1","readableName":"Button"}]*/ __yak.__yak_button("ym7uBBu1");
+`;
+
+  assert.deepEqual(parseGeneratedNames(code), [
+    { column: 16, line: 3, name: "ym7uBBu", readableName: "fadeIn" },
+    { column: 23, line: 9, name: "ym7uBBu1", readableName: "Button" },
+  ]);
+});
+
+test("report names which collide with names of other files", () => {
+  const manifest = new NameManifest();
+
+  assert.deepEqual(
+    manifest.register("src/a.tsx", [
+      { name: "ya", readableName: "Button", line: 3, column: 16 },
+    ]),
+    [],
+  );
+  assert.deepEqual(
+    manifest.register("src/b.tsx", [
+      { name: "yb", readableName: "Title", line: 1, column: 1 },
+    ]),
+    [],
+  );
+  assert.deepEqual(
+    manifest.register("src/c.tsx", [
+      { name: "ya", readableName: "Card", line: 9, column: 23 },
+    ]),
+    [
+      `The generated name "ya" for "Card" (src/c.tsx:9:23) collides with "Button" (src/a.tsx:3:16) - change one of the names or use a different prefix or classNameTemplate`,
+    ],
+  );
+});
+
+test("register a file again after a change", () => {
+  const manifest = new NameManifest();
+
+  manifest.register("src/a.tsx", [{ name: "ya", readableName: "Button" }]);
+  // The same file keeps its names
+  assert.deepEqual(
+    manifest.register("src/a.tsx", [{ name: "ya", readableName: "Button" }]),
+    [],
+  );
+  // Names which were removed from a file don't collide anymore
+  manifest.register("src/a.tsx", [{ name: "yb", readableName: "Button" }]);
+  assert.deepEqual(
+    manifest.register("src/b.tsx", [{ name: "ya", readableName: "Card" }]),
+    [],
+  );
+});
+
+test("identical content of different files does not collide", () => {
+  const manifest = new NameManifest();
+
+  manifest.register("src/a.tsx", [
+    { name: "ya", readableName: "Button", content: "hash1" },
+  ]);
+  assert.deepEqual(
+    manifest.register("src/b.tsx", [
+      { name: "ya", readableName: "Button", content: "hash1" },
+    ]),
+    [],
+  );
+  assert.strictEqual(
+    manifest.register("src/c.tsx", [
+      { name: "ya", readableName: "Button", content: "hash2" },
+    ]).length,
+    2,
+  );
+});
@@ -0,0 +1,97 @@
+/**
+ * A class name, keyframe name or css variable name generated by yak-swc
+ *
+ * Listed per css expression with the `checkUniqueNames` option e.g.:
+ * ```
+ * /*YAK GENERATED NAMES:[{"name":"ym7uBBu","readableName":"Button","line":3,"column":16}]*\/
+ * ```
+ */
+export type GeneratedName = {
+  name: string;
+  readableName: string;
+  line?: number;
+  column?: number;
+  /** Hash of the css in the `ContentHash` naming mode */
+  content?: string;
+};
+
+/**
+ * Reads all generated names of a file transformed by yak-swc
+ */
+export function parseGeneratedNames(code: string): GeneratedName[] {
+  const parts = code.split("/*YAK GENERATED NAMES:");
+  const generatedNames: GeneratedName[] = [];
+  for (let i = 1; i < parts.length; i++) {
+    const [manifest] = parts[i].split("*/", 1);
+    generatedNames.push(...(JSON.parse(manifest) as GeneratedName[]));
+  }
+  return generatedNames;
+}
+
+/**
+ * Merges the generated names of all files and finds names
+ * which are generated by more than one file
+ *
+ * Files can be registered again e.g. after a change in watch mode
+ */
+export class NameManifest {
+  private namesByFile = new Map<string, GeneratedName[]>();
+  private filesByName = new Map<string, Set<string>>();
+
+  /**
+   * Registers the names of a file and returns a message for every
+   * name which collides with a name of another file
+   */
+  register(filePath: string, generatedNames: GeneratedName[]): string[] {
+    for (const { name } of this.namesByFile.get(filePath) ?? []) {
+      this.filesByName.get(name)?.delete(filePath);
+    }
+    this.namesByFile.set(filePath, generatedNames);
+
+    const collisions: string[] = [];
+    for (const generatedName of generatedNames) {
+      let files = this.filesByName.get(generatedName.name);
+      if (!files) {
+        files = new Set();
+        this.filesByName.set(generatedName.name, files);
+      }
+      for (const otherFile of files) {
+        if (otherFile === filePath) {
+          continue;
+        }
+        const existing = this.namesByFile
+          .get(otherFile)!
+          .find(({ name }) => name === generatedName.name)!;
+        // Identical css of different files generates the same name on purpose
+        if (
+          generatedName.content !== undefined &&
+          generatedName.content === existing.content
+        ) {
+          continue;
+        }
+        collisions.push(
+          `The generated name "${generatedName.name}" for ${formatLocation(
+            filePath,
+            generatedName,
+          )} collides with ${formatLocation(
+            otherFile,
+            existing,
+          )} - change one of the names or use a different prefix or classNameTemplate`,
+        );
+      }
+      files.add(filePath);
+    }
+    return collisions;
+  }
+}
+
+/**
+ * e.g. `"Button" (src/Button.tsx:3:16)`
+ */
+function formatLocation(filePath: string, generatedName: GeneratedName) {
+  const position =
+    generatedName.line === undefined
+      ? ""
+      : `:${generatedName.line}:${generatedName.column}`;
+  return `"${generatedName.readableName}" (${filePath}${position})`;
+}
@@ -1,6 +1,8 @@
 // @ts-nocheck These are runtime tests and the external API isn't the runtime (after compile) API
 import type { YakTheme } from "../context";
 import { css } from "../cssLiteral";
+import { staticFallback } from "../internals/staticFallback";
+import { cssString, cssUrl } from "../internals/cssString";
 
 describe("cssLiteral css function", () => {
   describe("static CSS class names", () => {
@@ -293,6 +295,44 @@ describe("cssLiteral css function", () => {
       expect(style["--undefined-var"]).toBe("undefined");
       expect(style["--empty-var"]).toBe("");
     });
+
+    it("should reset variables with a static fallback if there is no value", () => {
+      const classNames = new Set<string>();
+      const style: React.CSSProperties = {};
+      const props = { theme: {}, $color: "blue" };
+
+      const styleObj = {
+        style: {
+          "--set-var": staticFallback((props) => props.$color),
+          "--missing-var": staticFallback((props) => props.$background),
+        },
+      };
+
+      const processor = css("class", styleObj);
+      processor(props, classNames, style);
+
+      expect(style["--set-var"]).toBe("blue");
+      expect(style["--missing-var"]).toBe("initial");
+    });
+
+    it("should quote and escape runtime values inside of url() and strings", () => {
+      const classNames = new Set<string>();
+      const style: React.CSSProperties = {};
+      const props = { theme: {}, $name: 'a"b', $label: "x\\y" };
+
+      const styleObj = {
+        style: {
+          "--url-var": cssUrl((props) => props.$name, "/icons/", ".svg"),
+          "--string-var": cssString((props) => props.$label, "Label: ", ""),
+        },
+      };
+
+      const processor = css("class", styleObj);
+      processor(props, classNames, style);
+
+      expect(style["--url-var"]).toBe('url("/icons/a\\"b.svg")');
+      expect(style["--string-var"]).toBe('"Label: x\\\\y"');
+    });
   });
 
   describe("error conditions", () => {
@@ -496,4 +536,35 @@ describe("cssLiteral css function", () => {
       expect(style["--dynamic2"]).toBe("dynamic2");
     });
   });
+
+  describe("cross-file mixins", () => {
+    it("should call imported dynamic mixins with the props", () => {
+      const classNames = new Set<string>();
+      const style: React.CSSProperties = {};
+      const props = { $active: true, $size: 20 };
+
+      // e.g. export const buttonStyles = css`${({$active}) => $active && css`...`}`
+      const importedMixin = css(
+        ({ $active }) => $active && css("mixin-active"),
+        { style: { "--mixin-size": ({ $size }) => `${$size}px` } },
+      );
+      // e.g. styled.button`${buttonStyles};`
+      const processor = css("component", () => importedMixin);
+      processor(props, classNames, style);
+
+      expect(classNames).toEqual(new Set(["component", "mixin-active"]));
+      expect(style["--mixin-size"]).toBe("20px");
+    });
+
+    it("should ignore imported constants", () => {
+      const classNames = new Set<string>();
+      const style: React.CSSProperties = {};
+
+      const processor = css("component", () => "red", () => ({ __yak: "color: red;" }));
+      processor({}, classNames, style);
+
+      expect(classNames).toEqual(new Set(["component"]));
+      expect(style).toEqual({});
+    });
+  });
 });
@@ -1,5 +1,5 @@
 // @ts-nocheck
-import { it, expect } from "vitest";
+import { it, expect, describe, vi, afterEach } from "vitest";
 import { mergeCssProp } from "../internals/mergeCssProp";
 import { css } from "../cssLiteral";
 import { atoms } from "../atoms";
@@ -313,3 +313,49 @@ it("merge properties with dark mode and state variants", async () => {
     },
   });
 });
+
+describe("dev mode - component check", () => {
+  const originalNodeEnv = process.env.NODE_ENV;
+
+  afterEach(() => {
+    process.env.NODE_ENV = originalNodeEnv;
+    vi.useRealTimers();
+    vi.restoreAllMocks();
+    document.body.innerHTML = "";
+  });
+
+  it("warns if a component does not forward the css prop className", () => {
+    process.env.NODE_ENV = "development";
+    vi.useFakeTimers();
+    const warn = vi.spyOn(console, "warn").mockImplementation(() => {});
+    mergeCssProp({}, css("yakDroppedClass"), "DroppingComponent");
+    vi.runAllTimers();
+    expect(warn).toHaveBeenCalledWith(
+      expect.stringContaining("<DroppingComponent />"),
+    );
+  });
+
+  it("does not warn if the className was forwarded", () => {
+    process.env.NODE_ENV = "development";
+    vi.useFakeTimers();
+    const warn = vi.spyOn(console, "warn").mockImplementation(() => {});
+    document.body.innerHTML = `<div class="yakForwardedClass"></div>`;
+    mergeCssProp({}, css("yakForwardedClass"), "ForwardingComponent");
+    vi.runAllTimers();
+    expect(warn).not.toHaveBeenCalled();
+  });
+
+  it("checks every component only once", () => {
+    process.env.NODE_ENV = "development";
+    vi.useFakeTimers();
+    const getElementsByClassName = vi.spyOn(
+      document,
+      "getElementsByClassName",
+    );
+    document.body.innerHTML = `<div class="yakCheckedOnceClass"></div>`;
+    mergeCssProp({}, css("yakCheckedOnceClass"), "CheckedOnceComponent");
+    mergeCssProp({}, css("yakCheckedOnceClass"), "CheckedOnceComponent");
+    vi.runAllTimers();
+    expect(getElementsByClassName).toHaveBeenCalledTimes(1);
+  });
+});
@@ -120,6 +120,78 @@ it("should forward properties to the next yak component", () => {
   `);
 });
 
+it("should filter out consumed properties for native elements", () => {
+  const Component = styled.input(
+    "cssClass",
+    { style: { "--tone": ({ tone }) => tone } },
+    { consumedProps: ["tone"] },
+  );
+
+  const { container } = render(<Component tone="primary" title="kept" />);
+
+  expect(container).toMatchInlineSnapshot(`
+    <div>
+      <input
+        class="cssClass"
+        style="--tone: primary;"
+        title="kept"
+      />
+    </div>
+  `);
+});
+
+it("should forward consumed properties to custom elements", () => {
+  const Component = styled("my-widget")(
+    "cssClass",
+    { style: { "--tone": ({ tone }) => tone } },
+    { consumedProps: ["tone"] },
+  );
+
+  const { container } = render(<Component tone="primary" />);
+
+  expect(container).toMatchInlineSnapshot(`
+    <div>
+      <my-widget
+        class="cssClass"
+        style="--tone: primary;"
+        tone="primary"
+      />
+    </div>
+  `);
+});
+
+it("should forward consumed properties to custom components", () => {
+  let forwardedProps = null;
+  const Component = ({ className, style, ...props }) => {
+    forwardedProps = props;
+    return null;
+  };
+  const StyledComponent = styled(Component)(
+    ({ tone }) => tone && css("toned"),
+    { consumedProps: ["tone"] },
+  );
+  render(<StyledComponent tone="primary" />);
+
+  expect(forwardedProps).toEqual({ tone: "primary" });
+});
+
+it("should filter out consumed properties of wrapping yak components", () => {
+  const Component = styled.input``;
+  const StyledComponent = styled(Component)(
+    ({ tone }) => tone && css("toned"),
+    { consumedProps: ["tone"] },
+  );
+  const { container } = render(<StyledComponent tone="primary" />);
+
+  expect(container).toMatchInlineSnapshot(`
+    <div>
+      <input
+        class="toned"
+      />
+    </div>
+  `);
+});
+
 it("should concatenate classNames", () => {
   const Component = styled.input("className1");
 
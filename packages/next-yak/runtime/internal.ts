@@ -35,6 +35,11 @@ export { useTheme, YakThemeProvider } from "next-yak/context";
 // runtime internals (helpers which get injected by the compiler)
 export { unitPostFix as __yak_unitPostFix } from "./internals/unitPostFix.js";
 export { mergeCssProp as __yak_mergeCssProp } from "./internals/mergeCssProp.js";
+export { staticFallback as __yak_staticFallback } from "./internals/staticFallback.js";
+export {
+  cssString as __yak_cssString,
+  cssUrl as __yak_cssUrl,
+} from "./internals/cssString.js";
 
 // export shorthand for DOM styled components (e.g. for styled.div)
 export * from "./styledDom.js";
@@ -0,0 +1,56 @@
+/**
+ * Internal helpers called by transformed code - Do not use directly
+ *
+ * css variables are not replaced inside of `url()` and strings,
+ * therefore the compiler moves the whole `url()` or string into the css variable
+ *
+ * ```tsx
+ * import { styled } from "next-yak";
+ *
+ * const Icon = styled.div<{ $name: string }>`
+ *   background: url("/icons/${({ $name }) => $name}.svg");
+ * `;
+ * ```
+ *
+ * Which will be transformed to:
+ *  ```tsx
+ * import { styled } from "next-yak/internals";
+ *
+ * // background: var(--Icon__background);
+ * const Icon = styled.div<{ $name: string }>(
+ *  "div", {
+ *   "--Icon__background": cssUrl(({ $name }) => $name, "/icons/", ".svg")
+ * });
+ */
+export const cssString = (arg: unknown, before: string, after: string) => {
+  switch (typeof arg) {
+    case "function":
+      return (props: any) => cssString(arg(props), before, after);
+    case "number":
+    case "string":
+      return quote(`${before}${arg}${after}`);
+    // Ignore falsy values
+    default:
+      return undefined;
+  }
+};
+
+export const cssUrl = (arg: unknown, before: string, after: string) => {
+  switch (typeof arg) {
+    case "function":
+      return (props: any) => cssUrl(arg(props), before, after);
+    case "number":
+    case "string":
+      return `url(${quote(`${before}${arg}${after}`)})`;
+    // Ignore falsy values
+    default:
+      return undefined;
+  }
+};
+
+/**
+ * Escapes a value so it can't break out of a double quoted css string
+ * e.g. `a"b` -> `"a\"b"`
+ */
+const quote = (value: string) =>
+  `"${value.replace(/\\/g, "\\\\").replace(/"/g, '\\"').replace(/\n/g, "\\a ")}"`;
@@ -12,6 +12,10 @@ import { RuntimeStyleProcessor } from "../publicStyledApi.js";
  * `}
  * {...{ style: { padding: "30px" }}}
  * />
+ *
+ * If the css prop is used on a component and the `cssProp.runtimeCheck` option is enabled,
+ * the component name is passed so that a warning can be shown in development
+ * if the component does not forward the className to the DOM
  */
 export const mergeCssProp = (
   relevantProps: {
@@ -19,6 +23,7 @@ export const mergeCssProp = (
     style?: Record<string, string>;
   } & Record<string, unknown>,
   cssProp: RuntimeStyleProcessor<unknown>,
+  componentName?: string,
 ) => {
   const existingClassName = relevantProps.className;
   const classNames = existingClassName
@@ -30,6 +35,15 @@ export const mergeCssProp = (
 
   cssProp({}, classNames, style);
 
+  if (process.env.NODE_ENV === "development" && componentName) {
+    checkForwardedClassNames(
+      componentName,
+      Array.from(classNames).filter(
+        (className) => !existingClassName?.split(" ").includes(className),
+      ),
+    );
+  }
+
   const result: { className?: string; style?: Record<string, string> } = {};
 
   if (Object.keys(style).length > 0) {
@@ -41,3 +55,35 @@ export const mergeCssProp = (
 
   return result;
 };
+
+const checkedComponents = new Set<string>();
+
+/**
+ * Warns once per component if none of the css prop class names
+ * can be found in the DOM after the component rendered
+ *
+ * Every component is checked only once to keep renders cheap
+ */
+const checkForwardedClassNames = (
+  componentName: string,
+  cssPropClassNames: string[],
+) => {
+  if (
+    typeof document === "undefined" ||
+    cssPropClassNames.length === 0 ||
+    checkedComponents.has(componentName)
+  ) {
+    return;
+  }
+  checkedComponents.add(componentName);
+  setTimeout(() => {
+    const isForwarded = cssPropClassNames.some(
+      (className) => document.getElementsByClassName(className).length > 0,
+    );
+    if (!isForwarded) {
+      console.warn(
+        `The css prop of <${componentName} /> has no effect as ${componentName} does not forward the className prop to a DOM element.`,
+      );
+    }
+  });
+};
@@ -0,0 +1,32 @@
+/**
+ * Internal helper called by transformed code - Do not use directly
+ *
+ * Takes a function which returns the dynamic part of a css variable and
+ * resets the css variable if there is no value so that the static fallback is used
+ *
+ * ```tsx
+ * import { styled } from "next-yak";
+ *
+ * const Button = styled.button<{ $color?: string }>`
+ *   color: ${({ $color }) => $color ?? "red"};
+ * `;
+ * ```
+ *
+ * Which will be transformed to:
+ *  ```tsx
+ * import { styled } from "next-yak/internals";
+ *
+ * // color: var(--Button__color, red);
+ * const Button = styled.button<{ $color?: string }>(
+ *  "button", {
+ *   "--Button__color": staticFallback(({ $color }) => $color)
+ * });
+ */
+export const staticFallback = (arg: unknown): unknown => {
+  if (typeof arg === "function") {
+    return (props: any) => staticFallback(arg(props));
+  }
+  // `initial` is the guaranteed-invalid value of a css variable
+  // which makes var(--x, fallback) use its fallback
+  return arg ?? "initial";
+};
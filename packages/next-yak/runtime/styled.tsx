@@ -59,14 +59,24 @@ const yakStyled: StyledInternal = (Component, attrs) => {
   // if the component that is wrapped is a yak component, we can extract it to render the underlying component directly
   // and we can also extract the attrs function and the dynamic style function to merge it with the current attrs function (or dynamic style function)
   // so that the sequence of the attrs functions is preserved
-  const [parentYakComponent, parentAttrsFn, parentRuntimeStylesFn] =
-    isYakComponent
-      ? (Component[yakComponentSymbol] as [
-          YakComponent<unknown>,
-          ExtractAttrsFunction<typeof attrs>,
-          RuntimeStyleProcessor<unknown>,
-        ])
-      : [];
+  const [
+    parentYakComponent,
+    parentAttrsFn,
+    parentRuntimeStylesFn,
+    parentRendersNativeElement,
+  ] = isYakComponent
+    ? (Component[yakComponentSymbol] as [
+        YakComponent<unknown>,
+        ExtractAttrsFunction<typeof attrs>,
+        RuntimeStyleProcessor<unknown>,
+        boolean,
+      ])
+    : [];
+  // props which are only used for styling are removed for native elements e.g. styled.button
+  // custom elements receive all props as attributes e.g. styled("my-widget")
+  const rendersNativeElement = isYakComponent
+    ? Boolean(parentRendersNativeElement)
+    : typeof Component === "string" && !Component.includes("-");
 
   const mergedAttrsFn = buildRuntimeAttrsProcessor(attrs, parentAttrsFn);
 
@@ -81,6 +91,11 @@ const yakStyled: StyledInternal = (Component, attrs) => {
       runtimeStylesFn,
       parentRuntimeStylesFn,
     );
+    // the compiler passes the props which are read by the styles
+    // e.g. styled.div`color: ${({ tone }) => tone};` -> { consumedProps: ["tone"] }
+    const consumedProps = rendersNativeElement
+      ? getConsumedProps(values)
+      : undefined;
     const yak: React.FunctionComponent = (props) => {
       // if the css component does not require arguments
       // it can be called without arguments and we skip calling useTheme()
@@ -152,9 +167,14 @@ const yakStyled: StyledInternal = (Component, attrs) => {
 
       // remove all props that start with a $ sign for string components e.g. "button" or "div"
       // so that they are not passed to the DOM element
+      //
+      // props which are only read by the styles are removed as soon as the styles are processed
+      // (for styled(YakComponent) by the outer component as it processes the styles of all components)
       const filteredProps = !isYakComponent
-        ? removeNonDomProperties(propsBeforeFiltering)
-        : propsBeforeFiltering;
+        ? removeNonDomProperties(propsBeforeFiltering, consumedProps)
+        : consumedProps
+          ? removeConsumedProperties(propsBeforeFiltering, consumedProps)
+          : propsBeforeFiltering;
 
       return parentYakComponent ? (
         // if the styled(Component) syntax is used and the component is a yak component
@@ -172,11 +192,12 @@ const yakStyled: StyledInternal = (Component, attrs) => {
 
     // Assign the yakComponentSymbol directly without forwardRef
     return Object.assign(yak, {
-      [yakComponentSymbol]: [yak, mergedAttrsFn, runtimeStyleProcessor] as [
-        unknown,
-        unknown,
-        unknown,
-      ],
+      [yakComponentSymbol]: [
+        yak,
+        mergedAttrsFn,
+        runtimeStyleProcessor,
+        rendersNativeElement,
+      ] as [unknown, unknown, unknown, unknown],
     });
   };
 };
@@ -189,16 +210,55 @@ const yakStyled: StyledInternal = (Component, attrs) => {
  */
 const removeNonDomProperties = <T extends Record<string, unknown>>(
   obj: T,
+  consumedProps?: string[],
 ): T => {
   const result = {} as T;
   for (const key in obj) {
-    if (!key.startsWith("$") && obj[key] !== undefined) {
+    if (
+      !key.startsWith("$") &&
+      obj[key] !== undefined &&
+      !consumedProps?.includes(key)
+    ) {
       result[key] = obj[key];
     }
   }
   return result;
 };
 
+/**
+ * Remove all props which are only read by the styles of a component
+ */
+const removeConsumedProperties = <T extends Record<string, unknown>>(
+  obj: T,
+  consumedProps: string[],
+): T => {
+  const result = {} as T;
+  for (const key in obj) {
+    if (!consumedProps.includes(key)) {
+      result[key] = obj[key];
+    }
+  }
+  return result;
+};
+
+/**
+ * Find the props which are read by the styles of a component
+ * e.g. `{ consumedProps: ["tone"] }` added by the compiler
+ */
+const getConsumedProps = (values: unknown[]) => {
+  for (const value of values) {
+    if (
+      value &&
+      typeof value === "object" &&
+      "consumedProps" in value &&
+      Array.isArray(value.consumedProps)
+    ) {
+      return value.consumedProps as string[];
+    }
+  }
+  return undefined;
+};
+
 // util function to merge class names, as they are concatenated with a space
 const mergeClassNames = (a?: string, b?: string) => {
   if (!a && !b) return undefined;
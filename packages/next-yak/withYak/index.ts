@@ -30,6 +30,75 @@ export type YakConfigOptions = {
    * - Increases bundle size slightly when enabled
    */
   displayNames?: boolean;
+  /**
+   * Decides how unique class names, keyframes and css variables are generated
+   * - `FileHash` (default) hashes the file name and counts components with the same name
+   * - `ContentHash` hashes the name and the css of each component, so class names stay
+   *   stable if the file is moved or other components of the file change
+   */
+  namingMode?: "FileHash" | "ContentHash";
+  /**
+   * Template for generated class names, keyframes and css variables
+   * e.g. `"[file]-[component]-[hash:6]"`
+   *
+   * Supported placeholders: `[prefix]` (alias `[project]`), `[file]`, `[component]`, `[hash]`, `[fileHash]`,
+   * `[contentHash]` and `[counter]` - hashes can be shortened e.g. `[hash:6]`
+   * `[hash]` follows the `namingMode` and one of the hashes is required
+   */
+  classNameTemplate?: string;
+  /**
+   * Reports every interpolation which needs work during render as a build error
+   * e.g. `${({ $size }) => $size}px` or conditional css
+   * - for pages which must ship without any runtime style computation
+   */
+  zeroRuntime?: boolean;
+  /**
+   * Registers css variables of dynamic props with `@property` so they can be transitioned and animated
+   * The type is inferred from the css property and unit e.g. `<length>` for `left: ${({ $x }) => $x}px`
+   * - variables of nested selectors stay untyped
+   * - a missing value (e.g. an `undefined` prop) uses the initial value of the type e.g. `0px` or `transparent`
+   */
+  typedCssVariables?: boolean;
+  /**
+   * Stops forwarding props to native elements which are only read by the styles
+   * e.g. `tone` in `${({ tone }) => tone}` - dom attributes like `disabled` and custom elements
+   * (e.g. `styled("my-widget")`) still receive all props
+   */
+  stripConsumedProps?: boolean;
+  /**
+   * Reports class names, keyframes and css variables which are generated by more than one file
+   * e.g. if the short hashes of minified names collide in large projects
+   * - every collision is reported as build error with both source locations
+   * - identical components of different files don't collide in the `ContentHash` naming mode
+   */
+  checkUniqueNames?: boolean;
+  /**
+   * Customizes which `styled.<element>` shorthands are available
+   * - `elements` replaces the built-in list of html and svg elements
+   * - `extend` adds elements e.g. `["my-widget", "svg:feGaussianBlur"]`
+   * - `aliases` renders a different element e.g. `{ View: "div" }` - the element must be configured
+   *
+   * Invalid entries are reported as build errors
+   */
+  styledElements?: {
+    elements?: string[];
+    extend?: string[];
+    aliases?: Record<string, string>;
+  };
+  /**
+   * Controls the `css` prop on components (e.g. `<Button css={...} />`)
+   * - `allowedComponents` only these components may use the css prop
+   * - `deniedComponents` components which don't forward `className` and `style`
+   * - `runtimeCheck` warns in development if a component drops the css prop styles
+   * - `classNameHelpers` functions like `cn` or `clsx` which receive the css prop class name
+   *   directly instead of merging it at runtime (defaults to `["clsx", "cn", "classnames"]`)
+   */
+  cssProp?: {
+    allowedComponents?: string[];
+    deniedComponents?: string[];
+    runtimeCheck?: boolean;
+    classNameHelpers?: string[];
+  };
   experiments?: {
     debug?:
       | boolean
@@ -37,7 +106,19 @@ export type YakConfigOptions = {
           filter?: (path: string) => boolean;
           type: "all" | "ts" | "css" | "css resolved";
         };
-    transpilationMode?: "CssModule" | "Css";
+    /**
+     * - `CssModule` (default) one css module class per component
+     * - `Css` plain global css
+     * - `Atomic` css modules with one deduplicated class per static declaration
+     *   (properties which are declared more than once or by conditional css keep the component class,
+     *   but overriding another component e.g. `styled(Button)` or mixing shorthands and longhands
+     *   depends on the stylesheet order)
+     * - `Scoped` plain global css which only applies inside an element with a `data-yak-scope`
+     *   attribute without increasing the specificity - e.g. for widgets embedded in other pages
+     *   (keyframe names are prefixed but stay global and styles of the host page still apply
+     *   to the scoped elements e.g. element selectors or inherited properties)
+     */
+    transpilationMode?: "CssModule" | "Css" | "Atomic" | "Scoped";
   };
 };
 
@@ -57,6 +138,14 @@ const addYak = (yakOptions: YakConfigOptions, nextConfig: NextConfig) => {
       basePath: currentDir,
       prefix: yakOptions.prefix,
       displayNames: yakOptions.displayNames ?? !minify,
+      namingMode: yakOptions.namingMode,
+      classNameTemplate: yakOptions.classNameTemplate,
+      zeroRuntime: yakOptions.zeroRuntime,
+      typedCssVariables: yakOptions.typedCssVariables,
+      stripConsumedProps: yakOptions.stripConsumedProps,
+      checkUniqueNames: yakOptions.checkUniqueNames,
+      styledElements: yakOptions.styledElements,
+      cssProp: yakOptions.cssProp,
       transpilationMode: yakOptions.experiments?.transpilationMode,
     },
   ]);
@@ -68,7 +157,8 @@ const addYak = (yakOptions: YakConfigOptions, nextConfig: NextConfig) => {
 
     webpackConfig.module.rules.push({
       test:
-        yakOptions.experiments?.transpilationMode === "Css"
+        yakOptions.experiments?.transpilationMode === "Css" ||
+        yakOptions.experiments?.transpilationMode === "Scoped"
           ? /\.yak\.css$/
           : /\.yak\.module\.css$/,
       loader: path.join(currentDir, "../loaders/css-loader.js"),
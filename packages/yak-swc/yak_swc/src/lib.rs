@@ -1,44 +1,72 @@
 use css_in_js_parser::{find_char, parse_css, to_css, CommentStateType};
-use css_in_js_parser::{Declaration, ParserState};
-use rustc_hash::FxHashMap;
+use css_in_js_parser::{Declaration, ParserState, ScopeType};
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Deserialize;
 use std::ops::Deref;
+use std::rc::Rc;
 use std::vec;
 use swc_core::atoms::atom;
 use swc_core::atoms::Atom;
 
 use swc_core::common::comments::Comment;
 use swc_core::common::comments::Comments;
-use swc_core::common::errors::HANDLER;
-use swc_core::common::{Spanned, SyntaxContext, DUMMY_SP};
+use swc_core::common::errors::{SourceMapper, HANDLER};
+use swc_core::common::source_map::PURE_SP;
+use swc_core::common::sync::Lrc;
+use swc_core::common::{BytePos, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::visit::{Fold, VisitMutWith};
 use swc_core::ecma::{ast::*, visit::VisitMut};
-use utils::add_suffix_to_expr::add_suffix_to_expr;
-use utils::ast_helper::{extract_ident_and_parts, is_valid_tagged_tpl, TemplateIterator};
+use utils::add_suffix_to_expr::{add_prefix_and_suffix_to_expr, add_suffix_to_expr};
+use utils::ast_helper::{
+  extract_ident_and_parts, get_first_references, get_template_fingerprint, is_same_expression,
+  is_valid_tagged_tpl, TemplateIterator,
+};
 use utils::cross_file_selectors::ImportType;
-use utils::css_prop::HasCSSProp;
+use utils::css_hash::hash_to_css;
+use utils::css_prop::{get_component_name, HasCSSProp};
+use utils::css_strings::get_css_string_context;
+use utils::prop_independent::{
+  get_module_bindings, get_prop_independent_body, is_precomputable_mixin,
+};
+use utils::styled_target::{
+  classify_styled_call, find_styled_call_mut, hoist_styled_target, StyledTarget,
+};
+use utils::typed_css_variables::{css_variable_property_declarations, infer_css_variable_syntax};
 
 mod variable_visitor;
 use variable_visitor::{ScopedVariableReference, VariableVisitor};
 mod yak_imports;
 use yak_imports::{visit_module_imports, YakImports};
 mod math_evaluate;
+pub mod module_graph;
+pub mod name_registry;
 #[cfg(feature = "plugin")]
 mod plugin;
 pub mod yak_file;
+use module_graph::{ModuleGraph, ModuleGraphProvider};
+use name_registry::{GeneratedName, NameRegistry};
 
 use math_evaluate::try_evaluate;
 
 mod utils {
   pub(crate) mod add_suffix_to_expr;
   pub(crate) mod ast_helper;
+  pub(crate) mod atomic_css;
+  pub(crate) mod consumed_props;
   pub(crate) mod cross_file_selectors;
   pub(crate) mod css_hash;
   pub(crate) mod css_prop;
+  pub(crate) mod css_strings;
   pub(crate) mod native_elements;
+  pub(crate) mod prop_independent;
+  pub(crate) mod styled_target;
+  pub(crate) mod typed_css_variables;
 }
+use utils::native_elements::{validate_styled_elements, StyledElements};
 pub mod naming_convention;
-use naming_convention::{NamingConvention, TranspilationMode};
+use naming_convention::{
+  validate_class_name_template, NamingConvention, NamingMode, TranspilationMode,
+};
 
 mod yak_transforms;
 use yak_transforms::{
@@ -46,7 +74,7 @@ use yak_transforms::{
 };
 
 /// Static plugin configuration.
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -68,6 +96,103 @@ pub struct Config {
   /// Influences how class names and selectors are transpiled
   #[serde(default = "Config::transpilation_mode_default")]
   pub transpilation_mode: TranspilationMode,
+  /// Customizes which `styled.<element>` shorthands are compiled
+  /// to prebuilt element components
+  #[serde(default)]
+  pub styled_elements: StyledElementsConfig,
+  /// Controls the `css` prop on components (e.g. `<Button css={...} />`)
+  #[serde(default)]
+  pub css_prop: CssPropConfig,
+  /// Decides how unique class names, keyframes and css variables are generated\
+  /// `ContentHash` keeps names stable if other components of the file change
+  #[serde(default)]
+  pub naming_mode: NamingMode,
+  /// Template for generated class names, keyframes and css variables\
+  /// e.g. `"[file]-[component]-[hash:6]"`\
+  /// Placeholders: `[prefix]` (or `[project]`), `[file]`, `[component]`, `[hash]` (depends on the naming mode),
+  /// `[fileHash]`, `[contentHash]` and `[counter]` - hashes can be shortened e.g. `[hash:6]`
+  pub class_name_template: Option<String>,
+  /// Reports every interpolation which needs work during render as an error
+  /// e.g. for pages which must ship without any runtime style computation
+  #[serde(default)]
+  pub zero_runtime: bool,
+  /// Registers runtime css variables with `@property` if their type can be inferred
+  /// from the css property and unit e.g. `<length>` for `left: ${({$x}) => $x}px`
+  #[serde(default)]
+  pub typed_css_variables: bool,
+  /// Stops forwarding props to native elements which are only read by the styles
+  /// e.g. `tone` in styled.div`color: ${({ tone }) => tone};`
+  #[serde(default)]
+  pub strip_consumed_props: bool,
+  /// Lists the generated names of every css expression in a `YAK GENERATED NAMES` comment
+  /// so the loader can report names which collide with names of other files
+  #[serde(default)]
+  pub check_unique_names: bool,
+}
+
+/// Configuration for the `styled.<element>` shorthands
+#[derive(Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct StyledElementsConfig {
+  /// Replaces the built-in list of html and svg elements\
+  /// e.g. `["div", "span", "svg:path"]`
+  pub elements: Option<Vec<String>>,
+  /// Elements which are added to the built-in (or replaced) list\
+  /// Svg elements which are not built-in must be prefixed with their namespace\
+  /// e.g. `["my-widget", "svg:feGaussianBlur"]`
+  #[serde(default)]
+  pub extend: Vec<String>,
+  /// Shorthands which render a different element\
+  /// e.g. `{ "View": "div", "Text": "span" }`\
+  /// The rendered element must be part of the configured elements
+  #[serde(default)]
+  pub aliases: FxHashMap<String, String>,
+}
+
+/// Configuration for the `css` prop on components
+///
+/// Intrinsic elements (e.g. `<div css={...} />`) always support the `css` prop.
+/// Components only receive the styles if they forward `className` and `style`.
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct CssPropConfig {
+  /// If set, only these components may use the `css` prop\
+  /// e.g. `["Button", "Layout.Header"]`
+  pub allowed_components: Option<Vec<String>>,
+  /// Components which do not forward `className` and `style`
+  /// and must not use the `css` prop
+  #[serde(default)]
+  pub denied_components: Vec<String>,
+  /// Warns in development if a component drops the styles of its `css` prop
+  #[serde(default)]
+  pub runtime_check: bool,
+  /// Functions which combine class names (e.g. `className={cn("a", "b")}`)\
+  /// Static css props are appended to their arguments instead of being merged at runtime
+  #[serde(default = "CssPropConfig::class_name_helpers_default")]
+  pub class_name_helpers: Vec<String>,
+}
+
+impl CssPropConfig {
+  fn class_name_helpers_default() -> Vec<String> {
+    vec![
+      "clsx".to_string(),
+      "cn".to_string(),
+      "classnames".to_string(),
+    ]
+  }
+}
+
+impl Default for CssPropConfig {
+  fn default() -> Self {
+    Self {
+      allowed_components: Default::default(),
+      denied_components: Default::default(),
+      runtime_check: Default::default(),
+      class_name_helpers: CssPropConfig::class_name_helpers_default(),
+    }
+  }
 }
 
 impl Config {
@@ -88,6 +213,14 @@ impl Default for Config {
       prefix: Default::default(),
       display_names: Default::default(),
       transpilation_mode: TranspilationMode::CssModule,
+      styled_elements: Default::default(),
+      css_prop: Default::default(),
+      naming_mode: Default::default(),
+      class_name_template: Default::default(),
+      zero_runtime: Default::default(),
+      typed_css_variables: Default::default(),
+      strip_consumed_props: Default::default(),
+      check_unique_names: Default::default(),
     }
   }
 }
@@ -110,6 +243,10 @@ where
   current_condition: Vec<String>,
   /// Current css expression is exported
   current_exported: bool,
+  /// Names under which the current variable is exported
+  /// e.g. [Button] for export const Button = styled.button`color: red;`
+  /// e.g. [PrimaryButton] for const Button = styled.button`color: red;`; export { Button as PrimaryButton };
+  current_export_names: Vec<Atom>,
   /// SWC comments proxy to add extracted css as comments
   comments: Option<GenericComments>,
   /// Extracted variables from the AST
@@ -124,6 +261,9 @@ where
   /// e.g. const Button = styled.button`...` -> Button\
   /// Used to replace expressions with the actual class name or keyframes name
   variable_name_selector_mapping: FxHashMap<ScopedVariableReference, String>,
+  /// Class names of module level styled components which are assigned before the transformation
+  /// so selectors can reference components which are declared later in the file
+  styled_class_names: FxHashMap<ScopedVariableReference, String>,
   /// Naming convention to generate unique css identifiers
   naming_convention: NamingConvention,
   /// Expression replacement to replace a yak library call with the transformed one
@@ -135,35 +275,189 @@ where
   display_names: bool,
   /// Transpilation mode to determine how to transpile the code
   transpilation_mode: TranspilationMode,
+  /// Elements which are allowed as `styled.<element>` shorthand
+  /// Handed over to the yak imports once they are parsed
+  styled_elements: Option<StyledElements>,
+  /// Rules for the `css` prop on components
+  css_prop: CssPropConfig,
+  /// Number of functions surrounding the current expression
+  /// Used to decide if an expression can be hoisted to the module scope
+  function_depth: usize,
+  /// Declarations which have to be inserted in front of the current module item
+  /// e.g. const __yak_Button_target = forwardRef(...)
+  hoisted_module_items: Vec<ModuleItem>,
+  /// File name of the current file
+  filename: String,
+  /// Optional module graph to resolve cross-file references without the loader
+  module_graph: Option<ModuleGraph>,
+  /// Configuration which is reused for modules of the module graph
+  config: Config,
+  /// Optional project wide registry to detect name collisions between files
+  name_registry: Option<Rc<dyn NameRegistry>>,
+  /// Optional source map to add the line and column to the generated names manifest
+  source_map: Option<Lrc<dyn SourceMapper>>,
+  /// Atomic class names which already got their css in this file
+  emitted_atomic_class_names: FxHashSet<String>,
+  /// Runtime expressions and css variable names of the current root css expression
+  /// Used to share one css variable between structurally identical expressions
+  top_level_css_variables: Vec<(Expr, String)>,
+  /// Module level bindings and the position after which they are initialized
+  /// Used to evaluate runtime functions which don't read props only once
+  module_bindings: FxHashMap<Id, BytePos>,
+  /// Syntax of the typed css variables of the current root css expression (only for `typed_css_variables`)
+  css_variable_syntaxes: FxHashMap<String, &'static str>,
+  /// Components which need runtime work (only tracked for `zero_runtime`)
+  runtime_components: Vec<String>,
 }
 
 impl<GenericComments> TransformVisitor<GenericComments>
 where
   GenericComments: Comments,
 {
-  pub fn new(
-    comments: Option<GenericComments>,
-    filename: impl AsRef<str>,
-    minify: bool,
-    prefix: Option<String>,
-    display_names: bool,
-    transpilation_mode: TranspilationMode,
-  ) -> Self {
+  pub fn new(comments: Option<GenericComments>, filename: impl AsRef<str>, config: Config) -> Self {
+    let mut naming_convention =
+      NamingConvention::new(filename.as_ref(), config.minify, config.prefix.clone())
+        .with_naming_mode(config.naming_mode)
+        .with_transpilation_mode(config.transpilation_mode)
+        .with_class_name_template(
+          config
+            .class_name_template
+            .clone()
+            .filter(|template| validate_class_name_template(template).is_ok()),
+        );
+    if config.check_unique_names {
+      naming_convention.track_generated_names();
+    }
     Self {
+      filename: filename.as_ref().to_string(),
+      module_graph: None,
+      name_registry: None,
+      source_map: None,
+      emitted_atomic_class_names: FxHashSet::default(),
+      runtime_components: vec![],
+      top_level_css_variables: vec![],
+      module_bindings: FxHashMap::default(),
+      css_variable_syntaxes: FxHashMap::default(),
+      styled_class_names: FxHashMap::default(),
+      config: config.clone(),
       current_css_state: None,
       current_declaration: vec![],
       current_variable_name: None,
       current_condition: vec![],
       current_exported: false,
+      current_export_names: vec![],
       variables: VariableVisitor::new(),
       yak_library_imports: None,
-      naming_convention: NamingConvention::new(filename.as_ref(), minify, prefix),
+      naming_convention,
       variable_name_selector_mapping: FxHashMap::default(),
       expression_replacement: None,
       inside_element_with_css_attribute: false,
       comments,
-      display_names,
-      transpilation_mode,
+      display_names: config.display_names,
+      transpilation_mode: config.transpilation_mode,
+      styled_elements: Some(StyledElements::new(&config.styled_elements)),
+      css_prop: config.css_prop,
+      function_depth: 0,
+      hoisted_module_items: vec![],
+    }
+  }
+
+  /// Resolve cross-file constants, mixins and selectors with the given module graph
+  /// instead of leaving them to the loader
+  ///
+  /// Used by hosts which can read other files e.g. the playground
+  pub fn with_module_graph(mut self, provider: Rc<dyn ModuleGraphProvider>) -> Self {
+    self.module_graph = Some(ModuleGraph::new(provider, self.config.clone()));
+    self
+  }
+
+  /// Adds the line and column of each css expression to the generated names manifest
+  /// (only for `check_unique_names`)
+  pub fn with_source_map(mut self, source_map: Lrc<dyn SourceMapper>) -> Self {
+    self.source_map = Some(source_map);
+    self
+  }
+
+  /// Report generated names which collide with names of other files
+  pub fn with_name_registry(mut self, name_registry: Rc<dyn NameRegistry>) -> Self {
+    self.naming_convention.track_generated_names();
+    self.name_registry = Some(name_registry);
+    self
+  }
+
+  /// Registers the names which were generated for a css expression
+  /// and reports collisions with other files
+  ///
+  /// With `check_unique_names` the names are additionally listed in a comment
+  /// which the loader merges with the names of all other files
+  /// e.g. `YAK GENERATED NAMES:[{"name":"ym7uBBu","readableName":"Button","line":3,"column":16}]`
+  fn register_generated_names(&mut self, span: Span, result_span: Span, css_code: &str) {
+    if self.name_registry.is_none() && !self.config.check_unique_names {
+      return;
+    }
+    let content =
+      (self.config.naming_mode == NamingMode::ContentHash).then(|| css_code.to_string());
+    let generated_names = self
+      .naming_convention
+      .take_generated_names()
+      .into_iter()
+      .map(|(name, readable_name)| GeneratedName {
+        name,
+        filename: self.filename.clone(),
+        span,
+        readable_name,
+        content: content.clone(),
+      })
+      .collect::<Vec<_>>();
+    if let Some(name_registry) = &self.name_registry {
+      for generated_name in &generated_names {
+        let Some(existing) = name_registry.register(generated_name.clone()) else {
+          continue;
+        };
+        HANDLER.with(|handler| {
+          handler
+            .struct_span_err(
+              span,
+              &format!(
+                "The generated name \"{}\" for \"{}\" collides with \"{}\" in \"{}\" - change one of the names or use a different prefix or classNameTemplate",
+                generated_name.name, generated_name.readable_name, existing.readable_name, existing.filename
+              ),
+            )
+            .emit();
+        });
+      }
+    }
+    if self.config.check_unique_names && !generated_names.is_empty() {
+      let location = self
+        .source_map
+        .as_ref()
+        .map(|source_map| source_map.lookup_char_pos(span.lo));
+      let manifest = generated_names
+        .iter()
+        .map(|generated_name| {
+          let mut entry = serde_json::json!({
+            "name": generated_name.name,
+            "readableName": generated_name.readable_name,
+          });
+          if let Some(location) = &location {
+            entry["line"] = location.line.into();
+            entry["column"] = (location.col.0 + 1).into();
+          }
+          // Identical css of different files may generate the same name on purpose
+          if let Some(content) = &generated_name.content {
+            entry["content"] = hash_to_css(content).into();
+          }
+          entry
+        })
+        .collect::<Vec<_>>();
+      self.comments.add_leading(
+        result_span.lo,
+        Comment {
+          kind: swc_core::common::comments::CommentKind::Block,
+          span: DUMMY_SP,
+          text: format!("YAK GENERATED NAMES:{}", serde_json::Value::Array(manifest)).into(),
+        },
+      );
     }
   }
 
@@ -190,6 +484,204 @@ where
     })
   }
 
+  /// Reports an interpolation which would need work during render in zero runtime mode
+  /// e.g. styled.button`color: ${({ $color }) => $color};`
+  fn report_runtime_interpolation(&mut self, expr: &Expr) {
+    let component_name = self.get_current_component_id().to_readable_string();
+    HANDLER.with(|handler| {
+      handler
+        .struct_span_err(
+          expr.span(),
+          &format!(
+            "\"{component_name}\" needs runtime work for this interpolation - zeroRuntime only allows constants, mixins and component selectors"
+          ),
+        )
+        .emit();
+    });
+    if !self.runtime_components.contains(&component_name) {
+      self.runtime_components.push(component_name);
+    }
+  }
+
+  /// Splits a statically known fallback from a runtime property value
+  /// e.g. `({$c}) => $c ?? "red"` -> (`({$c}) => $c`, "red")
+  ///
+  /// The runtime expression returns `undefined` instead of the fallback
+  /// so the css variable can fall back to the static value
+  fn split_static_fallback(&self, expr: &Expr) -> Option<(Expr, String)> {
+    let Expr::Arrow(arrow) = expr else {
+      return None;
+    };
+    let BlockStmtOrExpr::Expr(body) = &*arrow.body else {
+      return None;
+    };
+    let Expr::Bin(bin) = body.unwrap_parens() else {
+      return None;
+    };
+    if !matches!(bin.op, op!("??") | op!("||")) {
+      return None;
+    }
+    let fallback = match bin.right.unwrap_parens() {
+      Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+      Expr::Lit(Lit::Num(num)) => num.value.to_string(),
+      // e.g. const theme = { primary: "red" }; ({$c}) => $c || theme.primary
+      right => match *self
+        .variables
+        .get_const_value(&extract_ident_and_parts(right)?)?
+      {
+        Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+        Expr::Lit(Lit::Num(num)) => num.value.to_string(),
+        _ => return None,
+      },
+    };
+    let runtime_body = if bin.op == op!("??") {
+      bin.left.clone()
+    } else {
+      // Falsy values have to fall back too e.g. `""` or `0`
+      Box::new(Expr::Bin(BinExpr {
+        right: Box::new(Expr::Ident(Ident::new(
+          "undefined".into(),
+          DUMMY_SP,
+          SyntaxContext::empty(),
+        ))),
+        ..bin.clone()
+      }))
+    };
+    Some((
+      Expr::Arrow(ArrowExpr {
+        body: Box::new(BlockStmtOrExpr::Expr(runtime_body)),
+        ..arrow.clone()
+      }),
+      fallback,
+    ))
+  }
+
+  /// Returns the value of a runtime function which doesn't read props
+  /// so it can be evaluated once when the component is created instead of on every render
+  /// e.g. `${() => getSpacing()}px` -> `__yak_unitPostFix(getSpacing(), "px")`
+  /// e.g. `${() => flags.compact && css`...`}` -> `flags.compact && css("compact")`
+  fn get_precomputed_value(&self, expr: &Expr, is_property_value: bool) -> Option<Expr> {
+    let body = get_prop_independent_body(expr, &self.module_bindings, expr.span().lo, |id| {
+      self.variables.is_mutated(id)
+    })?;
+    (is_property_value || is_precomputable_mixin(&body, self.yak_imports().yak_css_idents()))
+      .then_some(body)
+  }
+
+  /// Assigns the class names of module level styled components which are referenced
+  /// before the end of their declaration up front
+  /// e.g. styled.ul`${Item}:hover & { color: red; }` with `const Item = styled.li``;` below
+  ///
+  /// All other names are assigned in order of their declaration to keep minified names stable
+  /// Default exports can't be referenced as they have no name
+  fn assign_module_names(&mut self, module: &Module) {
+    let first_references = get_first_references(module);
+    for item in &module.body {
+      let var_decl = match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+          decl: Decl::Var(var_decl),
+          ..
+        })) => var_decl,
+        _ => continue,
+      };
+      for decl in &var_decl.decls {
+        if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) = (&decl.name, &decl.init) {
+          let is_forward_reference = first_references
+            .get(&id.to_id())
+            .is_some_and(|position| *position < decl.span.hi);
+          if !is_forward_reference {
+            continue;
+          }
+          let scoped_name = ScopedVariableReference::new(id.to_id(), vec![id.sym.clone()]);
+          self.assign_names(init, scoped_name);
+        }
+      }
+    }
+  }
+
+  /// Assigns the class name of a styled component declaration
+  /// including declarations in object literals e.g. const List = { Item: styled.li`...` }
+  fn assign_names(&mut self, init: &Expr, scoped_name: ScopedVariableReference) {
+    match init {
+      Expr::TaggedTpl(tagged_tpl) => {
+        let Some(yak_library_function_name) =
+          self.yak_imports().get_yak_library_function_name(tagged_tpl)
+        else {
+          return;
+        };
+        // Keyframes are named once they are referenced
+        if yak_library_function_name != "styled" {
+          return;
+        }
+        self
+          .naming_convention
+          .set_content(Some(get_template_fingerprint(tagged_tpl)));
+        let class_name = self
+          .naming_convention
+          .get_css_variable_name(&scoped_name.to_readable_string());
+        self.naming_convention.set_content(None);
+        self.variable_name_selector_mapping.insert(
+          scoped_name.clone(),
+          self.transpilation_mode.css_class_name(&class_name),
+        );
+        self.styled_class_names.insert(scoped_name, class_name);
+      }
+      Expr::Object(object) => {
+        for prop in &object.props {
+          let PropOrSpread::Prop(prop) = prop else {
+            continue;
+          };
+          let Prop::KeyValue(KeyValueProp { key, value }) = &**prop else {
+            continue;
+          };
+          let part = match key {
+            PropName::Ident(value) => value.sym.clone(),
+            PropName::Str(value) => value.value.clone(),
+            PropName::Num(value) => Atom::from(value.value.to_string()),
+            PropName::BigInt(value) => Atom::from(value.value.to_string()),
+            PropName::Computed(_) => continue,
+          };
+          let mut parts = scoped_name.parts.clone();
+          parts.push(part);
+          self.assign_names(
+            value,
+            ScopedVariableReference::new(scoped_name.id.clone(), parts),
+          );
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// Validate the component wrapped by `styled(...)` and transform inline targets
+  /// e.g. styled(forwardRef((props, ref) => <div css={css`...`} />))`...`
+  fn process_styled_target(&mut self, n: &mut TaggedTpl, component_id: &ScopedVariableReference) {
+    let Some(styled_call) = find_styled_call_mut(&mut n.tag) else {
+      return;
+    };
+    match classify_styled_call(styled_call) {
+      Ok(StyledTarget::Reference) => {}
+      // Inline components might contain css props
+      // e.g. styled((props) => <div css={css`...`} {...props} />)
+      Ok(StyledTarget::Expression) => styled_call.args.visit_mut_with(self),
+      Ok(StyledTarget::Hoistable) => {
+        styled_call.args.visit_mut_with(self);
+        // Expressions inside functions might reference local variables
+        // and can therefore only be marked as side effect free
+        if self.function_depth > 0 {
+          if let Expr::Call(call) = &mut *styled_call.args[0].expr {
+            call.span = PURE_SP;
+          }
+        } else {
+          let hoisted = hoist_styled_target(styled_call, &component_id.to_readable_string());
+          self.hoisted_module_items.push(hoisted);
+        }
+      }
+      Err(error) => error.emit(),
+    }
+  }
+
   /// Iterate over the quasi and expressions of a tagged template literal
   /// and process the css code and expressions
   fn process_yak_literal(
@@ -285,7 +777,16 @@ where
               .iter()
               .map(|quasi| quasi.raw.as_str())
               .collect::<String>();
-            let import_type: ImportType =
+            // Values of a css property are constants and not mixins
+            // e.g. styled.button`color: ${colors.primary};`
+            // (A previous mixin without a semicolon also leaves the parser inside a value)
+            let is_property_value = css_state.as_ref().is_some_and(|state| {
+              state.is_inside_property_value
+                && state.current_declaration.property != "--yak-css-import"
+            });
+            let import_type: ImportType = if is_property_value {
+              ImportType::Value
+            } else {
               match find_char(code_after_expression, &[';', '{', '}', '@']) {
                 Some((char, _)) =>
                 // e.g. styled.button`${Icon} { ... }`
@@ -293,22 +794,134 @@ where
                   if char == '{' {
                     ImportType::Selector
                   }
-                  // e.g. styled.button`${colors.primary} @media { ... }`
-                  // e.g. styled.button`.foo { ${colors.primary} }`
-                  // e.g. styled.button`${colors.primary};`
+                  // e.g. styled.button`${fonts.h1} @media { ... }`
+                  // e.g. styled.button`.foo { ${fonts.h1} }`
+                  // e.g. styled.button`${fonts.h1};`
                   else {
                     ImportType::Mixin
                   }
                 }
-                // e.g. styled.button`${colors.primary}`
+                // e.g. styled.button`${fonts.h1}`
                 None => ImportType::Mixin,
-              };
-            let cross_file_import_token =
-              import_kind.encode_module_import(import_type, scoped_name.parts);
+              }
+            };
+            // Inline the referenced value if the host provides the module graph
+            // or leave it to the loader otherwise
+            let resolved_import = self.module_graph.as_ref().and_then(|module_graph| {
+              module_graph.resolve(
+                &self.filename,
+                import_kind,
+                &scoped_name.parts,
+                &import_type,
+              )
+            });
+            let css_scopes = css_state
+              .as_ref()
+              .map_or(0, |state| state.current_scopes.len());
+            // Mixins might be dynamic and are therefore passed to the runtime
+            // which calls them with the current props (static mixins are no-ops)
+            // The mixin is wrapped in a function to read it lazily and thereby
+            // support circular imports
+            // e.g. styled.button`${fonts.h1};` -> styled.button("...", () => fonts.h1)
+            //
+            // The conditional classes of a dynamic mixin are added to the element
+            // and can't keep a surrounding selector or at-rule - with zero runtime
+            // there is no runtime to call it at all
+            let is_runtime_mixin =
+              import_type == ImportType::Mixin && css_scopes <= 1 && !self.config.zero_runtime;
+            let is_dynamic_mixin = import_type == ImportType::Mixin
+              && resolved_import
+                .as_ref()
+                .is_some_and(|resolved| resolved.is_dynamic_mixin);
+            if is_dynamic_mixin && css_scopes > 1 {
+              HANDLER.with(|handler| {
+                handler
+                  .struct_span_err(
+                    expr.span(),
+                    &format!(
+                      "The dynamic mixin \"{}\" must not be used inside a selector or at-rule as its conditional styles are applied to the element itself",
+                      scoped_name.to_readable_string()
+                    ),
+                  )
+                  .emit();
+              });
+            }
+            // Without the module graph only the loader knows if the mixin is dynamic
+            // and reports it if it isn't passed to the runtime
+            let import_type = if import_type == ImportType::Mixin && !is_runtime_mixin {
+              ImportType::StaticMixin
+            } else {
+              import_type
+            };
+            if is_runtime_mixin && (resolved_import.is_none() || is_dynamic_mixin) {
+              runtime_expressions.push(Expr::Arrow(ArrowExpr {
+                span: DUMMY_SP,
+                ctxt: SyntaxContext::empty(),
+                params: vec![],
+                body: Box::new(BlockStmtOrExpr::Expr(expr.clone())),
+                is_async: false,
+                is_generator: false,
+                type_params: None,
+                return_type: None,
+              }));
+            }
+            let cross_file_import_token = match resolved_import {
+              Some(resolved_import) => resolved_import.css,
+              None => import_kind.encode_module_import(import_type, scoped_name.parts),
+            };
 
-            // TODO Track Dynamic Mixins as runtime dependency to pass props: `runtime_expressions.push(*expr.clone());`
             let (new_state, _) = parse_css(&cross_file_import_token, css_state);
             css_state = Some(new_state);
+
+            if is_dynamic_mixin && is_top_level && self.config.zero_runtime {
+              self.report_runtime_interpolation(expr);
+            }
+          }
+          // Components which are loaded dynamically can't be referenced
+          // e.g. const Icon = lazy(() => import("./Icon").then(load));
+          else if let Some(import_source) =
+            self.variables.get_dynamic_import_source(&scoped_name.id)
+          {
+            HANDLER.with(|handler| {
+              handler
+                .struct_span_err(
+                  expr.span(),
+                  &format!(
+                    "\"{}\" is loaded dynamically from \"{}\" and can't be resolved at build time - import it statically e.g. import {{ {} }} from \"{}\"",
+                    scoped_name.to_readable_string(),
+                    import_source,
+                    scoped_name.id.0,
+                    import_source
+                  ),
+                )
+                .emit();
+            });
+          }
+          // Variables which are modified after their declaration can't be inlined
+          // e.g. let size = 10; size = 20; styled.button`padding: ${size}px;`
+          else if self.variables.is_reference_mutated(&scoped_name) {
+            let is_property_value = css_state
+              .as_ref()
+              .is_some_and(|state| state.is_inside_property_value);
+            let hint = if is_property_value {
+              format!(
+                "read it at runtime e.g. ${{() => {}}}",
+                scoped_name.to_readable_string()
+              )
+            } else {
+              "use a const which is never modified".to_string()
+            };
+            HANDLER.with(|handler| {
+              handler
+                .struct_span_err(
+                  expr.span(),
+                  &format!(
+                    "\"{}\" is reassigned or mutated and can't be inlined at build time - {}",
+                    scoped_name.id.0, hint
+                  ),
+                )
+                .emit();
+            });
           }
           // Constants
           else if let Some(value) = self.variables.get_const_value(&scoped_name) {
@@ -338,18 +951,24 @@ where
               else if is_valid_tagged_tpl(&tagged_tpl, self.yak_imports().yak_keyframes_idents())
               {
                 // Create a unique name for the keyframe
+                let previous_content = self
+                  .naming_convention
+                  .set_content(Some(get_template_fingerprint(&tagged_tpl)));
                 let keyframe_name = self
                   .naming_convention
                   .get_keyframe_name(&scoped_name.to_readable_string());
+                self.naming_convention.set_content(previous_content);
                 // Store the keyframe for the later keyframe declaration
                 self
                   .variable_name_selector_mapping
                   .insert(scoped_name.clone(), keyframe_name.clone());
                 let (new_state, _) = match &self.transpilation_mode {
-                  TranspilationMode::CssModule => {
+                  TranspilationMode::CssModule | TranspilationMode::Atomic => {
                     parse_css(&format!("global({})", keyframe_name), css_state)
                   }
-                  TranspilationMode::Css => parse_css(&keyframe_name, css_state),
+                  TranspilationMode::Css | TranspilationMode::Scoped => {
+                    parse_css(&keyframe_name, css_state)
+                  }
                 };
                 css_state = Some(new_state);
               } else {
@@ -442,6 +1061,10 @@ ${{() => {var}}};\n",
 
           let is_inside_property_value = css_state.as_ref().unwrap().is_inside_property_value;
 
+          if is_top_level && self.config.zero_runtime {
+            self.report_runtime_interpolation(expr);
+          }
+
           // If the expression is inside a css property value
           // it has to be replaced with a css variable
           if is_inside_property_value {
@@ -450,15 +1073,190 @@ ${{() => {var}}};\n",
             if is_top_level {
               verify_valid_property_value_expr(expr);
             }
+            // css variables are not replaced inside of url() and strings
+            // so the whole url() or string is moved into the css variable
+            // e.g. styled.div`background: url(${({$src}) => $src});` -> `background: var(--background);`
+            let string_context = {
+              let state = css_state.as_ref().unwrap();
+              get_css_string_context(
+                &state.current_declaration.value,
+                state.is_inside_string,
+                pair
+                  .next_quasi
+                  .map_or("", |next_quasi| next_quasi.raw.as_str()),
+              )
+            };
+            let string_context = match string_context {
+              Some(Ok(string_context)) => Some(string_context),
+              Some(Err(message)) => {
+                HANDLER.with(|handler| {
+                  handler.struct_span_err(expr.span(), &message).emit();
+                });
+                None
+              }
+              None => None,
+            };
+            if let Some(string_context) = &string_context {
+              let state = css_state.as_mut().unwrap();
+              // The pending css is parsed again with the next css code
+              // so the url() or string has to be removed from it as well
+              let removed_len = state.current_declaration.value.len() - string_context.value_start;
+              if state.pending_css_segment.len() >= removed_len {
+                state
+                  .pending_css_segment
+                  .truncate(state.pending_css_segment.len() - removed_len);
+              } else {
+                HANDLER.with(|handler| {
+                  handler
+                    .struct_span_err(
+                      expr.span(),
+                      "The url() or css string around this runtime value can't be moved into a css variable - create the whole value in one function e.g. ${({$a}) => `url(/img/${$a}.png)`}",
+                    )
+                    .emit();
+                });
+              }
+              state
+                .current_declaration
+                .value
+                .truncate(string_context.value_start);
+              state.is_inside_string = None;
+              if string_context.utility == "cssUrl" {
+                state.paren_depth = state.paren_depth.saturating_sub(1);
+              }
+            }
+
             // Check if the next quasi starts with a unit
             // e.g. styled.button`left: ${({$x}) => $x}px;`
-            let unit = if let Some(next_quasi) = pair.next_quasi {
-              extract_leading_css_unit(next_quasi.raw.as_str())
-            } else {
-              None
+            let unit = match (pair.next_quasi, &string_context) {
+              (Some(next_quasi), None) => extract_leading_css_unit(next_quasi.raw.as_str()),
+              _ => None,
             };
             // The css code offset is used to remove the unit from the next css code
-            css_code_offset = unit.map_or(0, |unit_str| unit_str.len());
+            css_code_offset = match &string_context {
+              Some(string_context) => string_context.consumed,
+              None => unit.map_or(0, |unit_str| unit_str.len()),
+            };
+
+            // Statically known fallbacks are moved into the css
+            // e.g. styled.button`color: ${({$c}) => $c ?? "red"};` -> color: var(--x, red);
+            let (runtime_expr, static_fallback) = match string_context
+              .is_none()
+              .then(|| self.split_static_fallback(expr))
+              .flatten()
+            {
+              Some((runtime_expr, fallback)) => (
+                runtime_expr,
+                Some(format!("{}{}", fallback, unit.unwrap_or_default())),
+              ),
+              None => (*expr.clone(), None),
+            };
+            let runtime_expr = self
+              .get_precomputed_value(&runtime_expr, true)
+              .unwrap_or(runtime_expr);
+            // e.g. __yak_cssUrl(({$src}) => $src, "/img/", ".png")
+            let runtime_expr = match &string_context {
+              Some(string_context) => add_prefix_and_suffix_to_expr(
+                runtime_expr,
+                self
+                  .yak_library_imports
+                  .as_mut()
+                  .unwrap()
+                  .get_yak_utility_ident(string_context.utility),
+                &string_context.before,
+                &string_context.after,
+              ),
+              None => runtime_expr,
+            };
+            let css_variable_reference = |css_variable_name: &str| match &static_fallback {
+              Some(fallback) => format!("var({}, {})", css_variable_name, fallback),
+              None => format!("var({})", css_variable_name),
+            };
+
+            let css_variable_runtime_expr = if let Some(unit) = unit {
+              add_suffix_to_expr(
+                runtime_expr,
+                self
+                  .yak_library_imports
+                  .as_mut()
+                  .unwrap()
+                  .get_yak_utility_ident("unitPostFix"),
+                unit,
+              )
+            } else {
+              runtime_expr
+            };
+            // Missing runtime values reset the css variable so the static fallback applies
+            // e.g. __yak_staticFallback(({$c}) => $c)
+            let css_variable_runtime_expr = if static_fallback.is_some() {
+              Expr::Call(CallExpr {
+                span: PURE_SP,
+                ctxt: SyntaxContext::empty(),
+                callee: Callee::Expr(
+                  self
+                    .yak_library_imports
+                    .as_mut()
+                    .unwrap()
+                    .get_yak_utility_ident("staticFallback")
+                    .into(),
+                ),
+                args: vec![css_variable_runtime_expr.into()],
+                type_args: None,
+              })
+            } else {
+              css_variable_runtime_expr
+            };
+
+            // Typed variables can be transitioned and animated
+            // e.g. @property --Button__left { syntax: "<length>"; ... }
+            // Registered variables never use the var() fallback so they stay untyped
+            // Variables of nested selectors stay untyped as they are set on the component
+            // but read by other elements
+            // e.g. styled.div`& > span { color: ${({$c}) => $c}; }`
+            let is_component_scope = css_state.as_ref().is_some_and(|state| {
+              state
+                .current_scopes
+                .iter()
+                .skip(1)
+                .all(|scope| scope.scope_type == ScopeType::AtRule)
+            });
+            let typed_css_variable =
+              if self.config.typed_css_variables && static_fallback.is_none() && is_component_scope
+              {
+                infer_css_variable_syntax(
+                  &css_state.as_ref().unwrap().current_declaration.property,
+                  unit,
+                )
+              } else {
+                None
+              };
+
+            // Structurally identical expressions share one css variable
+            // e.g. styled.div`gap: ${({$gap}) => $gap}px; padding: ${({$gap}) => $gap}px;`
+            // Nested css reuses the variables of the root css expression (including inlined mixins)
+            // as the root style object is always applied
+            let is_reusable = |name: &String, value: &Expr| {
+              is_same_expression(value, &css_variable_runtime_expr)
+                && self.css_variable_syntaxes.get(name).copied()
+                  == typed_css_variable.map(|(syntax, _)| syntax)
+            };
+            let existing_css_variable = runtime_css_variables
+              .iter()
+              .find(|(name, value)| is_reusable(name, value))
+              .map(|(name, _)| name.clone())
+              .or_else(|| {
+                self
+                  .top_level_css_variables
+                  .iter()
+                  .find(|(value, name)| is_reusable(name, value))
+                  .map(|(_, name)| name.clone())
+              });
+            if let Some(existing_css_variable) = existing_css_variable {
+              let (new_state, _) =
+                parse_css(&css_variable_reference(&existing_css_variable), css_state);
+              css_state = Some(new_state);
+              self.current_css_state = css_state_before;
+              continue;
+            }
 
             let readable_name = format!(
               "{}__{}",
@@ -476,25 +1274,32 @@ ${{() => {var}}};\n",
             let css_variable_name = self
               .naming_convention
               .get_css_variable_name(readable_name.as_str());
-            let css_variable_runtime_expr = if let Some(unit) = unit {
-              add_suffix_to_expr(
-                *expr.clone(),
-                self
-                  .yak_library_imports
-                  .as_mut()
-                  .unwrap()
-                  .get_yak_utility_ident("unitPostFix"),
-                unit,
-              )
-            } else {
-              *expr.clone()
-            };
-
+            if let Some((syntax, initial_value)) = typed_css_variable {
+              self
+                .current_declaration
+                .extend(css_variable_property_declarations(
+                  &format!("--{}", css_variable_name),
+                  syntax,
+                  initial_value,
+                ));
+              self
+                .css_variable_syntaxes
+                .insert(format!("--{}", css_variable_name), syntax);
+            }
+            if is_top_level {
+              self.top_level_css_variables.push((
+                css_variable_runtime_expr.clone(),
+                format!("--{}", css_variable_name),
+              ));
+            }
             runtime_css_variables.insert(
               format!("--{}", css_variable_name.clone()),
               css_variable_runtime_expr,
             );
-            let (new_state, _) = parse_css(&format!("var(--{})", css_variable_name), css_state);
+            let (new_state, _) = parse_css(
+              &css_variable_reference(&format!("--{}", css_variable_name)),
+              css_state,
+            );
             css_state = Some(new_state);
           }
 
@@ -503,7 +1308,11 @@ ${{() => {var}}};\n",
           // If the expression is outside a css property value
           // it is probably a nested css expression
           if !is_inside_property_value {
-            runtime_expressions.push(*expr.clone());
+            runtime_expressions.push(
+              self
+                .get_precomputed_value(expr, false)
+                .unwrap_or_else(|| *expr.clone()),
+            );
           }
 
           // revert to the css state before the current expression or literal
@@ -523,7 +1332,11 @@ where
 {
   fn visit_mut_program(&mut self, program: &mut Program) {
     if let Program::Module(module) = program {
-      self.yak_library_imports = Some(visit_module_imports(module));
+      let mut yak_imports = visit_module_imports(module);
+      if let Some(styled_elements) = self.styled_elements.take() {
+        yak_imports.set_styled_elements(styled_elements);
+      }
+      self.yak_library_imports = Some(yak_imports);
     } else {
       return;
     }
@@ -533,11 +1346,41 @@ where
       return;
     }
 
+    // Invalid templates are ignored in favor of the default names
+    if let Some(template) = &self.config.class_name_template {
+      if let Err(message) = validate_class_name_template(template) {
+        HANDLER.with(|handler| {
+          handler.struct_span_err(program.span(), &message).emit();
+        });
+      }
+    }
+    // Invalid styled elements and aliases are skipped
+    for message in validate_styled_elements(&self.config.styled_elements) {
+      HANDLER.with(|handler| {
+        handler.struct_span_err(program.span(), &message).emit();
+      });
+    }
+
     // Use VariableVisitor to visit the AST and extract all variable names
     let mut variable_visitor = VariableVisitor::new();
     program.visit_mut_children_with(&mut variable_visitor);
     self.variables = variable_visitor;
+    if let Program::Module(module) = program {
+      if self.yak_imports().is_using_next_yak() {
+        self.module_bindings = get_module_bindings(module);
+        self.assign_module_names(module);
+      }
+    }
     program.visit_mut_children_with(self);
+
+    if !self.runtime_components.is_empty() {
+      let message = format!(
+        "zeroRuntime: {} component(s) in this file would need runtime work: {}",
+        self.runtime_components.len(),
+        self.runtime_components.join(", ")
+      );
+      HANDLER.with(|handler| handler.struct_warn(&message).emit());
+    }
   }
 
   /// Inject the css module import to the current file so webpack can process
@@ -584,6 +1427,12 @@ where
           last_import_index += 1;
         }
 
+        // e.g. const __yak_my_widget = styled("my-widget")
+        for declaration in self.yak_imports().get_yak_custom_element_declarations() {
+          module.body.insert(last_import_index, declaration);
+          last_import_index += 1;
+        }
+
         module.body.insert(
           last_import_index,
           ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
@@ -593,10 +1442,10 @@ where
             src: Box::new(Str {
               span: DUMMY_SP,
               value: match &self.transpilation_mode {
-                TranspilationMode::CssModule => {
+                TranspilationMode::CssModule | TranspilationMode::Atomic => {
                   format!("./{basename}.yak.module.css!=!./{basename}?./{basename}.yak.module.css")
                 }
-                TranspilationMode::Css => {
+                TranspilationMode::Css | TranspilationMode::Scoped => {
                   format!("./{basename}.yak.css!=!./{basename}?./{basename}.yak.css")
                 }
               }
@@ -611,6 +1460,34 @@ where
     }
   }
 
+  /// Visit module items one by one
+  /// To insert hoisted declarations right in front of the item which requires them
+  fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+    let mut new_items = Vec::with_capacity(items.len());
+    for mut item in items.drain(..) {
+      item.visit_mut_with(self);
+      new_items.append(&mut self.hoisted_module_items);
+      new_items.push(item);
+    }
+    *items = new_items;
+  }
+
+  /// Visit functions
+  /// To track wether the current expression is inside a function scope
+  fn visit_mut_function(&mut self, n: &mut Function) {
+    self.function_depth += 1;
+    n.visit_mut_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  /// Visit arrow functions
+  /// To track wether the current expression is inside a function scope
+  fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+    self.function_depth += 1;
+    n.visit_mut_children_with(self);
+    self.function_depth -= 1;
+  }
+
   /// Visit export declarations
   /// To store the current export state
   /// e.g. export const Button = styled.button`color: red;`
@@ -634,7 +1511,13 @@ where
           id.to_id(),
           vec![id.sym.clone()],
         ));
+        let mut export_names = self.variables.get_local_export_names(&id.to_id()).to_vec();
+        if self.current_exported {
+          export_names.insert(0, id.sym.clone());
+        }
+        let previous_export_names = std::mem::replace(&mut self.current_export_names, export_names);
         decl.init.visit_mut_with(self);
+        self.current_export_names = previous_export_names;
         self.current_variable_name = previous_variable_name;
       }
     }
@@ -688,18 +1571,34 @@ where
     }
     let css_prop = n.has_css_prop();
     if let Some(css_prop) = css_prop {
+      // e.g. <Button css={...} /> - only works if Button forwards className and style
+      let component_name = get_component_name(&n.name);
+      if let Some(component_name) = &component_name {
+        css_prop.validate_component(n, component_name, &self.css_prop);
+      }
       let previous_inside_css_attribute = self.inside_element_with_css_attribute;
       self.inside_element_with_css_attribute = true;
       n.visit_mut_children_with(self);
       self.inside_element_with_css_attribute = previous_inside_css_attribute;
-      css_prop.transform(
+      let replaced_css_spans = css_prop.transform(
         n,
-        &self
-          .yak_library_imports
-          .as_mut()
-          .unwrap()
-          .get_yak_utility_ident("mergeCssProp"),
+        self.yak_library_imports.as_mut().unwrap(),
+        &self.css_prop.class_name_helpers,
+        component_name
+          .as_deref()
+          .filter(|_| self.css_prop.runtime_check),
       );
+      // Css calls which were replaced by their class name keep the
+      // extracted css comment but must not be annotated as pure
+      for span in replaced_css_spans {
+        if let Some(comments) = self.comments.take_leading(span.lo) {
+          for comment in comments {
+            if comment.text != pure_annotation().text {
+              self.comments.add_leading(span.lo, comment);
+            }
+          }
+        }
+      }
     }
   }
 
@@ -801,14 +1700,36 @@ where
     let is_top_level = !self.is_inside_css_expression();
     let current_variable_id = self.get_current_component_id();
 
+    // Names of the css expression and its nested expressions
+    // are derived from its content (only for NamingMode::ContentHash)
+    if is_top_level {
+      self
+        .naming_convention
+        .set_content(Some(get_template_fingerprint(n)));
+    }
+
+    if is_top_level && yak_library_function_name == "styled" {
+      self.process_styled_target(n, &current_variable_id);
+    }
+
+    // Expressions inside of exported functions are shared under their fallback name
+    // e.g. `export function createButton() { return styled.button``; }`
+    let export_names = if self.current_export_names.is_empty() && self.current_exported {
+      vec![current_variable_id.to_readable_string().into()]
+    } else {
+      self.current_export_names.clone()
+    };
+
     let mut transform: Box<dyn YakTransform> = match yak_library_function_name.deref() {
       // Styled Components transform works only on top level
       "styled" if is_top_level => Box::new(TransformStyled::new(
         &mut self.naming_convention,
+        self.styled_class_names.remove(&current_variable_id),
         current_variable_id.clone(),
         self.display_names,
-        self.current_exported,
+        export_names.clone(),
         self.transpilation_mode,
+        self.config.strip_consumed_props,
       )),
       // Keyframes transform works only on top level
       "keyframes" if is_top_level => Box::new(TransformKeyframes::with_animation_name(
@@ -827,7 +1748,7 @@ where
       "css" if is_top_level => Box::new(TransformCssMixin::new(
         &mut self.naming_convention,
         current_variable_id.clone(),
-        self.current_exported,
+        export_names.clone(),
         self.inside_element_with_css_attribute,
         self.transpilation_mode,
       )),
@@ -874,7 +1795,7 @@ where
     let (runtime_expressions, runtime_css_variables) =
       self.process_yak_literal(n, css_state.clone());
 
-    let transform_result = transform.transform_expression(
+    let mut transform_result = transform.transform_expression(
       n,
       runtime_expressions,
       &self.current_declaration,
@@ -882,12 +1803,49 @@ where
       self.yak_library_imports.as_mut().unwrap(),
     );
 
+    // Atomic declarations are shared by all components of the file
+    // and their css has to be emitted only once
+    let atomic_selectors = transform
+      .get_atomic_class_names()
+      .iter()
+      .map(|class_name| self.transpilation_mode.css_class_name(class_name))
+      .collect::<FxHashSet<_>>();
+    if !atomic_selectors.is_empty() {
+      transform_result
+        .css
+        .declarations
+        .retain(|declaration| match declaration.scope.first() {
+          Some(scope) if atomic_selectors.contains(&scope.name) => {
+            self.emitted_atomic_class_names.insert(scope.name.clone())
+          }
+          _ => true,
+        });
+    }
+
     if is_top_level {
       self.current_declaration = vec![];
+      self.top_level_css_variables = vec![];
+      self.css_variable_syntaxes.clear();
+      self.naming_convention.set_content(None);
     }
     let css_code = to_css(&transform_result.css.declarations);
     let result_span = transform_result.expression.span();
-    if (!css_code.is_empty() || self.current_exported) && is_top_level {
+    if is_top_level {
+      self.register_generated_names(n.span, result_span, &css_code);
+    }
+    if is_top_level {
+      for text in &transform_result.css.leading_comments {
+        self.comments.add_leading(
+          result_span.lo,
+          Comment {
+            kind: swc_core::common::comments::CommentKind::Block,
+            span: DUMMY_SP,
+            text: text.as_str().into(),
+          },
+        );
+      }
+    }
+    if (!css_code.is_empty() || !export_names.is_empty()) && is_top_level {
       if let Some(comment_prefix) = transform_result.css.comment_prefix.clone() {
         self.comments.add_leading(
           result_span.lo,
@@ -1009,13 +1967,29 @@ fn verify_valid_property_value_expr(expr: &Expr) -> bool {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use std::path::PathBuf;
+  use std::path::{Path, PathBuf};
+  use swc_core::common::Mark;
   use swc_core::ecma::{
     parser::{Syntax, TsSyntax},
+    transforms::base::resolver,
     transforms::testing::{test_fixture, test_transform, FixtureTestConfig},
     visit::visit_mut_pass,
   };
 
+  /// Reads the optional plugin configuration of a fixture from
+  /// the `config.json` file next to its input
+  fn fixture_config(input: &Path) -> Config {
+    let config_path = input.with_file_name("config.json");
+    if !config_path.exists() {
+      return Config::default();
+    }
+    let mut config: serde_json::Value =
+      serde_json::from_str(&std::fs::read_to_string(config_path).unwrap())
+        .expect("invalid fixture config.json");
+    config["basePath"] = "".into();
+    serde_json::from_value(config).expect("invalid fixture config.json")
+  }
+
   #[testing::fixture("tests/fixture/**/input.tsx")]
   fn fixture_dev(input: PathBuf) {
     test_fixture(
@@ -1024,14 +1998,21 @@ mod tests {
         ..Default::default()
       }),
       &|tester| {
-        visit_mut_pass(TransformVisitor::new(
-          Some(tester.comments.clone()),
-          "path/input.tsx",
-          false,
-          None,
-          true,
-          TranspilationMode::CssModule,
-        ))
+        (
+          resolver(Mark::new(), Mark::new(), true),
+          visit_mut_pass(
+            TransformVisitor::new(
+              Some(tester.comments.clone()),
+              "path/input.tsx",
+              Config {
+                minify: false,
+                display_names: true,
+                ..fixture_config(&input)
+              },
+            )
+            .with_source_map(tester.cm.clone()),
+          ),
+        )
       },
       &input,
       &input.with_file_name("output.dev.tsx"),
@@ -1051,14 +2032,21 @@ mod tests {
         ..Default::default()
       }),
       &|tester| {
-        visit_mut_pass(TransformVisitor::new(
-          Some(tester.comments.clone()),
-          "path/input.tsx",
-          true,
-          None,
-          false,
-          TranspilationMode::CssModule,
-        ))
+        (
+          resolver(Mark::new(), Mark::new(), true),
+          visit_mut_pass(
+            TransformVisitor::new(
+              Some(tester.comments.clone()),
+              "path/input.tsx",
+              Config {
+                minify: true,
+                display_names: false,
+                ..fixture_config(&input)
+              },
+            )
+            .with_source_map(tester.cm.clone()),
+          ),
+        )
       },
       &input,
       &input.with_file_name("output.prod.tsx"),
@@ -1115,4 +2103,232 @@ mod tests {
     assert_eq!(extract_leading_css_unit("1px"), None);
     assert_eq!(extract_leading_css_unit("color"), None);
   }
+
+  /// Transforms a module like the fixture tests and returns the number of
+  /// reported errors together with their rendered messages
+  fn transform_with_diagnostics(
+    filename: &str,
+    code: &str,
+    create_visitor: impl FnOnce()
+      -> TransformVisitor<swc_core::common::comments::SingleThreadedComments>,
+  ) -> (usize, String) {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use swc_core::common::errors::Handler;
+    use swc_core::common::{sync::Lrc, FileName, SourceMap, GLOBALS};
+    use swc_core::ecma::parser::parse_file_as_module;
+
+    #[derive(Clone, Default)]
+    struct Output(Arc<Mutex<Vec<u8>>>);
+    impl Write for Output {
+      fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+      }
+      fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+      }
+    }
+
+    let output = Output::default();
+    let err_count = GLOBALS.set(&Default::default(), || {
+      let cm: Lrc<SourceMap> = Default::default();
+      let fm = cm.new_source_file(
+        Lrc::new(FileName::Custom(filename.into())),
+        code.to_string(),
+      );
+      let mut program = Program::Module(
+        parse_file_as_module(
+          &fm,
+          Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+          }),
+          EsVersion::latest(),
+          None,
+          &mut vec![],
+        )
+        .unwrap(),
+      );
+      program.mutate(resolver(Mark::new(), Mark::new(), true));
+      let mut visitor = create_visitor();
+      let handler = Handler::with_emitter_writer(Box::new(output.clone()), Some(cm.clone()));
+      HANDLER.set(&handler, || program.visit_mut_with(&mut visitor));
+      handler.err_count()
+    });
+    let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    (err_count, output)
+  }
+
+  #[test]
+  fn test_module_imports_report_no_errors() {
+    let code =
+      std::fs::read_to_string("tests/fixture/cross-file-dynamic-import/input.tsx").unwrap();
+    let (err_count, output) = transform_with_diagnostics("input.tsx", &code, || {
+      TransformVisitor::new(Some(Default::default()), "input.tsx", Config::default())
+    });
+    assert_eq!(err_count, 0, "{}", output);
+  }
+
+  /// Transforms a file with a shared name registry and returns if errors were reported
+  fn transform_with_name_registry(
+    filename: &str,
+    code: &str,
+    config: Config,
+    name_registry: Rc<name_registry::InMemoryNameRegistry>,
+  ) -> bool {
+    let (err_count, _) = transform_with_diagnostics(filename, code, || {
+      TransformVisitor::new(Some(Default::default()), filename, config)
+        .with_name_registry(name_registry)
+    });
+    err_count > 0
+  }
+
+  #[test]
+  fn test_name_registry_reports_collisions() {
+    use name_registry::{GeneratedName, InMemoryNameRegistry, NameRegistry};
+
+    let code = r#"
+      import { styled } from "next-yak";
+      export const Button = styled.button`color: red;`;
+    "#;
+    let name_registry = Rc::new(InMemoryNameRegistry::default());
+    assert!(!transform_with_name_registry(
+      "src/Button.tsx",
+      code,
+      Config::default(),
+      name_registry.clone()
+    ));
+    // Transforming the same file again is no collision
+    assert!(!transform_with_name_registry(
+      "src/Button.tsx",
+      code,
+      Config::default(),
+      name_registry.clone()
+    ));
+
+    // Simulate a hash collision by registering the name for another file
+    let name = NamingConvention::new("src/Link.tsx", true, None).get_css_variable_name("Link");
+    name_registry.register(GeneratedName {
+      name,
+      filename: "src/Other.tsx".into(),
+      span: DUMMY_SP,
+      readable_name: "Other".into(),
+      content: None,
+    });
+    assert!(transform_with_name_registry(
+      "src/Link.tsx",
+      r#"
+        import { styled } from "next-yak";
+        export const Link = styled.a`color: red;`;
+      "#,
+      Config::default(),
+      name_registry
+    ));
+  }
+
+  #[test]
+  fn test_name_registry_allows_same_content_hash() {
+    let code = r#"
+      import { styled } from "next-yak";
+      export const Button = styled.button`color: red;`;
+    "#;
+    let name_registry = Rc::new(name_registry::InMemoryNameRegistry::default());
+    let config = Config {
+      naming_mode: NamingMode::ContentHash,
+      ..Default::default()
+    };
+    assert!(!transform_with_name_registry(
+      "src/Button.tsx",
+      code,
+      config.clone(),
+      name_registry.clone()
+    ));
+    // The same component in another file generates the same names on purpose
+    assert!(!transform_with_name_registry(
+      "src/legacy/Button.tsx",
+      code,
+      config,
+      name_registry
+    ));
+  }
+
+  #[test]
+  fn test_zero_runtime_summary() {
+    let code = r#"
+      import { styled } from "next-yak";
+      export const Title = styled.h1`color: red;`;
+      export const Button = styled.button`padding: ${({ $size }) => $size}px;`;
+      export const Link = styled.a`${({ $active }) => $active && "color: red;"}`;
+    "#;
+    let (err_count, output) = transform_with_diagnostics("input.tsx", code, || {
+      TransformVisitor::new(
+        Some(Default::default()),
+        "input.tsx",
+        Config {
+          zero_runtime: true,
+          ..Default::default()
+        },
+      )
+    });
+    assert_eq!(err_count, 2, "{}", output);
+    assert!(output
+      .contains("zeroRuntime: 2 component(s) in this file would need runtime work: Button, Link"));
+  }
+
+  #[test]
+  fn test_zero_runtime_reports_imported_dynamic_mixins() {
+    use module_graph::ProvidedModule;
+    use swc_core::common::{sync::Lrc, FileName, SourceMap};
+    use swc_core::ecma::parser::parse_file_as_module;
+
+    struct Typography;
+
+    impl ModuleGraphProvider for Typography {
+      fn load_module(&self, _importer: &str, import_source: &str) -> Option<ProvidedModule> {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+          Lrc::new(FileName::Custom(import_source.into())),
+          r#"
+            import { css } from "next-yak";
+            export const h1 = css`font-size: 2rem;`;
+            export const highlight = css`${({ $active }) => $active && css`color: red;`}`;
+          "#
+          .to_string(),
+        );
+        let module = parse_file_as_module(
+          &fm,
+          Syntax::Typescript(TsSyntax::default()),
+          EsVersion::latest(),
+          None,
+          &mut vec![],
+        )
+        .ok()?;
+        Some(ProvidedModule {
+          filename: "typography.ts".into(),
+          module,
+        })
+      }
+    }
+
+    let code = r#"
+      import { styled } from "next-yak";
+      import { h1, highlight } from "./typography";
+      export const Title = styled.h1`${h1};`;
+      export const Label = styled.span`${highlight};`;
+    "#;
+    let (err_count, output) = transform_with_diagnostics("input.tsx", code, || {
+      TransformVisitor::new(
+        Some(Default::default()),
+        "input.tsx",
+        Config {
+          zero_runtime: true,
+          ..Default::default()
+        },
+      )
+      .with_module_graph(Rc::new(Typography))
+    });
+    assert_eq!(err_count, 1, "{}", output);
+    assert!(output.contains("\"Label\" needs runtime work for this interpolation"));
+    assert!(output.contains("would need runtime work: Label"));
+  }
 }
@@ -0,0 +1,793 @@
+//! Resolves cross-file references at compile time.
+//!
+//! By default imported constants, mixins and selectors are encoded as
+//! `--yak-css-import: url(...)` tokens which are resolved by the webpack loader.
+//! Hosts without that loader (e.g. a CLI, the playground or turbopack-only builds)
+//! can provide the module graph instead, so the transform can inline the values directly.
+//!
+//! The playground provides its virtual files as module graph. The swc plugin can't read
+//! other files, so the loader still resolves all cross-file references there.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::atoms::Atom;
+use swc_core::common::comments::SingleThreadedComments;
+use swc_core::common::errors::{Handler, HANDLER};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::VisitMutWith;
+
+use crate::utils::cross_file_selectors::{encode_percent, ImportKind, ImportType};
+use crate::variable_visitor::VariableVisitor;
+use crate::{Config, TransformVisitor};
+
+/// Maximum depth of nested cross-file references
+/// e.g. a mixin which uses a mixin of a third file
+const MAX_RESOLVE_DEPTH: usize = 10;
+
+/// A parsed module of the module graph
+pub struct ProvidedModule {
+  /// File name of the module, used to generate its class names
+  /// (must match the file name which is used when the module itself is transformed)
+  pub filename: String,
+  pub module: Module,
+}
+
+/// Provides the modules imported by the transformed files
+pub trait ModuleGraphProvider {
+  /// Loads the module imported by `importer` with `import_source`
+  /// e.g. ("src/Button.tsx", "./theme") -> src/theme.ts
+  ///
+  /// Returns None if the module can't be resolved, in which case the
+  /// reference is left for the loader
+  fn load_module(&self, importer: &str, import_source: &str) -> Option<ProvidedModule>;
+}
+
+/// A cross-file reference which was resolved by the module graph
+#[derive(Debug, PartialEq)]
+pub(crate) struct ResolvedImport {
+  /// The css code which replaces the reference
+  /// e.g. `red` or `font-size: 2rem;` or `:global(.theme_Button_m7uBBu)`
+  pub css: String,
+  /// The mixin has to be called with the props of the importing component
+  /// e.g. export const highlight = css`${({$active}) => $active && css`color: red;`}`
+  pub is_dynamic_mixin: bool,
+}
+
+impl ResolvedImport {
+  fn new(css: String) -> Self {
+    Self {
+      css,
+      is_dynamic_mixin: false,
+    }
+  }
+}
+
+/// Resolves cross-file references with the help of a [ModuleGraphProvider]
+#[derive(Clone)]
+pub(crate) struct ModuleGraph {
+  provider: Rc<dyn ModuleGraphProvider>,
+  config: Config,
+  depth: usize,
+  /// Exports of the already transformed modules by file name
+  /// Shared with the nested graphs so every module is transformed at most once
+  /// None if the module failed or is still being transformed (circular import)
+  transformed_modules: Rc<RefCell<FxHashMap<String, Option<Rc<ModuleExports>>>>>,
+}
+
+impl ModuleGraph {
+  pub fn new(provider: Rc<dyn ModuleGraphProvider>, config: Config) -> Self {
+    Self {
+      provider,
+      config,
+      depth: 0,
+      transformed_modules: Default::default(),
+    }
+  }
+
+  /// Resolves an imported value to the css code which replaces it
+  /// e.g. `colors.primary` -> `red`
+  /// e.g. `typography.h1` -> `font-size: 2rem;\nfont-weight: bold;`
+  /// e.g. `Button` -> `:global(.theme_Button_m7uBBu)`
+  pub fn resolve(
+    &self,
+    importer: &str,
+    import_kind: &ImportKind,
+    import_chain: &[Atom],
+    import_type: &ImportType,
+  ) -> Option<ResolvedImport> {
+    if self.depth >= MAX_RESOLVE_DEPTH {
+      return None;
+    }
+    let mut provided = self
+      .provider
+      .load_module(importer, import_kind.import_source().as_str())?;
+
+    // e.g. import { colors } from "./theme" + colors.primary -> ["colors", "primary"]
+    let mut export_chain: Vec<Atom> = match import_kind {
+      ImportKind::Default { .. } => vec!["default".into()],
+      ImportKind::Named { external_name, .. } => vec![external_name.clone()],
+      ImportKind::Namespace { .. } => vec![],
+    };
+    export_chain.extend(import_chain.iter().skip(1).cloned());
+    if export_chain.is_empty() {
+      return None;
+    }
+
+    // Literal values can be read directly from the source
+    // unless they are changed after their declaration
+    let mut variables = VariableVisitor::new();
+    provided.module.visit_mut_with(&mut variables);
+    if let Some(value) = find_exported_literal(&provided.module, &variables, &export_chain) {
+      return Some(ResolvedImport::new(value));
+    }
+
+    // Follow re-exports e.g. of barrel files
+    let mut nested_graph = self.clone();
+    nested_graph.depth += 1;
+    for (re_export_kind, re_export_chain) in find_re_exports(&provided.module, &export_chain) {
+      if let Some(value) = nested_graph.resolve(
+        &provided.filename,
+        &re_export_kind,
+        &re_export_chain,
+        import_type,
+      ) {
+        return Some(value);
+      }
+    }
+
+    let exports = self.transform_module(provided)?;
+    match import_type {
+      ImportType::Selector => exports
+        .styled_components
+        .get(
+          &export_chain
+            .iter()
+            .map(|part| part.as_str())
+            .collect::<Vec<_>>()
+            .join("."),
+        )
+        .map(|class_name| {
+          ResolvedImport::new(self.config.transpilation_mode.css_class_name(class_name))
+        }),
+      ImportType::Mixin | ImportType::StaticMixin | ImportType::Value => {
+        let export_chain = export_chain
+          .iter()
+          .map(|part| encode_percent(part))
+          .collect::<Vec<_>>()
+          .join(":");
+        exports.mixins.get(&export_chain).map(|css| ResolvedImport {
+          css: css.clone(),
+          is_dynamic_mixin: exports.dynamic_mixins.contains(&export_chain),
+        })
+      }
+    }
+  }
+
+  /// Transforms the provided module the same way it is transformed on its own
+  /// and collects its exported styled components and mixins
+  fn transform_module(&self, provided: ProvidedModule) -> Option<Rc<ModuleExports>> {
+    if let Some(exports) = self.transformed_modules.borrow().get(&provided.filename) {
+      return exports.clone();
+    }
+    // Circular imports are left to the loader
+    self
+      .transformed_modules
+      .borrow_mut()
+      .insert(provided.filename.clone(), None);
+
+    let filename = provided.filename.clone();
+    let exports = self.collect_module_exports(provided).map(Rc::new);
+    self
+      .transformed_modules
+      .borrow_mut()
+      .insert(filename, exports.clone());
+    exports
+  }
+
+  fn collect_module_exports(&self, provided: ProvidedModule) -> Option<ModuleExports> {
+    let comments = SingleThreadedComments::default();
+    let mut program = Program::Module(provided.module);
+    let mut nested_graph = self.clone();
+    nested_graph.depth += 1;
+    // Runtime work of the other file is reported by the importing file if it uses it
+    // e.g. a dynamic mixin in `zero_runtime` mode
+    let mut visitor = TransformVisitor::new(
+      Some(comments.clone()),
+      &provided.filename,
+      Config {
+        zero_runtime: false,
+        ..self.config.clone()
+      },
+    );
+    visitor.module_graph = Some(nested_graph);
+
+    // Errors belong to the other file and are reported once it is transformed itself
+    let handler = Handler::with_emitter_writer(Box::new(std::io::sink()), None);
+    HANDLER.set(&handler, || program.visit_mut_with(&mut visitor));
+    if handler.has_errors() {
+      return None;
+    }
+
+    let mut exports = ModuleExports::default();
+    let (leading, _) = comments.borrow_all();
+    for comment in leading.values().flatten() {
+      exports.add_comment(&comment.text);
+    }
+    Some(exports)
+  }
+}
+
+/// Exported styled components and mixins of a transformed module
+#[derive(Default)]
+struct ModuleExports {
+  /// e.g. "Button" -> "theme_Button_m7uBBu"
+  styled_components: FxHashMap<String, String>,
+  /// e.g. "typography:h1" -> "font-size: 2rem;"
+  mixins: FxHashMap<String, String>,
+  /// Mixins which need the props of the importing component e.g. "typography:h1"
+  dynamic_mixins: FxHashSet<String>,
+}
+
+impl ModuleExports {
+  /// Reads the exports from the comments which are also used by the loader
+  /// e.g. `YAK EXPORTED STYLED:Button:theme_Button_m7uBBu`
+  /// e.g. `YAK EXPORTED MIXIN:typography:h1\nfont-size: 2rem;`
+  /// e.g. `YAK DYNAMIC MIXIN:typography:h1`
+  fn add_comment(&mut self, text: &str) {
+    // Styled components are followed by extracted css e.g. `...*//*YAK Extracted CSS:...`
+    let text = text.split("*/").next().unwrap_or_default();
+    if let Some(mixin) = text.strip_prefix("YAK DYNAMIC MIXIN:") {
+      self.dynamic_mixins.insert(mixin.trim().to_string());
+    } else if let Some(styled) = text.strip_prefix("YAK EXPORTED STYLED:") {
+      if let Some((name, class_name)) = styled.split_once(':') {
+        self
+          .styled_components
+          .insert(name.to_string(), class_name.to_string());
+      }
+    } else if let Some(mixin) = text.strip_prefix("YAK EXPORTED MIXIN:") {
+      let (name, css) = mixin.split_once('\n').unwrap_or((mixin, ""));
+      self.mixins.insert(name.to_string(), css.trim().to_string());
+    }
+  }
+}
+
+/// Finds a string or number literal exported by a module
+/// e.g. ["colors", "primary"] in `export const colors = { primary: "red" }`
+///
+/// Exported variables which are reassigned or mutated are left to the loader
+/// (the module is not resolved, so a write to a shadowing variable of the
+/// same name counts as well)
+fn find_exported_literal(
+  module: &Module,
+  variables: &VariableVisitor,
+  export_chain: &[Atom],
+) -> Option<String> {
+  let (export_name, property_chain) = export_chain.split_first()?;
+  let local_consts = module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        decl: Decl::Var(var_decl),
+        ..
+      }))
+      | ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+        if var_decl.kind == VarDeclKind::Const =>
+      {
+        Some(var_decl.decls.iter())
+      }
+      _ => None,
+    })
+    .flatten()
+    .filter_map(|declarator| match (&declarator.name, &declarator.init) {
+      (Pat::Ident(ident), Some(init)) => Some((ident.sym.clone(), (ident.to_id(), init))),
+      _ => None,
+    })
+    .collect::<FxHashMap<_, _>>();
+
+  let exported_value = module.body.iter().find_map(|item| match item {
+    // e.g. export const colors = { ... }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+      decl: Decl::Var(var_decl),
+      ..
+    })) => var_decl
+      .decls
+      .iter()
+      .find_map(|declarator| match &declarator.name {
+        Pat::Ident(ident) if ident.sym == *export_name => declarator
+          .init
+          .as_deref()
+          .map(|init| (Some(ident.to_id()), init)),
+        _ => None,
+      }),
+    // e.g. export default { ... }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default))
+      if export_name == "default" =>
+    {
+      Some((None, &*export_default.expr))
+    }
+    // e.g. export { colors as theme }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+      specifiers,
+      src: None,
+      ..
+    })) => specifiers.iter().find_map(|specifier| match specifier {
+      ExportSpecifier::Named(ExportNamedSpecifier {
+        orig: ModuleExportName::Ident(orig),
+        exported,
+        ..
+      }) => {
+        let exported_name = match exported {
+          Some(ModuleExportName::Ident(ident)) => &ident.sym,
+          Some(ModuleExportName::Str(str)) => &str.value,
+          None => &orig.sym,
+        };
+        if exported_name == export_name {
+          local_consts
+            .get(&orig.sym)
+            .map(|(id, init)| (Some(id.clone()), &***init))
+        } else {
+          None
+        }
+      }
+      _ => None,
+    }),
+    _ => None,
+  })?;
+
+  let (binding, mut value) = exported_value;
+  if binding.is_some_and(|binding| variables.is_mutated(&binding)) {
+    return None;
+  }
+  for property in property_chain {
+    value = find_object_property(unwrap_expr(value), property)?;
+  }
+  match unwrap_expr(value) {
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+    Expr::Lit(Lit::Num(num)) => Some(num.value.to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl.quasis[0]
+      .cooked
+      .as_ref()
+      .map(|cooked| cooked.to_string()),
+    _ => None,
+  }
+}
+
+/// Finds the re-exports of other modules which might provide the export chain
+/// The result can be resolved like an import of the re-exported module
+/// e.g. `export { Button as PrimaryButton } from "./Button"` -> (Named { Button }, [PrimaryButton, ...])
+/// e.g. `export * as buttons from "./buttons"` -> (Namespace, [buttons, ...])
+/// e.g. `export * from "./buttons"` -> (Namespace, [*, Button, ...])
+fn find_re_exports(module: &Module, export_chain: &[Atom]) -> Vec<(ImportKind, Vec<Atom>)> {
+  let Some(export_name) = export_chain.first() else {
+    return vec![];
+  };
+  let mut re_exports = vec![];
+  for item in &module.body {
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        specifiers,
+        src: Some(src),
+        ..
+      })) => {
+        for specifier in specifiers {
+          match specifier {
+            ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) => {
+              let orig_name = module_export_name(orig);
+              if exported
+                .as_ref()
+                .map(module_export_name)
+                .unwrap_or(orig_name)
+                == export_name
+              {
+                re_exports.push((
+                  ImportKind::Named {
+                    external_name: orig_name.clone(),
+                    import_source: src.value.clone(),
+                  },
+                  export_chain.to_vec(),
+                ));
+              }
+            }
+            ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => {
+              if module_export_name(name) == export_name {
+                re_exports.push((
+                  ImportKind::Namespace {
+                    import_source: src.value.clone(),
+                  },
+                  export_chain.to_vec(),
+                ));
+              }
+            }
+            ExportSpecifier::Default(_) => {}
+          }
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { src, .. })) => {
+        re_exports.push((
+          ImportKind::Namespace {
+            import_source: src.value.clone(),
+          },
+          std::iter::once(Atom::from("*"))
+            .chain(export_chain.iter().cloned())
+            .collect(),
+        ));
+      }
+      _ => {}
+    }
+  }
+  re_exports
+}
+
+fn module_export_name(name: &ModuleExportName) -> &Atom {
+  match name {
+    ModuleExportName::Ident(ident) => &ident.sym,
+    ModuleExportName::Str(str) => &str.value,
+  }
+}
+
+/// Finds the value of a property in an object literal
+/// e.g. primary in `{ primary: "red" }`
+fn find_object_property<'a>(expr: &'a Expr, property: &Atom) -> Option<&'a Expr> {
+  let Expr::Object(object) = expr else {
+    return None;
+  };
+  object.props.iter().rev().find_map(|prop| match prop {
+    PropOrSpread::Prop(prop) => match &**prop {
+      Prop::KeyValue(KeyValueProp { key, value }) => {
+        let key = match key {
+          PropName::Ident(ident) => ident.sym.to_string(),
+          PropName::Str(str) => str.value.to_string(),
+          PropName::Num(num) => num.value.to_string(),
+          _ => return None,
+        };
+        (key == property.as_str()).then_some(&**value)
+      }
+      _ => None,
+    },
+    _ => None,
+  })
+}
+
+/// Removes typescript and parenthesis wrappers
+/// e.g. `{ primary: "red" } as const`
+fn unwrap_expr(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(ParenExpr { expr, .. })
+    | Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => unwrap_expr(expr),
+    _ => expr,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::{sync::Lrc, FileName, SourceMap, GLOBALS};
+  use swc_core::ecma::parser::{parse_file_as_module, Syntax, TsSyntax};
+
+  struct InMemoryModules(FxHashMap<&'static str, &'static str>);
+
+  impl ModuleGraphProvider for InMemoryModules {
+    fn load_module(&self, _importer: &str, import_source: &str) -> Option<ProvidedModule> {
+      let source = self.0.get(import_source)?;
+      let cm: Lrc<SourceMap> = Default::default();
+      let fm = cm.new_source_file(
+        Lrc::new(FileName::Custom(import_source.into())),
+        source.to_string(),
+      );
+      let module = parse_file_as_module(
+        &fm,
+        Syntax::Typescript(TsSyntax::default()),
+        EsVersion::latest(),
+        None,
+        &mut vec![],
+      )
+      .ok()?;
+      Some(ProvidedModule {
+        filename: format!("{}.ts", import_source.trim_start_matches("./")),
+        module,
+      })
+    }
+  }
+
+  fn test_modules() -> Rc<InMemoryModules> {
+    Rc::new(InMemoryModules(FxHashMap::from_iter([
+      (
+        "./index",
+        r#"
+        export { colors as palette } from "./theme";
+        export * as theme from "./theme";
+        export * from "./theme";
+      "#,
+      ),
+      (
+        "./theme",
+        r#"
+        import { css, styled } from "next-yak";
+        const spacing = 8;
+        export const colors = { primary: "red", sizes: { small: 4 } } as const;
+        export const overrides = { primary: "red" };
+        Object.assign(overrides, { primary: "blue" });
+        export { spacing as space };
+        export const typography = {
+          h1: css`
+            font-size: 2rem;
+          `,
+          highlight: css`
+            ${({ $active }) => $active && css`color: red;`}
+          `,
+        };
+        export const Button = styled.button`
+          color: red;
+        `;
+        export default "blue";
+      "#,
+      ),
+    ])))
+  }
+
+  fn resolve(
+    import_kind: ImportKind,
+    import_chain: &[&str],
+    import_type: ImportType,
+  ) -> Option<String> {
+    resolve_import(import_kind, import_chain, import_type).map(|resolved| resolved.css)
+  }
+
+  fn resolve_import(
+    import_kind: ImportKind,
+    import_chain: &[&str],
+    import_type: ImportType,
+  ) -> Option<ResolvedImport> {
+    let graph = ModuleGraph::new(
+      test_modules(),
+      Config {
+        minify: false,
+        ..Default::default()
+      },
+    );
+    GLOBALS.set(&Default::default(), || {
+      graph.resolve(
+        "input.tsx",
+        &import_kind,
+        &import_chain
+          .iter()
+          .map(|part| Atom::from(*part))
+          .collect::<Vec<_>>(),
+        &import_type,
+      )
+    })
+  }
+
+  fn named(name: &str) -> ImportKind {
+    ImportKind::Named {
+      external_name: name.into(),
+      import_source: "./theme".into(),
+    }
+  }
+
+  #[test]
+  fn test_resolve_constants() {
+    assert_eq!(
+      resolve(named("colors"), &["colors", "primary"], ImportType::Value),
+      Some("red".to_string())
+    );
+    assert_eq!(
+      resolve(
+        named("colors"),
+        &["colors", "sizes", "small"],
+        ImportType::Value
+      ),
+      Some("4".to_string())
+    );
+    assert_eq!(
+      resolve(named("space"), &["space"], ImportType::Value),
+      Some("8".to_string())
+    );
+    assert_eq!(
+      resolve(
+        ImportKind::Default {
+          import_source: "./theme".into()
+        },
+        &["theme"],
+        ImportType::Value
+      ),
+      Some("blue".to_string())
+    );
+  }
+
+  #[test]
+  fn test_resolve_mixins_and_selectors() {
+    assert_eq!(
+      resolve(
+        named("typography"),
+        &["typography", "h1"],
+        ImportType::Mixin
+      ),
+      Some("font-size: 2rem;".to_string())
+    );
+    assert_eq!(
+      resolve(
+        ImportKind::Namespace {
+          import_source: "./theme".into()
+        },
+        &["theme", "Button"],
+        ImportType::Selector
+      ),
+      Some(":global(.theme_Button_f3LUs5)".to_string())
+    );
+  }
+
+  #[test]
+  fn test_resolve_dynamic_mixins() {
+    assert_eq!(
+      resolve_import(
+        named("typography"),
+        &["typography", "h1"],
+        ImportType::Mixin
+      )
+      .map(|resolved| resolved.is_dynamic_mixin),
+      Some(false)
+    );
+    assert_eq!(
+      resolve_import(
+        named("typography"),
+        &["typography", "highlight"],
+        ImportType::Mixin
+      )
+      .map(|resolved| resolved.is_dynamic_mixin),
+      Some(true)
+    );
+  }
+
+  #[test]
+  fn test_modules_are_transformed_once() {
+    let graph = ModuleGraph::new(test_modules(), Config::default());
+    GLOBALS.set(&Default::default(), || {
+      for export_name in ["h1", "highlight"] {
+        assert!(graph
+          .resolve(
+            "input.tsx",
+            &named("typography"),
+            &["typography".into(), export_name.into()],
+            &ImportType::Mixin,
+          )
+          .is_some());
+      }
+    });
+    assert_eq!(
+      graph
+        .transformed_modules
+        .borrow()
+        .keys()
+        .collect::<Vec<_>>(),
+      vec!["theme.ts"]
+    );
+  }
+
+  #[test]
+  fn test_only_dynamic_mixins_are_passed_to_the_runtime() {
+    use swc_core::ecma::parser::parse_file_as_module;
+    use swc_core::ecma::visit::{Visit, VisitWith};
+
+    /// Counts the lazy mixin references e.g. `() => typography.highlight`
+    #[derive(Default)]
+    struct MixinThunks(usize);
+    impl Visit for MixinThunks {
+      fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        if arrow.params.is_empty() {
+          self.0 += 1;
+        }
+        arrow.visit_children_with(self);
+      }
+    }
+
+    let code = r#"
+      import { styled } from "next-yak";
+      import { typography } from "./theme";
+      export const Title = styled.h1`${typography.h1};`;
+      export const Highlight = styled.p`${typography.highlight};`;
+      export const Link = styled.a`
+        &:hover {
+          ${typography.highlight};
+        }
+      `;
+    "#;
+    GLOBALS.set(&Default::default(), || {
+      let cm: Lrc<SourceMap> = Default::default();
+      let fm = cm.new_source_file(
+        Lrc::new(FileName::Custom("input.tsx".into())),
+        code.to_string(),
+      );
+      let mut program = Program::Module(
+        parse_file_as_module(
+          &fm,
+          Syntax::Typescript(TsSyntax::default()),
+          EsVersion::latest(),
+          None,
+          &mut vec![],
+        )
+        .unwrap(),
+      );
+      let mut visitor = TransformVisitor::new(
+        Some(SingleThreadedComments::default()),
+        "input.tsx",
+        Config::default(),
+      )
+      .with_module_graph(test_modules());
+      let handler = Handler::with_emitter_writer(Box::new(std::io::sink()), None);
+      HANDLER.set(&handler, || program.visit_mut_with(&mut visitor));
+      // The dynamic mixin can't keep the `&:hover` scope of Link
+      assert_eq!(handler.err_count(), 1);
+      let mut thunks = MixinThunks::default();
+      program.visit_with(&mut thunks);
+      assert_eq!(thunks.0, 1);
+    });
+  }
+
+  #[test]
+  fn test_resolve_re_exports() {
+    let barrel = |name: &str| ImportKind::Named {
+      external_name: name.into(),
+      import_source: "./index".into(),
+    };
+    assert_eq!(
+      resolve(
+        barrel("palette"),
+        &["palette", "primary"],
+        ImportType::Value
+      ),
+      Some("red".to_string())
+    );
+    assert_eq!(
+      resolve(
+        barrel("theme"),
+        &["theme", "typography", "h1"],
+        ImportType::Mixin
+      ),
+      Some("font-size: 2rem;".to_string())
+    );
+    assert_eq!(
+      resolve(
+        ImportKind::Namespace {
+          import_source: "./index".into()
+        },
+        &["barrel", "Button"],
+        ImportType::Selector
+      ),
+      Some(":global(.theme_Button_f3LUs5)".to_string())
+    );
+  }
+
+  #[test]
+  fn test_unresolved_references() {
+    assert_eq!(
+      resolve(named("colors"), &["colors", "missing"], ImportType::Value),
+      None
+    );
+    // Mutated values are left to the loader
+    assert_eq!(
+      resolve(
+        named("overrides"),
+        &["overrides", "primary"],
+        ImportType::Value
+      ),
+      None
+    );
+    assert_eq!(
+      resolve(
+        ImportKind::Named {
+          external_name: "colors".into(),
+          import_source: "./unknown".into(),
+        },
+        &["colors"],
+        ImportType::Mixin
+      ),
+      None
+    );
+  }
+}
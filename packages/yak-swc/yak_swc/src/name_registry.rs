@@ -0,0 +1,129 @@
+//! Detects generated names which collide across files.
+//!
+//! Names are unique within a file, but the short hashes of minified names
+//! might collide between files of large projects. Hosts which transform the
+//! whole project (e.g. a CLI) can share one registry between all files to
+//! report those collisions.
+//!
+//! The swc plugin transforms every file on its own - with `check_unique_names` it lists
+//! the generated names in a comment instead, which the loader merges and checks.
+
+use std::cell::RefCell;
+
+use rustc_hash::FxHashMap;
+use swc_core::common::Span;
+
+/// A generated class name, keyframe name or css variable name
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedName {
+  /// e.g. "ym7uBBu"
+  pub name: String,
+  /// File which generated the name
+  pub filename: String,
+  /// Position of the css expression which generated the name
+  pub span: Span,
+  /// Readable name of the component, mixin or variable
+  /// e.g. "Button" or "Button__active"
+  pub readable_name: String,
+  /// Css of the expression which generated the name (only for `NamingMode::ContentHash`)
+  /// The same component in different files generates the same names on purpose
+  pub content: Option<String>,
+}
+
+impl GeneratedName {
+  /// Names of different files collide unless they are generated for the same content
+  fn collides_with(&self, other: &GeneratedName) -> bool {
+    self.filename != other.filename && (self.content.is_none() || self.content != other.content)
+  }
+}
+
+/// Registry of all names generated in a project
+pub trait NameRegistry {
+  /// Registers a generated name
+  ///
+  /// Returns the previously registered name if it was generated by another file
+  /// (for another content in `NamingMode::ContentHash`)
+  fn register(&self, name: GeneratedName) -> Option<GeneratedName>;
+}
+
+/// [NameRegistry] which keeps all names in memory
+/// It can only be shared between files which are transformed on the same thread
+#[derive(Default)]
+pub struct InMemoryNameRegistry {
+  names: RefCell<FxHashMap<String, GeneratedName>>,
+}
+
+impl NameRegistry for InMemoryNameRegistry {
+  fn register(&self, name: GeneratedName) -> Option<GeneratedName> {
+    let mut names = self.names.borrow_mut();
+    match names.get(&name.name) {
+      Some(existing) if existing.collides_with(&name) => Some(existing.clone()),
+      // The same content of another file keeps the first registration
+      Some(existing) if existing.filename != name.filename => None,
+      // Files might be transformed again e.g. after a change
+      _ => {
+        names.insert(name.name.clone(), name);
+        None
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::DUMMY_SP;
+
+  fn generated(name: &str, filename: &str) -> GeneratedName {
+    GeneratedName {
+      name: name.to_string(),
+      filename: filename.to_string(),
+      span: DUMMY_SP,
+      readable_name: "Button".to_string(),
+      content: None,
+    }
+  }
+
+  fn generated_from_content(name: &str, filename: &str, content: &str) -> GeneratedName {
+    GeneratedName {
+      content: Some(content.to_string()),
+      ..generated(name, filename)
+    }
+  }
+
+  #[test]
+  fn test_register_collisions() {
+    let registry = InMemoryNameRegistry::default();
+    assert_eq!(registry.register(generated("ya", "a.tsx")), None);
+    assert_eq!(registry.register(generated("yb", "b.tsx")), None);
+    assert_eq!(
+      registry.register(generated("ya", "b.tsx")),
+      Some(generated("ya", "a.tsx"))
+    );
+  }
+
+  #[test]
+  fn test_register_same_file_again() {
+    let registry = InMemoryNameRegistry::default();
+    assert_eq!(registry.register(generated("ya", "a.tsx")), None);
+    assert_eq!(registry.register(generated("ya", "a.tsx")), None);
+  }
+
+  #[test]
+  fn test_register_same_content() {
+    let registry = InMemoryNameRegistry::default();
+    let color_red = "color: red;";
+    assert_eq!(
+      registry.register(generated_from_content("ya", "a.tsx", color_red)),
+      None
+    );
+    assert_eq!(
+      registry.register(generated_from_content("ya", "b.tsx", color_red)),
+      None
+    );
+    assert_eq!(
+      registry.register(generated_from_content("ya", "c.tsx", "color: blue;")),
+      Some(generated_from_content("ya", "a.tsx", color_red))
+    );
+  }
+}
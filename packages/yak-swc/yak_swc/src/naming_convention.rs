@@ -1,3 +1,4 @@
+use crate::utils::atomic_css::AtomicClassNames;
 use crate::utils::css_hash::hash_to_css;
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
@@ -10,6 +11,39 @@ pub struct NamingConvention {
   file_name_hash: Option<String>,
   minify: bool,
   prefix: String,
+  naming_mode: NamingMode,
+  /// Fingerprint of the current css expression
+  content: Option<String>,
+  /// Custom template for generated names e.g. "[file]-[component]-[hash:6]"
+  class_name_template: Option<String>,
+  /// Generated names and their readable names (only collected if enabled)
+  generated_names: Option<Vec<(String, String)>>,
+  transpilation_mode: TranspilationMode,
+}
+
+/// Placeholders which can be used in a `classNameTemplate`
+/// `[project]` is an alias of `[prefix]`
+const CLASS_NAME_TEMPLATE_PLACEHOLDERS: &[&str] = &[
+  "prefix",
+  "project",
+  "file",
+  "component",
+  "hash",
+  "fileHash",
+  "contentHash",
+  "counter",
+];
+
+/// Decides which input is hashed to generate unique names
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub enum NamingMode {
+  /// Hash of the file name and a counter per name
+  /// Names change if the file is moved or a component with the same name is added above
+  #[default]
+  FileHash,
+  /// Hash of the readable name and the content of the css expression
+  /// Names stay stable across unrelated edits and file moves
+  ContentHash,
 }
 
 /// A naming convention that generates safe unique names for CSS variables, classes, and other identifiers.
@@ -22,6 +56,11 @@ impl NamingConvention {
       file_name: file_name.as_ref().into(),
       file_name_base: None,
       file_name_hash: None,
+      naming_mode: NamingMode::default(),
+      content: None,
+      class_name_template: None,
+      generated_names: None,
+      transpilation_mode: TranspilationMode::CssModule,
       minify,
       prefix: prefix.unwrap_or_else(|| {
         if !minify {
@@ -34,6 +73,66 @@ impl NamingConvention {
     }
   }
 
+  pub fn with_naming_mode(mut self, naming_mode: NamingMode) -> Self {
+    self.naming_mode = naming_mode;
+    self
+  }
+
+  pub fn with_transpilation_mode(mut self, transpilation_mode: TranspilationMode) -> Self {
+    self.transpilation_mode = transpilation_mode;
+    self
+  }
+
+  pub fn with_class_name_template(mut self, class_name_template: Option<String>) -> Self {
+    self.class_name_template = class_name_template;
+    self
+  }
+
+  /// Collects all generated names e.g. to detect collisions with other files
+  pub fn track_generated_names(&mut self) {
+    self.generated_names.get_or_insert_with(Vec::new);
+  }
+
+  /// Returns the names which were generated since the last call
+  /// e.g. [("ym7uBBu", "Button"), ("ym7uBBu1", "Button__active")]
+  pub fn take_generated_names(&mut self) -> Vec<(String, String)> {
+    self
+      .generated_names
+      .as_mut()
+      .map(std::mem::take)
+      .unwrap_or_default()
+  }
+
+  fn track_generated_name(&mut self, name: &str, base_name: &str) {
+    if let Some(generated_names) = &mut self.generated_names {
+      generated_names.push((name.to_string(), base_name.to_string()));
+    }
+  }
+
+  /// Sets the content of the current css expression and returns the previous one
+  /// In `NamingMode::ContentHash` the content is hashed instead of the file name
+  pub fn set_content(&mut self, content: Option<String>) -> Option<String> {
+    std::mem::replace(&mut self.content, content)
+  }
+
+  /// Returns the hash which makes a name unique
+  /// e.g. the file name hash or the hash of the name and the current css content
+  fn get_hash(&mut self, base_name: &str) -> String {
+    match self.naming_mode {
+      NamingMode::FileHash => self.get_file_name_hash(),
+      NamingMode::ContentHash => self.get_content_hash(base_name),
+    }
+  }
+
+  /// Returns the hash of a name and the current css content
+  /// Falls back to the file name hash outside of css expressions
+  fn get_content_hash(&mut self, base_name: &str) -> String {
+    match &self.content {
+      Some(content) => hash_to_css(&format!("{}\n{}", base_name, content)),
+      None => self.get_file_name_hash(),
+    }
+  }
+
   /// Returns the hash of the file name
   /// This allows to generate unique names based on the file name
   /// which will be consistent across multiple builds
@@ -84,8 +183,73 @@ impl NamingConvention {
     }
   }
 
+  /// Generate a unique name from the `classNameTemplate`
+  /// e.g. "[file]-[component]-[hash:4]" -> "App-Button-m7uB"
+  ///
+  /// The counter which keeps names unique is added at the `[counter]` placeholder
+  /// or at the end of the name
+  fn generate_name_from_template(&mut self, template: &str, base_name: &str) -> String {
+    let mut name = String::new();
+    let mut name_after_counter: Option<String> = None;
+    let mut rest = template;
+    while let Some((before, placeholder, after)) = next_placeholder(rest) {
+      let current = name_after_counter.as_mut().unwrap_or(&mut name);
+      current.push_str(before);
+      rest = after;
+      let (placeholder, length) = match placeholder.split_once(':') {
+        Some((placeholder, length)) => (placeholder, length.parse::<usize>().ok()),
+        None => (placeholder, None),
+      };
+      let value = match placeholder {
+        "prefix" | "project" => self.prefix.clone(),
+        "file" => self.get_base_file_name(),
+        "component" if base_name.is_empty() => "yak".to_string(),
+        "component" => base_name.to_string(),
+        "hash" => self.get_hash(base_name),
+        "fileHash" => self.get_file_name_hash(),
+        "contentHash" => self.get_content_hash(base_name),
+        "counter" => {
+          name_after_counter.get_or_insert_with(String::new);
+          continue;
+        }
+        _ => continue,
+      };
+      let current = name_after_counter.as_mut().unwrap_or(&mut name);
+      match length {
+        Some(length) => current.extend(value.chars().take(length)),
+        None => current.push_str(&value),
+      }
+    }
+    name_after_counter
+      .as_mut()
+      .unwrap_or(&mut name)
+      .push_str(rest);
+
+    let Some(name_after_counter) = name_after_counter else {
+      return self.generate_unique_name(&name);
+    };
+    let escaped_name = escape_css_identifier(&format!("{}{}", name, name_after_counter));
+    let counter = self.postfix_counters.entry(escaped_name).or_insert(0);
+    *counter += 1;
+    let postfix = match *counter {
+      1 => String::new(),
+      counter if !self.minify => format!("{:02}", counter - 1),
+      counter => minify_number(counter - 1),
+    };
+    escape_css_identifier(&format!("{}{}{}", name, postfix, name_after_counter))
+  }
+
   /// Generate a unique CSS variable name based on the file name and a base name
   pub fn get_css_variable_name(&mut self, base_name: &str) -> String {
+    let name = self.create_css_variable_name(base_name);
+    self.track_generated_name(&name, base_name);
+    name
+  }
+
+  fn create_css_variable_name(&mut self, base_name: &str) -> String {
+    if let Some(template) = self.class_name_template.clone() {
+      return self.generate_name_from_template(&template, base_name);
+    }
     let name: String = if !self.minify {
       if base_name.is_empty() {
         format!("{}_var_", self.get_base_file_name())
@@ -99,13 +263,32 @@ impl NamingConvention {
       "{}{}{}",
       self.prefix.clone(),
       name,
-      self.get_file_name_hash()
+      self.get_hash(base_name)
     );
     self.generate_unique_name(&css_variable_name)
   }
 
+  /// Class name generator for atomic declarations
+  pub fn get_atomic_class_names(&self) -> AtomicClassNames {
+    AtomicClassNames::new(self.prefix.clone(), self.minify)
+  }
+
   /// Generate a unique CSS keyframe name based on the file name and a base name
   pub fn get_keyframe_name(&mut self, base_name: &str) -> String {
+    let mut name = self.create_keyframe_name(base_name);
+    // Keyframes can't be limited to the scope attribute, so they get a prefix
+    // to not collide with the keyframes of the host page (e.g. of another yak build)
+    if self.transpilation_mode == TranspilationMode::Scoped {
+      name.insert_str(0, SCOPED_KEYFRAMES_PREFIX);
+    }
+    self.track_generated_name(&name, base_name);
+    name
+  }
+
+  fn create_keyframe_name(&mut self, base_name: &str) -> String {
+    if let Some(template) = self.class_name_template.clone() {
+      return self.generate_name_from_template(&template, base_name);
+    }
     let name: String = if !self.minify {
       if base_name.is_empty() {
         String::from("animation_")
@@ -119,12 +302,65 @@ impl NamingConvention {
       "{}{}{}",
       self.prefix.clone(),
       name,
-      self.get_file_name_hash()
+      self.get_hash(base_name)
     );
     self.generate_unique_name(&css_variable_name)
   }
 }
 
+/// Checks that a `classNameTemplate` only uses known placeholders
+/// and contains a hash or a component name to keep names unique across files
+pub fn validate_class_name_template(template: &str) -> Result<(), String> {
+  let mut rest = template;
+  let mut is_unique = false;
+  while let Some((_, placeholder, after)) = next_placeholder(rest) {
+    rest = after;
+    let (name, length) = placeholder
+      .split_once(':')
+      .map_or((placeholder, None), |(name, length)| (name, Some(length)));
+    if !CLASS_NAME_TEMPLATE_PLACEHOLDERS.contains(&name) {
+      return Err(format!(
+        "Unknown placeholder \"[{}]\" in classNameTemplate \"{}\" - supported placeholders are {}",
+        placeholder,
+        template,
+        CLASS_NAME_TEMPLATE_PLACEHOLDERS
+          .iter()
+          .map(|placeholder| format!("[{}]", placeholder))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ));
+    }
+    if let Some(length) = length {
+      if length.parse::<usize>().map_or(true, |length| length == 0) {
+        return Err(format!(
+          "Invalid length \"{}\" for placeholder \"[{}]\" in classNameTemplate \"{}\"",
+          length, name, template
+        ));
+      }
+    }
+    is_unique |= matches!(name, "hash" | "fileHash" | "contentHash");
+  }
+  if !is_unique {
+    return Err(format!(
+      "classNameTemplate \"{}\" must contain [hash], [fileHash] or [contentHash] to generate unique names across files",
+      template
+    ));
+  }
+  Ok(())
+}
+
+/// Splits a template at its next placeholder
+/// e.g. "a-[hash:4]-b" -> ("a-", "hash:4", "-b")
+fn next_placeholder(template: &str) -> Option<(&str, &str, &str)> {
+  let start = template.find('[')?;
+  let end = start + template[start..].find(']')?;
+  Some((
+    &template[..start],
+    &template[start + 1..end],
+    &template[end + 1..],
+  ))
+}
+
 /// This helper escapes names to be valid CSS identifiers
 ///
 /// CSS identifiers can be used as class name attribute or animation name
@@ -188,18 +424,40 @@ fn escape_css_class_name(input: &str) -> String {
   result
 }
 
-#[derive(Deserialize, Clone, Copy)]
+#[derive(Deserialize, Clone, Copy, PartialEq)]
 pub enum TranspilationMode {
   CssModule,
   Css,
+  /// Css modules where every static declaration gets its own deduplicated class
+  Atomic,
+  /// Global css which only applies inside an element with the `data-yak-scope` attribute
+  /// e.g. to embed components in third party pages without leaking styles into the host page
+  ///
+  /// Keyframe names are prefixed but stay global. Styles of the host page still apply
+  /// to the scoped elements (e.g. element selectors, inherited properties or
+  /// class names of the host which match the generated ones)
+  Scoped,
 }
 
+/// Attribute which marks the root element for the `Scoped` transpilation mode
+/// The selector is wrapped in `:where()` so it doesn't increase the specificity
+const SCOPE_ATTRIBUTE: &str = "data-yak-scope";
+
+/// Prefix of keyframe names in the `Scoped` transpilation mode
+const SCOPED_KEYFRAMES_PREFIX: &str = "yak-scope_";
+
 impl TranspilationMode {
   /// Returns a valid CSS class name
   pub fn css_class_name(&self, input: &str) -> String {
     match self {
-      TranspilationMode::CssModule => format!(":global(.{})", escape_css_class_name(input)),
+      TranspilationMode::CssModule | TranspilationMode::Atomic => {
+        format!(":global(.{})", escape_css_class_name(input))
+      }
       TranspilationMode::Css => format!(".{}", escape_css_class_name(input)),
+      TranspilationMode::Scoped => format!(
+        ".{}:where([{SCOPE_ATTRIBUTE}], [{SCOPE_ATTRIBUTE}] *)",
+        escape_css_class_name(input)
+      ),
     }
   }
 }
@@ -228,6 +486,89 @@ fn minify_number(num: u32) -> String {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_content_hash_ignores_file_name_and_order() {
+    let mut first =
+      NamingConvention::new("src/App.tsx", true, None).with_naming_mode(NamingMode::ContentHash);
+    first.set_content(Some("color: red;".into()));
+    let button = first.get_css_variable_name("Button");
+
+    let mut moved = NamingConvention::new("src/components/App.tsx", true, None)
+      .with_naming_mode(NamingMode::ContentHash);
+    moved.set_content(Some("color: blue;".into()));
+    moved.get_css_variable_name("Title");
+    moved.set_content(Some("color: red;".into()));
+    assert_eq!(moved.get_css_variable_name("Button"), button);
+
+    moved.set_content(Some("color: green;".into()));
+    assert_ne!(moved.get_css_variable_name("Button"), button);
+  }
+
+  #[test]
+  fn test_class_name_template() {
+    let mut naming = NamingConvention::new("src/App.tsx", false, None)
+      .with_class_name_template(Some("[file]-[component]-[hash:4]".into()));
+    let hash: String = naming.get_file_name_hash().chars().take(4).collect();
+    assert_eq!(
+      naming.get_css_variable_name("Button"),
+      format!("App-Button-{}", hash)
+    );
+    assert_eq!(
+      naming.get_css_variable_name("Button"),
+      format!("App-Button-{}-01", hash)
+    );
+    assert_eq!(
+      naming.get_keyframe_name("Layout.fadeIn"),
+      format!("App-Layout_fadeIn-{}", hash)
+    );
+  }
+
+  #[test]
+  fn test_class_name_template_counter() {
+    let mut naming = NamingConvention::new("src/App.tsx", true, Some("acme".into()))
+      .with_class_name_template(Some("[prefix]_[fileHash][counter]_x".into()));
+    let hash = naming.get_file_name_hash();
+    assert_eq!(
+      naming.get_css_variable_name("Button"),
+      format!("acme_{}_x", hash)
+    );
+    assert_eq!(
+      naming.get_css_variable_name("Title"),
+      format!("acme_{}1_x", hash)
+    );
+  }
+
+  #[test]
+  fn test_class_name_template_project() {
+    let mut naming = NamingConvention::new("src/App.tsx", false, Some("acme".into()))
+      .with_class_name_template(Some("[project]-[component]-[hash:4]".into()));
+    let hash: String = naming.get_file_name_hash().chars().take(4).collect();
+    assert_eq!(
+      naming.get_css_variable_name("Button"),
+      format!("acme-Button-{}", hash)
+    );
+  }
+
+  #[test]
+  fn test_validate_class_name_template() {
+    assert!(validate_class_name_template("[file]-[component]-[hash:6]").is_ok());
+    assert!(validate_class_name_template("x[contentHash][counter]").is_ok());
+    assert!(validate_class_name_template("[project]-[component]-[hash:6]").is_ok());
+    assert!(validate_class_name_template("[unknown]-[hash]").is_err());
+    assert!(validate_class_name_template("[component]").is_err());
+    assert!(validate_class_name_template("[hash:0]").is_err());
+  }
+
+  #[test]
+  fn test_file_hash_ignores_content() {
+    let mut naming = NamingConvention::new("src/App.tsx", true, None);
+    assert_eq!(naming.set_content(Some("color: red;".into())), None);
+    assert_eq!(
+      naming.get_css_variable_name("Button"),
+      format!("y{}", naming.get_file_name_hash())
+    );
+  }
+
   #[test]
   fn css_escape_css_identifier() {
     assert_eq!(escape_css_identifier("foo"), "foo");
@@ -263,6 +604,30 @@ mod tests {
     assert_eq!(escape_css_class_name("1"), "_1");
   }
 
+  #[test]
+  fn scoped_keyframe_names_are_prefixed() {
+    let mut naming = NamingConvention::new("src/App.tsx", true, None)
+      .with_transpilation_mode(TranspilationMode::Scoped);
+    let hash = naming.get_file_name_hash();
+    assert_eq!(
+      naming.get_keyframe_name("fadeIn"),
+      format!("yak-scope_y{}", hash)
+    );
+  }
+
+  #[test]
+  fn css_class_name_per_transpilation_mode() {
+    assert_eq!(
+      TranspilationMode::CssModule.css_class_name("foo$bar"),
+      ":global(.foo\\$bar)"
+    );
+    assert_eq!(TranspilationMode::Css.css_class_name("foo"), ".foo");
+    assert_eq!(
+      TranspilationMode::Scoped.css_class_name("foo"),
+      ".foo:where([data-yak-scope], [data-yak-scope] *)"
+    );
+  }
+
   #[test]
   fn css_naming_convention() {
     let mut convention = NamingConvention::new("file.css", false, None);
@@ -1,6 +1,7 @@
 use crate::yak_file::{is_yak_file, YakFileVisitor};
 use crate::{Config, TransformVisitor};
 use swc_core::common::plugin::metadata::TransformPluginMetadataContextKind;
+use swc_core::common::sync::Lrc;
 use swc_core::ecma::ast::Program;
 use swc_core::ecma::visit::visit_mut_pass;
 use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
@@ -27,12 +28,11 @@ pub fn process_transform(program: Program, metadata: TransformPluginProgramMetad
   // Get a relative posix path to generate always the same hash
   // on different machines or operating systems
   let deterministic_path = relative_posix_path::relative_posix_path(&config.base_path, &filename);
-  program.apply(visit_mut_pass(&mut TransformVisitor::new(
-    metadata.comments,
-    deterministic_path,
-    config.minify,
-    config.prefix,
-    config.display_names,
-    config.transpilation_mode,
-  )))
+  let check_unique_names = config.check_unique_names;
+  let mut visitor = TransformVisitor::new(metadata.comments, deterministic_path, config);
+  // Looking up the source positions needs a round trip to the host
+  if check_unique_names {
+    visitor = visitor.with_source_map(Lrc::new(metadata.source_map));
+  }
+  program.apply(visit_mut_pass(&mut visitor))
 }
@@ -23,3 +23,25 @@ pub fn add_suffix_to_expr(expr: Expr, helper: Ident, suffix: impl AsRef<str>) ->
     type_args: None,
   })
 }
+
+/// Adds a prefix and a suffix to an expression
+/// e.g: `({$foo}) => $foo` -> __yak_cssUrl(({$foo}) => $foo, "/img/", ".png")
+pub fn add_prefix_and_suffix_to_expr(
+  expr: Expr,
+  helper: Ident,
+  prefix: impl AsRef<str>,
+  suffix: impl AsRef<str>,
+) -> Expr {
+  let Expr::Call(mut call) = add_suffix_to_expr(expr, helper, prefix) else {
+    unreachable!()
+  };
+  call.args.push(
+    Expr::Lit(Lit::Str(Str {
+      span: DUMMY_SP,
+      value: suffix.as_ref().into(),
+      raw: None,
+    }))
+    .into(),
+  );
+  Expr::Call(call)
+}
@@ -2,6 +2,8 @@ use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use swc_core::atoms::Atom;
+use swc_core::common::{BytePos, Span, SyntaxContext};
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 use swc_core::{common::errors::HANDLER, common::DUMMY_SP, ecma::ast::*};
 
 use crate::variable_visitor::ScopedVariableReference;
@@ -127,6 +129,144 @@ pub fn is_valid_tagged_tpl(tagged_tpl: &TaggedTpl, literal_names: &FxHashSet<Id>
   false
 }
 
+/// Returns the position of the first reference of every variable
+/// e.g. `Item` in styled.ul`${Item}:hover & {}` above `const Item = styled.li``;`
+pub fn get_first_references(module: &Module) -> FxHashMap<Id, BytePos> {
+  let mut visitor = FirstReferencesVisitor::default();
+  module.visit_with(&mut visitor);
+  visitor.positions
+}
+
+#[derive(Default)]
+struct FirstReferencesVisitor {
+  positions: FxHashMap<Id, BytePos>,
+}
+
+impl FirstReferencesVisitor {
+  fn add(&mut self, ident: &Ident) {
+    let position = self.positions.entry(ident.to_id()).or_insert(ident.span.lo);
+    *position = (*position).min(ident.span.lo);
+  }
+}
+
+impl Visit for FirstReferencesVisitor {
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Expr::Ident(ident) = expr {
+      self.add(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  /// e.g. `{ Item }`
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.add(ident);
+    }
+    prop.visit_children_with(self);
+  }
+}
+
+/// Creates a fingerprint of a css expression which ignores formatting, positions and hygiene
+/// It contains the whole template including the structure of all runtime expressions
+/// e.g. `({$a}) => $a` and `({$a}) => $a.b` or `a && b` and `a || b` differ
+///
+/// Used to derive stable names from the content of a css expression
+/// (the debug representation of the ast might change with swc updates)
+pub fn get_template_fingerprint(tagged_tpl: &TaggedTpl) -> String {
+  let mut tpl = tagged_tpl.tpl.clone();
+  tpl.visit_mut_with(&mut TemplateFingerprint);
+  format!("{:?}", tpl)
+}
+
+/// Checks if two expressions have the same structure ignoring positions
+/// e.g. `({$gap}) => $gap` in two different interpolations of the same template
+///
+/// Variables declared by the expressions themselves (e.g. `$gap`) are compared by name,
+/// all other variables have to reference the same binding
+/// e.g. `() => size` differs if `size` is shadowed in one of the expressions' scopes
+pub fn is_same_expression(a: &Expr, b: &Expr) -> bool {
+  normalize_expression(a) == normalize_expression(b)
+}
+
+fn normalize_expression(expr: &Expr) -> Expr {
+  let mut bindings = BindingsCollector::default();
+  expr.visit_with(&mut bindings);
+  let mut expr = expr.clone();
+  expr.visit_mut_with(&mut ExpressionNormalizer {
+    local_bindings: bindings.ids,
+  });
+  expr
+}
+
+/// Collects all variables declared inside of an expression
+/// e.g. `$gap` in `({$gap}) => $gap`
+#[derive(Default)]
+struct BindingsCollector {
+  ids: FxHashSet<Id>,
+}
+
+impl Visit for BindingsCollector {
+  fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+    self.ids.insert(binding.to_id());
+  }
+
+  fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp) {
+    self.ids.insert(prop.key.to_id());
+    prop.visit_children_with(self);
+  }
+}
+
+struct ExpressionNormalizer {
+  local_bindings: FxHashSet<Id>,
+}
+
+impl VisitMut for ExpressionNormalizer {
+  fn visit_mut_span(&mut self, span: &mut Span) {
+    *span = DUMMY_SP;
+  }
+
+  /// Scopes of the expressions themselves e.g. of an arrow function
+  fn visit_mut_syntax_context(&mut self, ctxt: &mut SyntaxContext) {
+    *ctxt = SyntaxContext::empty();
+  }
+
+  /// Keeps the hygiene of variables declared outside of the expression
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    ident.span = DUMMY_SP;
+    if self.local_bindings.contains(&ident.to_id()) {
+      ident.ctxt = SyntaxContext::empty();
+    }
+  }
+}
+
+struct TemplateFingerprint;
+
+impl VisitMut for TemplateFingerprint {
+  fn visit_mut_span(&mut self, span: &mut Span) {
+    *span = DUMMY_SP;
+  }
+
+  /// Hygiene marks depend on the position inside of the file
+  fn visit_mut_syntax_context(&mut self, ctxt: &mut SyntaxContext) {
+    *ctxt = SyntaxContext::empty();
+  }
+
+  fn visit_mut_tpl_element(&mut self, element: &mut TplElement) {
+    element.raw = element.raw.split_whitespace().join(" ").into();
+    element.cooked = None;
+  }
+
+  /// Ignores the quotes of strings
+  fn visit_mut_str(&mut self, str: &mut Str) {
+    str.raw = None;
+  }
+
+  /// Ignores the notation of numbers e.g. `0.5` and `.5`
+  fn visit_mut_number(&mut self, num: &mut Number) {
+    num.raw = None;
+  }
+}
+
 pub struct TemplateIterator<'a> {
   tpl: &'a mut Tpl,
   quasis: Vec<swc_core::ecma::ast::TplElement>,
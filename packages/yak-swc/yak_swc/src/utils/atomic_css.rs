@@ -0,0 +1,272 @@
+use css_in_js_parser::{CssScope, Declaration, ScopeType};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::naming_convention::TranspilationMode;
+use crate::utils::css_hash::hash_to_css;
+
+/// Generates the class names for atomic css declarations
+///
+/// The name depends only on the declaration, so the same declaration
+/// gets the same class name in every component and every file
+#[derive(Clone, Debug)]
+pub struct AtomicClassNames {
+  prefix: String,
+  minify: bool,
+}
+
+impl AtomicClassNames {
+  pub fn new(prefix: String, minify: bool) -> Self {
+    Self { prefix, minify }
+  }
+
+  /// e.g. `color: red` inside `&:hover` -> `color_xYz123` (dev) or `yxYz123` (minified)
+  fn class_name(&self, nested_scopes: &[CssScope], declaration: &Declaration) -> String {
+    let hash = hash_to_css(&format!(
+      "{}|{}|{}",
+      nested_scopes
+        .iter()
+        .map(|scope| scope.name.as_str())
+        .collect::<Vec<_>>()
+        .join("|"),
+      declaration.property,
+      declaration.value
+    ));
+    if self.minify {
+      format!("{}{}", self.prefix, hash)
+    } else {
+      let property = declaration.property.trim_start_matches('-');
+      format!("{}{}_{}", self.prefix, property, hash)
+    }
+  }
+
+  /// Splits the declarations of a styled component into atomic declarations
+  ///
+  /// Every static declaration of the root scope gets its own class name.
+  /// Declarations which can't be moved to another selector (e.g. `& + &`, `@font-face`
+  /// or conditional css) are kept in the original scope
+  ///
+  /// Atomic classes are shared with other components, so their order in the stylesheet is
+  /// unknown. Properties whose result depends on the declaration order are kept in the
+  /// original scope as well:
+  /// - properties with different values in the same scope e.g. `color: red; color: blue;`
+  /// - properties which are also set by conditional css or a non-atomic scope
+  ///
+  /// Returns the unique atomic class names in order and the rewritten declarations
+  pub fn atomize(
+    &self,
+    root_scope_name: &str,
+    declarations: &[Declaration],
+    transpilation_mode: TranspilationMode,
+  ) -> (Vec<String>, Vec<Declaration>) {
+    let mut property_values: FxHashMap<_, FxHashSet<&str>> = FxHashMap::default();
+    let mut non_atomic_properties: FxHashSet<&str> = FxHashSet::default();
+    for declaration in declarations {
+      match split_atomic_scopes(root_scope_name, declaration) {
+        Some((_, nested_scopes)) => {
+          property_values
+            .entry(property_key(nested_scopes, declaration))
+            .or_default()
+            .insert(declaration.value.as_str());
+        }
+        None => {
+          non_atomic_properties.insert(declaration.property.as_str());
+        }
+      }
+    }
+
+    let mut class_names: Vec<String> = vec![];
+    let mut atomic_declarations: Vec<Declaration> = vec![];
+    for declaration in declarations {
+      let Some((root_scope, nested_scopes)) = split_atomic_scopes(root_scope_name, declaration)
+      else {
+        atomic_declarations.push(declaration.clone());
+        continue;
+      };
+      let has_single_value = property_values
+        .get(&property_key(nested_scopes, declaration))
+        .is_some_and(|values| values.len() == 1);
+      if !has_single_value || non_atomic_properties.contains(declaration.property.as_str()) {
+        atomic_declarations.push(declaration.clone());
+        continue;
+      }
+      let class_name = self.class_name(nested_scopes, declaration);
+      // The same declaration inside the same component (e.g. from a mixin) is only needed once
+      if class_names.contains(&class_name) {
+        continue;
+      }
+      let mut scope = vec![CssScope {
+        name: transpilation_mode.css_class_name(&class_name),
+        scope_type: root_scope.scope_type.clone(),
+      }];
+      scope.extend_from_slice(nested_scopes);
+      atomic_declarations.push(Declaration {
+        scope,
+        ..declaration.clone()
+      });
+      class_names.push(class_name);
+    }
+    (class_names, atomic_declarations)
+  }
+}
+
+/// Splits the scopes of a declaration which can be moved to an atomic class
+/// into the root scope and its nested scopes
+fn split_atomic_scopes<'a>(
+  root_scope_name: &str,
+  declaration: &'a Declaration,
+) -> Option<(&'a CssScope, &'a [CssScope])> {
+  let (root_scope, nested_scopes) = declaration.scope.split_first()?;
+  (root_scope.name == root_scope_name && nested_scopes.iter().all(is_atomic_scope))
+    .then_some((root_scope, nested_scopes))
+}
+
+/// e.g. (["&:hover"], "color") for `&:hover { color: red; }`
+fn property_key<'a>(
+  nested_scopes: &'a [CssScope],
+  declaration: &'a Declaration,
+) -> (Vec<&'a str>, &'a str) {
+  (
+    nested_scopes
+      .iter()
+      .map(|scope| scope.name.as_str())
+      .collect(),
+    declaration.property.as_str(),
+  )
+}
+
+/// Nested scopes which keep their meaning if the component class is replaced by an atomic class
+/// e.g. `&:hover` or `@media (min-width: 640px)` but not `& + &`
+fn is_atomic_scope(scope: &CssScope) -> bool {
+  match scope.scope_type {
+    ScopeType::Selector => scope.name.matches('&').count() <= 1,
+    ScopeType::AtRule => ["@media", "@supports", "@container", "@layer"]
+      .iter()
+      .any(|at_rule| scope.name.starts_with(at_rule)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn declaration(scope: &[(&str, ScopeType)], property: &str, value: &str) -> Declaration {
+    Declaration {
+      property: property.to_string(),
+      value: value.to_string(),
+      closed: true,
+      scope: scope
+        .iter()
+        .map(|(name, scope_type)| CssScope {
+          name: name.to_string(),
+          scope_type: scope_type.clone(),
+        })
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn test_atomize_deduplicates_declarations() {
+    let names = AtomicClassNames::new("".into(), false);
+    let root = ":global(.Button)";
+    let (class_names, declarations) = names.atomize(
+      root,
+      &[
+        declaration(&[(root, ScopeType::AtRule)], "color", "red"),
+        declaration(&[(root, ScopeType::AtRule)], "color", "red"),
+        declaration(
+          &[(root, ScopeType::AtRule), ("&:hover", ScopeType::Selector)],
+          "color",
+          "red",
+        ),
+      ],
+      TranspilationMode::Atomic,
+    );
+    assert_eq!(class_names.len(), 2);
+    assert_ne!(class_names[0], class_names[1]);
+    assert!(class_names[0].starts_with("color_"));
+    assert_eq!(declarations.len(), 2);
+    assert_eq!(
+      declarations[1].scope[0].name,
+      format!(":global(.{})", class_names[1])
+    );
+  }
+
+  #[test]
+  fn test_atomize_keeps_non_atomic_declarations() {
+    let names = AtomicClassNames::new("y".into(), true);
+    let root = ":global(.Button)";
+    let (class_names, declarations) = names.atomize(
+      root,
+      &[
+        declaration(
+          &[(root, ScopeType::AtRule), ("& + &", ScopeType::Selector)],
+          "margin",
+          "0",
+        ),
+        declaration(&[(":global(.Button1)", ScopeType::AtRule)], "color", "red"),
+      ],
+      TranspilationMode::Atomic,
+    );
+    assert!(class_names.is_empty());
+    assert_eq!(declarations[0].scope[0].name, root);
+    assert_eq!(declarations[1].scope[0].name, ":global(.Button1)");
+  }
+
+  #[test]
+  fn test_atomize_keeps_order_dependent_declarations() {
+    let names = AtomicClassNames::new("".into(), false);
+    let root = ":global(.Button)";
+    let (class_names, declarations) = names.atomize(
+      root,
+      &[
+        declaration(&[(root, ScopeType::AtRule)], "color", "red"),
+        declaration(&[(root, ScopeType::AtRule)], "color", "blue"),
+        declaration(&[(root, ScopeType::AtRule)], "color", "red"),
+        declaration(&[(root, ScopeType::AtRule)], "padding", "4px"),
+        declaration(&[(root, ScopeType::AtRule)], "display", "flex"),
+        declaration(
+          &[(":global(.Button__$active)", ScopeType::AtRule)],
+          "padding",
+          "8px",
+        ),
+      ],
+      TranspilationMode::Atomic,
+    );
+    assert_eq!(class_names.len(), 1);
+    assert!(class_names[0].starts_with("display_"));
+    // color and padding keep their order in the component class
+    assert_eq!(
+      declarations
+        .iter()
+        .filter(|declaration| declaration.scope[0].name == root)
+        .map(|declaration| declaration.value.as_str())
+        .collect::<Vec<_>>(),
+      vec!["red", "blue", "red", "4px"]
+    );
+  }
+
+  #[test]
+  fn test_atomic_class_names_are_stable() {
+    let names = AtomicClassNames::new("y".into(), true);
+    let (first, _) = names.atomize(
+      ":global(.A)",
+      &[declaration(
+        &[(":global(.A)", ScopeType::AtRule)],
+        "display",
+        "flex",
+      )],
+      TranspilationMode::Atomic,
+    );
+    let (second, _) = names.atomize(
+      ":global(.B)",
+      &[declaration(
+        &[(":global(.B)", ScopeType::AtRule)],
+        "display",
+        "flex",
+      )],
+      TranspilationMode::Atomic,
+    );
+    assert_eq!(first, second);
+    assert!(first[0].starts_with('y'));
+  }
+}
@@ -0,0 +1,437 @@
+use lazy_static::lazy_static;
+use rustc_hash::FxHashSet;
+use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::{Visit, VisitWith};
+
+// Props which native elements understand and which must be forwarded
+// even if they are read by a runtime style function e.g. `disabled` or `href`
+lazy_static! {
+  static ref DOM_PROPS: FxHashSet<&'static str> = FxHashSet::from_iter([
+    // React
+    "as",
+    "children",
+    "className",
+    "dangerouslySetInnerHTML",
+    "key",
+    "ref",
+    "style",
+    "suppressContentEditableWarning",
+    "suppressHydrationWarning",
+    "theme",
+    // HTML
+    "accept",
+    "acceptCharset",
+    "accessKey",
+    "action",
+    "allow",
+    "allowFullScreen",
+    "alt",
+    "async",
+    "autoCapitalize",
+    "autoComplete",
+    "autoFocus",
+    "autoPlay",
+    "capture",
+    "cellPadding",
+    "cellSpacing",
+    "charSet",
+    "checked",
+    "cite",
+    "cols",
+    "colSpan",
+    "content",
+    "contentEditable",
+    "controls",
+    "coords",
+    "crossOrigin",
+    "dateTime",
+    "decoding",
+    "default",
+    "defaultChecked",
+    "defaultValue",
+    "defer",
+    "dir",
+    "disabled",
+    "download",
+    "draggable",
+    "encType",
+    "enterKeyHint",
+    "fetchPriority",
+    "form",
+    "formAction",
+    "formEncType",
+    "formMethod",
+    "formNoValidate",
+    "formTarget",
+    "headers",
+    "height",
+    "hidden",
+    "high",
+    "href",
+    "hrefLang",
+    "htmlFor",
+    "httpEquiv",
+    "id",
+    "inert",
+    "inputMode",
+    "integrity",
+    "is",
+    "itemID",
+    "itemProp",
+    "itemRef",
+    "itemScope",
+    "itemType",
+    "kind",
+    "label",
+    "lang",
+    "list",
+    "loading",
+    "loop",
+    "low",
+    "max",
+    "maxLength",
+    "media",
+    "method",
+    "min",
+    "minLength",
+    "multiple",
+    "muted",
+    "name",
+    "noValidate",
+    "nonce",
+    "open",
+    "optimum",
+    "pattern",
+    "placeholder",
+    "playsInline",
+    "popover",
+    "popoverTarget",
+    "popoverTargetAction",
+    "poster",
+    "preload",
+    "readOnly",
+    "referrerPolicy",
+    "rel",
+    "required",
+    "reversed",
+    "role",
+    "rows",
+    "rowSpan",
+    "sandbox",
+    "scope",
+    "selected",
+    "shape",
+    "size",
+    "sizes",
+    "slot",
+    "span",
+    "spellCheck",
+    "src",
+    "srcDoc",
+    "srcLang",
+    "srcSet",
+    "start",
+    "step",
+    "summary",
+    "tabIndex",
+    "target",
+    "title",
+    "translate",
+    "type",
+    "useMap",
+    "value",
+    "width",
+    "wrap",
+    // SVG
+    "clipPath",
+    "clipRule",
+    "cx",
+    "cy",
+    "d",
+    "dominantBaseline",
+    "dx",
+    "dy",
+    "fill",
+    "fillOpacity",
+    "fillRule",
+    "filter",
+    "fontFamily",
+    "fontSize",
+    "fontWeight",
+    "gradientTransform",
+    "gradientUnits",
+    "markerEnd",
+    "markerStart",
+    "mask",
+    "offset",
+    "opacity",
+    "pathLength",
+    "points",
+    "preserveAspectRatio",
+    "r",
+    "rx",
+    "ry",
+    "stopColor",
+    "stopOpacity",
+    "stroke",
+    "strokeDasharray",
+    "strokeDashoffset",
+    "strokeLinecap",
+    "strokeLinejoin",
+    "strokeOpacity",
+    "strokeWidth",
+    "textAnchor",
+    "transform",
+    "version",
+    "viewBox",
+    "x",
+    "x1",
+    "x2",
+    "xlinkHref",
+    "xmlns",
+    "xmlnsXlink",
+    "y",
+    "y1",
+    "y2",
+  ]);
+}
+
+/// Collects the props which are read by the runtime functions of a styled component
+/// and which must not be forwarded to a native element
+/// e.g. `({ color }) => color` or `(props) => props.color` -> ["color"]
+///
+/// Only the runtime functions themselves and functions passed to nested css() calls
+/// or yak utilities (`yak_functions`) receive the props - other functions e.g. `items.map((item) => item.color)` are skipped
+///
+/// Transient props (`$color`), DOM attributes (`disabled`), event handlers, data and aria attributes
+/// are excluded as they are either removed anyway or needed by the element
+pub fn get_consumed_props<'a>(
+  expressions: impl IntoIterator<Item = &'a Expr>,
+  yak_functions: &FxHashSet<Id>,
+) -> Vec<String> {
+  let mut visitor = ConsumedPropsVisitor {
+    props: vec![],
+    yak_functions,
+  };
+  for expression in expressions {
+    visitor.visit_runtime_value(expression);
+  }
+  visitor
+    .props
+    .into_iter()
+    .filter(|prop| !is_forwarded_prop(prop))
+    .collect()
+}
+
+fn is_forwarded_prop(prop: &str) -> bool {
+  prop.starts_with('$')
+    || prop.starts_with("data-")
+    || prop.starts_with("aria-")
+    || (prop.starts_with("on") && prop[2..].starts_with(|c: char| c.is_ascii_uppercase()))
+    || DOM_PROPS.contains(prop)
+}
+
+struct ConsumedPropsVisitor<'a> {
+  props: Vec<String>,
+  /// The css function and the utilities which are imported from next-yak
+  /// e.g. css or __yak_unitPostFix
+  yak_functions: &'a FxHashSet<Id>,
+}
+
+impl ConsumedPropsVisitor<'_> {
+  fn add(&mut self, prop: &str) {
+    if !self.props.iter().any(|existing| existing == prop) {
+      self.props.push(prop.to_string());
+    }
+  }
+
+  /// Checks if a call passes the props to its function arguments
+  /// e.g. css("cls", { style: { "--x": (p) => p.x } }) or __yak_unitPostFix((p) => p.x, "px")
+  fn is_yak_call(&self, call: &CallExpr) -> bool {
+    let Callee::Expr(callee) = &call.callee else {
+      return false;
+    };
+    matches!(&**callee, Expr::Ident(ident) if self.yak_functions.contains(&ident.to_id()))
+  }
+
+  /// Collects the props of a value which is passed to the runtime
+  /// e.g. a runtime function or the style object of a nested css() call
+  fn visit_runtime_value(&mut self, expr: &Expr) {
+    match expr.unwrap_parens() {
+      Expr::Arrow(arrow) => self.visit_props_param(arrow.params.first(), &arrow.body),
+      Expr::Fn(FnExpr { function, .. }) => self.visit_props_param(
+        function.params.first().map(|param| &param.pat),
+        &function.body,
+      ),
+      // e.g. { style: { "--x": (p) => p.x } }
+      Expr::Object(object) => {
+        for prop in &object.props {
+          if let PropOrSpread::Prop(prop) = prop {
+            if let Prop::KeyValue(KeyValueProp { value, .. }) = &**prop {
+              self.visit_runtime_value(value);
+            }
+          }
+        }
+      }
+      Expr::Call(call) if self.is_yak_call(call) => {
+        for arg in &call.args {
+          self.visit_runtime_value(&arg.expr);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// Collects the props of the first parameter of a runtime function
+  fn visit_props_param<T: VisitWith<Self> + VisitWith<PropsMemberVisitor>>(
+    &mut self,
+    param: Option<&Pat>,
+    body: &T,
+  ) {
+    match param {
+      // e.g. ({ color, size: s = 1 }) => ...
+      Some(Pat::Object(object_pat)) => {
+        for prop in &object_pat.props {
+          match prop {
+            ObjectPatProp::KeyValue(KeyValuePatProp { key, .. }) => match key {
+              PropName::Ident(ident) => self.add(&ident.sym),
+              PropName::Str(str) => self.add(&str.value),
+              _ => {}
+            },
+            ObjectPatProp::Assign(AssignPatProp { key, .. }) => self.add(&key.sym),
+            ObjectPatProp::Rest(_) => {}
+          }
+        }
+      }
+      // e.g. (props) => props.color
+      Some(Pat::Ident(props_ident)) => {
+        let mut member_visitor = PropsMemberVisitor {
+          props_id: props_ident.to_id(),
+          props: vec![],
+        };
+        body.visit_with(&mut member_visitor);
+        for prop in member_visitor.props {
+          self.add(&prop);
+        }
+      }
+      _ => {}
+    }
+    // Nested css() calls pass the props to their runtime functions
+    // e.g. ({ $active }) => $active && css("active", { style: { "--x": ({ x }) => x } })
+    body.visit_with(self);
+  }
+}
+
+impl Visit for ConsumedPropsVisitor<'_> {
+  fn visit_call_expr(&mut self, call: &CallExpr) {
+    if self.is_yak_call(call) {
+      for arg in &call.args {
+        self.visit_runtime_value(&arg.expr);
+      }
+    } else {
+      call.visit_children_with(self);
+    }
+  }
+}
+
+/// Collects `props.x` and `props["x"]` accesses of a props parameter
+struct PropsMemberVisitor {
+  props_id: Id,
+  props: Vec<String>,
+}
+
+impl Visit for PropsMemberVisitor {
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    if let Expr::Ident(obj) = &*member.obj {
+      if obj.to_id() == self.props_id {
+        match &member.prop {
+          MemberProp::Ident(ident) => self.props.push(ident.sym.to_string()),
+          MemberProp::Computed(ComputedPropName { expr, .. }) => {
+            if let Expr::Lit(Lit::Str(str)) = &**expr {
+              self.props.push(str.value.to_string());
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+    member.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::{FileName, SourceMap};
+  use swc_core::ecma::parser::{parse_file_as_expr, Syntax};
+
+  fn parse(code: &str) -> Expr {
+    let source_map = SourceMap::default();
+    let source_file = source_map.new_source_file(FileName::Anon.into(), code.to_string());
+    *parse_file_as_expr(
+      &source_file,
+      Syntax::Es(Default::default()),
+      EsVersion::latest(),
+      None,
+      &mut vec![],
+    )
+    .unwrap()
+  }
+
+  /// Treats all `css` identifiers as the css function of next-yak
+  /// and `__yak_unitPostFix` as an imported yak utility
+  fn yak_functions(expressions: &[Expr]) -> FxHashSet<Id> {
+    struct YakFunctions(FxHashSet<Id>);
+    impl Visit for YakFunctions {
+      fn visit_ident(&mut self, ident: &Ident) {
+        if ident.sym == "css" || ident.sym == "__yak_unitPostFix" {
+          self.0.insert(ident.to_id());
+        }
+      }
+    }
+    let mut idents = YakFunctions(FxHashSet::default());
+    for expression in expressions {
+      expression.visit_with(&mut idents);
+    }
+    idents.0
+  }
+
+  fn consumed_props(expressions: &[Expr]) -> Vec<String> {
+    get_consumed_props(expressions, &yak_functions(expressions))
+  }
+
+  #[test]
+  fn test_destructured_props() {
+    let expressions = [
+      parse("({ color, $size, disabled, 'data-id': id, variant = 'primary' }) => color"),
+      parse("({ theme, onClick }) => theme.color"),
+    ];
+    assert_eq!(consumed_props(&expressions), vec!["color", "variant"]);
+  }
+
+  #[test]
+  fn test_props_member_access() {
+    let expressions = [
+      parse("(props) => props.tone && css('cls', { style: { '--x': (p) => p['weight'] } })"),
+      parse("(props) => props.tone"),
+    ];
+    assert_eq!(consumed_props(&expressions), vec!["tone", "weight"]);
+  }
+
+  #[test]
+  fn test_yak_utilities() {
+    let expressions = [parse("__yak_unitPostFix(({ gap }) => gap, 'px')")];
+    assert_eq!(consumed_props(&expressions), vec!["gap"]);
+    // User functions with a similar name don't receive the props
+    let expressions = [parse("__yak_format(({ gap }) => gap)")];
+    assert!(consumed_props(&expressions).is_empty());
+  }
+
+  #[test]
+  fn test_nested_functions_are_no_runtime_functions() {
+    let expressions = [parse(
+      "({ items }) => items.map(({ label }) => label).join(', ') && css('cls', { style: { '--x': () => items.map((item) => item.x) } })",
+    )];
+    assert_eq!(consumed_props(&expressions), vec!["items"]);
+  }
+}
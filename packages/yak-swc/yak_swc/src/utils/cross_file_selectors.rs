@@ -3,8 +3,26 @@ use swc_core::atoms::{atom, Atom};
 
 #[derive(PartialEq)]
 pub enum ImportType {
+  /// e.g. styled.button`${Icon} { ... }`
   Selector,
+  /// e.g. styled.button`${fonts.h1};`
   Mixin,
+  /// A mixin which is not passed to the runtime and therefore can't be dynamic
+  /// e.g. styled.button`&:hover { ${fonts.h1}; }`
+  StaticMixin,
+  /// e.g. styled.button`color: ${colors.primary};`
+  Value,
+}
+
+impl ImportType {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ImportType::Selector => "selector",
+      ImportType::Mixin => "mixin",
+      ImportType::StaticMixin => "static-mixin",
+      ImportType::Value => "value",
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -86,11 +104,7 @@ impl ImportKind {
       "--yak-css-import: url(\"{}:{}\",{})",
       self.import_source().as_str(),
       encoded_parts.join(":"),
-      if import_type == ImportType::Selector {
-        "selector"
-      } else {
-        "mixin"
-      }
+      import_type.as_str()
     )
   }
 }
@@ -232,6 +246,22 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_encode_module_import_with_value() {
+    let import_kind = ImportKind::Named {
+      external_name: atom!("colors"),
+      import_source: atom!("./theme"),
+    };
+
+    let value =
+      import_kind.encode_module_import(ImportType::Value, vec![atom!("colors"), atom!("primary")]);
+
+    assert_eq!(
+      value,
+      "--yak-css-import: url(\"./theme:colors:primary\",value)"
+    );
+  }
+
   #[test]
   fn test_encode_module_import_with_namespace() {
     // Create a namespace ImportKind
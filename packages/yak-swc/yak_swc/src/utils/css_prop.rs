@@ -1,13 +1,16 @@
 use swc_core::{
   common::errors::HANDLER,
-  common::{Span, SyntaxContext, DUMMY_SP},
+  common::{Span, Spanned, SyntaxContext, DUMMY_SP},
   ecma::ast::{
-    CallExpr, Callee, Expr, ExprOrSpread, Ident, JSXAttr, JSXAttrName, JSXAttrOrSpread,
-    JSXAttrValue, JSXExpr, JSXOpeningElement, KeyValueProp, ObjectLit, Prop, PropName,
-    PropOrSpread, SpreadElement,
+    BinExpr, BinaryOp, CallExpr, Callee, CondExpr, Expr, ExprOrSpread, Ident, IdentName, JSXAttr,
+    JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElementName, JSXExpr, JSXExprContainer,
+    JSXMemberExpr, JSXObject, JSXOpeningElement, KeyValueProp, Lit, ObjectLit, Prop, PropName,
+    PropOrSpread, SpreadElement, Str,
   },
 };
 
+use crate::{yak_imports::YakImports, CssPropConfig};
+
 #[derive(Debug)]
 pub struct CSSProp {
   index: usize,
@@ -40,7 +43,36 @@ impl CSSProp {
   ///     className: "myClassName"
   ///   })} />
   /// ```
-  pub fn transform(&self, opening_element: &mut JSXOpeningElement, merge_ident: &Ident) {
+  ///
+  /// If the css prop has no runtime values, its class names are compiled to a className
+  /// ```jsx
+  /// <div css={isActive ? css("a") : css("b")} />
+  /// ```
+  /// becomes
+  /// ```jsx
+  /// <div className={isActive ? "a" : "b"} />
+  /// ```
+  /// If the className is built by a known class name helper (e.g. `cn`, `clsx`), the
+  /// class names are added to the helper call instead
+  /// ```jsx
+  /// <div css={css("divClassName")} className={cn("a", isActive && "b")} />
+  /// ```
+  /// becomes
+  /// ```jsx
+  /// <div className={cn("a", isActive && "b", "divClassName")} />
+  /// ```
+  ///
+  /// Returns the spans of the css calls which got replaced by their class names
+  ///
+  /// If a component name is passed, it is added as third argument so the runtime
+  /// can warn in development if the component drops the className
+  pub fn transform(
+    &self,
+    opening_element: &mut JSXOpeningElement,
+    yak_imports: &mut YakImports,
+    class_name_helpers: &[String],
+    checked_component_name: Option<&str>,
+  ) -> Vec<Span> {
     let result: Result<_, TransformError> = (|| {
       let value = opening_element.attrs.remove(self.index);
 
@@ -54,8 +86,29 @@ impl CSSProp {
         })
         .collect();
       let css_expr = Self::extract_css_expr(&value, opening_element.span)?;
-      let merge_call =
-        Self::create_merge_call(&Self::map_props(&removed_attrs)?, css_expr, merge_ident);
+      // The runtime check needs the mergeCssProp call to detect dropped class names
+      if checked_component_name.is_none() {
+        let mut replaced_spans = vec![];
+        if let Some(class_name) =
+          Self::to_static_class_name(&css_expr, yak_imports, &mut replaced_spans)
+        {
+          let class_name_attr = if removed_attrs.is_empty() {
+            Some(Self::create_class_name_attr(class_name))
+          } else {
+            Self::merge_into_class_name_helper(&removed_attrs, class_name, class_name_helpers)
+          };
+          if let Some(class_name_attr) = class_name_attr {
+            opening_element.attrs.push(class_name_attr);
+            return Ok(replaced_spans);
+          }
+        }
+      }
+      let merge_call = Self::create_merge_call(
+        &Self::map_props(&removed_attrs)?,
+        css_expr,
+        &yak_imports.get_yak_utility_ident("mergeCssProp"),
+        checked_component_name,
+      );
       let insert_index = opening_element.attrs.len();
 
       let spread_attr = JSXAttrOrSpread::SpreadElement(SpreadElement {
@@ -63,16 +116,168 @@ impl CSSProp {
         expr: merge_call,
       });
       opening_element.attrs.insert(insert_index, spread_attr);
-      Ok(())
+      Ok(vec![])
     })();
 
-    if let Err(err) = result {
+    result.unwrap_or_else(|err| {
       HANDLER.with(|handler| {
-        handler.span_err(err.span(), err.message());
+        handler.span_err(err.span(), &err.message());
       });
+      vec![]
+    })
+  }
+
+  /// Adds the static class name of the css prop to a class name helper call
+  /// e.g. `className={cn("a", "b")}` + `"yakClass"` -> `className={cn("a", "b", "yakClass")}`
+  ///
+  /// Returns None if the className is not a known helper call or if other
+  /// relevant props (style or spread attributes) have to be merged
+  fn merge_into_class_name_helper(
+    removed_attrs: &[JSXAttrOrSpread],
+    class_name: Box<Expr>,
+    class_name_helpers: &[String],
+  ) -> Option<JSXAttrOrSpread> {
+    let [JSXAttrOrSpread::JSXAttr(class_name_attr)] = removed_attrs else {
+      return None;
+    };
+    if !matches!(&class_name_attr.name, JSXAttrName::Ident(ident) if ident.sym == "className") {
+      return None;
+    }
+    let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+      expr: JSXExpr::Expr(class_name_expr),
+      ..
+    })) = &class_name_attr.value
+    else {
+      return None;
+    };
+    let Expr::Call(helper_call) = &**class_name_expr else {
+      return None;
+    };
+    let Callee::Expr(helper_callee) = &helper_call.callee else {
+      return None;
+    };
+    if !matches!(&**helper_callee, Expr::Ident(ident) if class_name_helpers.iter().any(|name| ident.sym == *name))
+    {
+      return None;
+    }
+
+    let mut helper_call = helper_call.clone();
+    helper_call.args.push(ExprOrSpread {
+      spread: None,
+      expr: class_name,
+    });
+    Some(Self::create_class_name_attr(Box::new(Expr::Call(
+      helper_call,
+    ))))
+  }
+
+  /// Compiles a css prop without runtime values to an expression of its class names
+  /// e.g. `isActive ? css("a") : css("b")` -> `isActive ? "a" : "b"`
+  /// e.g. `isActive && css("a")` -> `isActive ? "a" : undefined`
+  ///
+  /// The spans of all replaced css calls are collected, as the extracted css
+  /// is attached to them as comment
+  fn to_static_class_name(
+    expr: &Expr,
+    yak_imports: &YakImports,
+    replaced_spans: &mut Vec<Span>,
+  ) -> Option<Box<Expr>> {
+    // If only a part of the expression can be converted, all css calls stay
+    // at runtime and their spans must not be reported as replaced
+    // e.g. `a ? css("a") : css("b", { style: ... })`
+    let replaced_spans_len = replaced_spans.len();
+    let result = Self::convert_to_static_class_name(expr, yak_imports, replaced_spans);
+    if result.is_none() {
+      replaced_spans.truncate(replaced_spans_len);
+    }
+    result
+  }
+
+  fn convert_to_static_class_name(
+    expr: &Expr,
+    yak_imports: &YakImports,
+    replaced_spans: &mut Vec<Span>,
+  ) -> Option<Box<Expr>> {
+    match expr {
+      // e.g. css("yakClass")
+      Expr::Call(css_call) => {
+        let Callee::Expr(css_callee) = &css_call.callee else {
+          return None;
+        };
+        if !matches!(&**css_callee, Expr::Ident(ident) if yak_imports.yak_css_idents().contains(&ident.to_id()))
+        {
+          return None;
+        }
+        let [ExprOrSpread {
+          spread: None,
+          expr: class_name,
+        }] = css_call.args.as_slice()
+        else {
+          return None;
+        };
+        let Expr::Lit(Lit::Str(class_name)) = &**class_name else {
+          return None;
+        };
+        replaced_spans.push(css_call.span);
+        Some(Box::new(Expr::Lit(Lit::Str(Str {
+          span: css_call.span,
+          value: class_name.value.clone(),
+          raw: None,
+        }))))
+      }
+      // e.g. (css("yakClass"))
+      Expr::Paren(paren) => Self::to_static_class_name(&paren.expr, yak_imports, replaced_spans),
+      // e.g. isActive ? css("a") : css("b")
+      Expr::Cond(cond) => Some(Box::new(Expr::Cond(CondExpr {
+        span: cond.span,
+        test: cond.test.clone(),
+        cons: Self::to_static_class_name(&cond.cons, yak_imports, replaced_spans)?,
+        alt: Self::to_static_class_name(&cond.alt, yak_imports, replaced_spans)?,
+      }))),
+      Expr::Bin(bin) if bin.op == BinaryOp::LogicalAnd => {
+        let right = Self::to_static_class_name(&bin.right, yak_imports, replaced_spans)?;
+        match Self::to_static_class_name(&bin.left, yak_imports, replaced_spans) {
+          // e.g. (isActive && css("a")) && css("b")
+          Some(left) => Some(Box::new(Expr::Bin(BinExpr {
+            span: bin.span,
+            op: BinaryOp::LogicalAnd,
+            left,
+            right,
+          }))),
+          // e.g. isActive && css("a")
+          // A ternary is used to prevent falsy values like 0 from ending up in the className
+          None => Some(Box::new(Expr::Cond(CondExpr {
+            span: bin.span,
+            test: bin.left.clone(),
+            cons: right,
+            alt: Expr::undefined(DUMMY_SP),
+          }))),
+        }
+      }
+      // e.g. (isActive && css("a")) || css("b")
+      // The left side must be a css prop as its value would be used otherwise
+      Expr::Bin(bin) if bin.op == BinaryOp::LogicalOr => Some(Box::new(Expr::Bin(BinExpr {
+        span: bin.span,
+        op: BinaryOp::LogicalOr,
+        left: Self::to_static_class_name(&bin.left, yak_imports, replaced_spans)?,
+        right: Self::to_static_class_name(&bin.right, yak_imports, replaced_spans)?,
+      }))),
+      _ => None,
     }
   }
 
+  /// Creates a `className={...}` attribute
+  fn create_class_name_attr(class_name: Box<Expr>) -> JSXAttrOrSpread {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+      span: DUMMY_SP,
+      name: JSXAttrName::Ident(IdentName::new("className".into(), DUMMY_SP)),
+      value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        span: DUMMY_SP,
+        expr: JSXExpr::Expr(class_name),
+      })),
+    })
+  }
+
   /// Extracts the CSS expression from a JSX attribute or spread element.
   fn extract_css_expr(attr: &JSXAttrOrSpread, span: Span) -> Result<Box<Expr>, TransformError> {
     match attr {
@@ -143,24 +348,89 @@ impl CSSProp {
     mapped_props: &[PropOrSpread],
     expr: Box<Expr>,
     merge_ident: &Ident,
+    checked_component_name: Option<&str>,
   ) -> Box<Expr> {
+    let mut args = vec![
+      ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Object(ObjectLit {
+          span: DUMMY_SP,
+          props: mapped_props.to_vec(),
+        })),
+      },
+      ExprOrSpread { spread: None, expr },
+    ];
+    if let Some(component_name) = checked_component_name {
+      args.push(ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Lit(Lit::Str(Str {
+          span: DUMMY_SP,
+          value: component_name.into(),
+          raw: None,
+        }))),
+      });
+    }
     Box::new(Expr::Call(CallExpr {
       span: DUMMY_SP,
       callee: Callee::Expr(Box::new(Expr::Ident(merge_ident.clone()))),
-      args: vec![
-        ExprOrSpread {
-          spread: None,
-          expr: Box::new(Expr::Object(ObjectLit {
-            span: DUMMY_SP,
-            props: mapped_props.to_vec(),
-          })),
-        },
-        ExprOrSpread { spread: None, expr },
-      ],
+      args,
       ctxt: SyntaxContext::empty(),
       type_args: None,
     }))
   }
+
+  /// Reports a compile time error if the css prop is used on a component
+  /// which is denied (or not allowed) by the configuration
+  pub fn validate_component(
+    &self,
+    opening_element: &JSXOpeningElement,
+    component_name: &str,
+    config: &CssPropConfig,
+  ) {
+    let span = opening_element.attrs[self.index].span();
+    let error = if config
+      .denied_components
+      .iter()
+      .any(|name| name == component_name)
+    {
+      Some(TransformError::DeniedComponent(
+        span,
+        component_name.to_string(),
+      ))
+    } else {
+      match &config.allowed_components {
+        Some(allowed) if !allowed.iter().any(|name| name == component_name) => Some(
+          TransformError::ComponentNotAllowed(span, component_name.to_string()),
+        ),
+        _ => None,
+      }
+    };
+    if let Some(err) = error {
+      HANDLER.with(|handler| {
+        handler.span_err(err.span(), &err.message());
+      });
+    }
+  }
+}
+
+/// Returns the name of a JSX element if it is a component and not an intrinsic element
+/// e.g. `Button` for <Button />, `Layout.Header` for <Layout.Header /> and None for <div />
+pub fn get_component_name(name: &JSXElementName) -> Option<String> {
+  fn member_to_string(member: &JSXMemberExpr) -> String {
+    let object = match &member.obj {
+      JSXObject::Ident(ident) => ident.sym.to_string(),
+      JSXObject::JSXMemberExpr(member) => member_to_string(member),
+    };
+    format!("{}.{}", object, member.prop.sym)
+  }
+
+  match name {
+    JSXElementName::Ident(ident) if ident.sym.starts_with(|c: char| c.is_ascii_uppercase()) => {
+      Some(ident.sym.to_string())
+    }
+    JSXElementName::JSXMemberExpr(member) => Some(member_to_string(member)),
+    _ => None,
+  }
 }
 
 pub trait HasCSSProp {
@@ -204,6 +474,8 @@ pub enum TransformError {
   MissingAttributeValue(Span),
   InvalidJSXEmptyExpr(Span),
   UnsupportedAttributeValue(Span),
+  DeniedComponent(Span, String),
+  ComponentNotAllowed(Span, String),
 }
 
 impl TransformError {
@@ -214,35 +486,48 @@ impl TransformError {
       | TransformError::InvalidJSXAttribute(span)
       | TransformError::MissingAttributeValue(span)
       | TransformError::InvalidJSXEmptyExpr(span)
-      | TransformError::UnsupportedAttributeValue(span) => *span,
+      | TransformError::UnsupportedAttributeValue(span)
+      | TransformError::DeniedComponent(span, _)
+      | TransformError::ComponentNotAllowed(span, _) => *span,
     }
   }
 
-  fn message(&self) -> &'static str {
+  fn message(&self) -> String {
     match self {
             TransformError::InvalidCSSAttribute(_) =>
                 "Invalid CSS attribute. The 'css' prop should contain a valid CSS-in-JS expression. \
-                Example: css={css`color: red;`}",
+                Example: css={css`color: red;`}".to_string(),
 
             TransformError::UnsupportedSpreadElement(_) =>
                 "Spread elements are not supported in the 'css' prop. \
                     Instead, use a css template literals for your styles. \
-                    Example: css={css`color: red;`}",
+                    Example: css={css`color: red;`}".to_string(),
 
             TransformError::InvalidJSXAttribute(_) =>
                 "Invalid JSX attribute detected. Ensure all attributes have valid names and values. \
-                Example: className=\"my-class\" or style={{ color: 'red' }}",
+                Example: className=\"my-class\" or style={{ color: 'red' }}".to_string(),
 
             TransformError::MissingAttributeValue(_) =>
                 "An attribute is missing its value. All attributes should have a value assigned. \
-                Example: css={styles} or className=\"my-class\"",
+                Example: css={styles} or className=\"my-class\"".to_string(),
 
             TransformError::InvalidJSXEmptyExpr(_) =>
-                "Invalid empty JSX expression found. Ensure your JSX expressions are valid JavaScript expressions.",
+                "Invalid empty JSX expression found. Ensure your JSX expressions are valid JavaScript expressions.".to_string(),
 
             TransformError::UnsupportedAttributeValue(_) =>
                 "Unsupported attribute value type. Use string literals for className, \
-                template literals for css prop, and object literals for style prop.",
+                template literals for css prop, and object literals for style prop.".to_string(),
+
+            TransformError::DeniedComponent(_, name) => format!(
+                "The 'css' prop can not be used on <{name} /> as it does not forward className and style. \
+                Wrap it instead: const Styled{} = styled({name})`...`",
+                name.replace('.', "")
+            ),
+
+            TransformError::ComponentNotAllowed(_, name) => format!(
+                "The 'css' prop is not allowed on <{name} />. \
+                Add \"{name}\" to cssProp.allowedComponents once it forwards className and style."
+            ),
         }
   }
 }
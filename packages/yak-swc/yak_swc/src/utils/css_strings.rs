@@ -0,0 +1,242 @@
+/// A runtime value inside `url()` or a css string
+/// e.g. `background: url("/img/${({$name}) => $name}.png");`
+///
+/// css variables are not replaced inside of `url()` and strings,
+/// therefore the whole `url()` or string has to be created at runtime
+#[derive(Debug, PartialEq)]
+pub struct CssStringContext {
+  /// The runtime utility which quotes and escapes the value
+  /// e.g. `cssUrl` or `cssString`
+  pub utility: &'static str,
+  /// The unescaped static text before the runtime value e.g. `/img/`
+  pub before: String,
+  /// The unescaped static text after the runtime value e.g. `.png`
+  pub after: String,
+  /// Byte position in the parsed property value where `url(` or the opening quote starts
+  pub value_start: usize,
+  /// Bytes of the following css code which belong to the `url()` or string
+  pub consumed: usize,
+}
+
+/// Detects if a runtime value is placed inside `url()` or a css string
+///
+/// - `value` is the property value parsed so far e.g. `url("/img/`
+/// - `string_quote` is the quote of the string the value is in (if any)
+/// - `next_css` is the css code after the runtime value e.g. `.png") no-repeat;`
+///
+/// Returns None if the value is not inside `url()` or a string and
+/// an error if the `url()` or string contains more than one runtime value
+pub fn get_css_string_context(
+  value: &str,
+  string_quote: Option<char>,
+  next_css: &str,
+) -> Option<Result<CssStringContext, String>> {
+  if let Some(quote) = string_quote {
+    let quote_start = find_unescaped(value, quote).last()?;
+    let before = unescape_css(&value[quote_start + quote.len_utf8()..]);
+    let url_start = find_open_url(&value[..quote_start])
+      .filter(|url_start| value[url_start + 4..quote_start].trim().is_empty());
+    let Some(quote_end) = find_unescaped(next_css, quote).next() else {
+      return Some(Err(multiple_values_error(url_start.is_some())));
+    };
+    let after = unescape_css(&next_css[..quote_end]);
+    let mut consumed = quote_end + quote.len_utf8();
+    if let Some(url_start) = url_start {
+      let rest = &next_css[consumed..];
+      let Some(url_end) = rest.find(')').filter(|end| rest[..*end].trim().is_empty()) else {
+        return Some(Err(multiple_values_error(true)));
+      };
+      consumed += url_end + 1;
+      return Some(Ok(CssStringContext {
+        utility: "cssUrl",
+        before,
+        after,
+        value_start: url_start,
+        consumed,
+      }));
+    }
+    return Some(Ok(CssStringContext {
+      utility: "cssString",
+      before,
+      after,
+      value_start: quote_start,
+      consumed,
+    }));
+  }
+
+  let url_start = find_open_url(value)?;
+  let before = unescape_css(value[url_start + 4..].trim_start());
+  let url_end = match next_css.find([')', '(', '"', '\'']) {
+    Some(url_end) if next_css[url_end..].starts_with(')') => url_end,
+    _ => return Some(Err(multiple_values_error(true))),
+  };
+  Some(Ok(CssStringContext {
+    utility: "cssUrl",
+    before,
+    after: unescape_css(next_css[..url_end].trim_end()),
+    value_start: url_start,
+    consumed: url_end + 1,
+  }))
+}
+
+/// Resolves css escapes as the runtime escapes the whole value again
+/// e.g. `\f101 ` -> `\u{f101}` or `\"` -> `"`
+fn unescape_css(value: &str) -> String {
+  let mut result = String::with_capacity(value.len());
+  let mut chars = value.chars().peekable();
+  while let Some(char) = chars.next() {
+    if char != '\\' {
+      result.push(char);
+      continue;
+    }
+    let mut hex = String::new();
+    while hex.len() < 6 {
+      match chars.peek() {
+        Some(digit) if digit.is_ascii_hexdigit() => {
+          hex.push(*digit);
+          chars.next();
+        }
+        _ => break,
+      }
+    }
+    if hex.is_empty() {
+      match chars.next() {
+        // Escaped newlines continue the string on the next line
+        Some('\n') | None => {}
+        Some(escaped) => result.push(escaped),
+      }
+      continue;
+    }
+    // A single whitespace ends the escape sequence
+    if chars.peek().is_some_and(|next| next.is_ascii_whitespace()) {
+      chars.next();
+    }
+    let code_point = u32::from_str_radix(&hex, 16).unwrap_or(0);
+    result.push(match char::from_u32(code_point) {
+      Some(unescaped) if code_point != 0 => unescaped,
+      _ => char::REPLACEMENT_CHARACTER,
+    });
+  }
+  result
+}
+
+/// Returns the byte positions of a quote which is not escaped
+/// e.g. `a\"b"` -> [4]
+fn find_unescaped(value: &str, quote: char) -> impl Iterator<Item = usize> + '_ {
+  let mut is_escaped = false;
+  value.char_indices().filter_map(move |(index, char)| {
+    let is_quote = char == quote && !is_escaped;
+    is_escaped = char == '\\' && !is_escaped;
+    is_quote.then_some(index)
+  })
+}
+
+/// Finds an unclosed `url(` at the end of a css value
+/// e.g. `no-repeat url(/img/` -> Some(10)
+fn find_open_url(value: &str) -> Option<usize> {
+  let url_start = value.to_ascii_lowercase().rfind("url(")?;
+  // The url must start a new css token e.g. not `my-url(`
+  let is_token_start = value[..url_start]
+    .chars()
+    .last()
+    .is_none_or(|c| !c.is_alphanumeric() && c != '-' && c != '_');
+  let is_open = !value[url_start + 4..].contains(['(', ')']);
+  (is_token_start && is_open).then_some(url_start)
+}
+
+fn multiple_values_error(is_url: bool) -> String {
+  format!(
+    "Only one runtime value is allowed inside of {} - create the whole value in one function e.g. ${{({{$a, $b}}) => `${{$a}}/${{$b}}`}}",
+    if is_url { "url()" } else { "a css string" }
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_unquoted_url() {
+    assert_eq!(
+      get_css_string_context("url(", None, ") no-repeat;"),
+      Some(Ok(CssStringContext {
+        utility: "cssUrl",
+        before: "".into(),
+        after: "".into(),
+        value_start: 0,
+        consumed: 1,
+      }))
+    );
+    assert_eq!(
+      get_css_string_context("center URL( /img/", None, ".png );"),
+      Some(Ok(CssStringContext {
+        utility: "cssUrl",
+        before: "/img/".into(),
+        after: ".png".into(),
+        value_start: 7,
+        consumed: 6,
+      }))
+    );
+  }
+
+  #[test]
+  fn test_quoted_url() {
+    assert_eq!(
+      get_css_string_context("url(\"/img/", Some('"'), ".png\") no-repeat;"),
+      Some(Ok(CssStringContext {
+        utility: "cssUrl",
+        before: "/img/".into(),
+        after: ".png".into(),
+        value_start: 0,
+        consumed: 6,
+      }))
+    );
+  }
+
+  #[test]
+  fn test_string() {
+    assert_eq!(
+      get_css_string_context("'Icon: ", Some('\''), "';"),
+      Some(Ok(CssStringContext {
+        utility: "cssString",
+        before: "Icon: ".into(),
+        after: "".into(),
+        value_start: 0,
+        consumed: 1,
+      }))
+    );
+  }
+
+  #[test]
+  fn test_escaped_string() {
+    assert_eq!(
+      get_css_string_context("\"\\f101 ", Some('"'), " \\\"\\41\";"),
+      Some(Ok(CssStringContext {
+        utility: "cssString",
+        before: "\u{f101}".into(),
+        after: " \"A".into(),
+        value_start: 0,
+        consumed: 7,
+      }))
+    );
+  }
+
+  #[test]
+  fn test_no_string_context() {
+    assert_eq!(get_css_string_context("calc(", None, "px + 2rem);"), None);
+    assert_eq!(get_css_string_context("my-url(", None, ");"), None);
+    assert_eq!(get_css_string_context("url(a.png) ", None, ";"), None);
+  }
+
+  #[test]
+  fn test_multiple_values() {
+    assert!(matches!(
+      get_css_string_context("url(", None, "/"),
+      Some(Err(_))
+    ));
+    assert!(matches!(
+      get_css_string_context("\"", Some('"'), " "),
+      Some(Err(_))
+    ));
+  }
+}
@@ -1,7 +1,9 @@
 use lazy_static::lazy_static;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-// All currenctly directly supported html elements
+use crate::StyledElementsConfig;
+
+// All html elements which are directly exported by next-yak/internal as __yak_<element>
 lazy_static! {
   pub static ref VALID_ELEMENTS: FxHashSet<String> = FxHashSet::from_iter(
     vec![
@@ -143,3 +145,240 @@ lazy_static! {
     .map(|s| s.to_string())
   );
 }
+
+/// Namespaces which may prefix a configured element
+/// e.g. "svg:feGaussianBlur"
+const NAMESPACES: &[&str] = &["html", "svg", "math"];
+
+/// The resolved set of tag names which are allowed as `styled.<element>` shorthand
+#[derive(Debug, Clone)]
+pub struct StyledElements {
+  /// Tag names which are rendered as native elements
+  elements: FxHashSet<String>,
+  /// Shorthand names which render a different element
+  /// e.g. View -> div
+  aliases: FxHashMap<String, String>,
+}
+
+/// How a `styled.<element>` shorthand is compiled
+#[derive(Debug, PartialEq)]
+pub enum StyledElement {
+  /// The runtime provides a prebuilt component
+  /// e.g. styled.button -> __yak.__yak_button
+  Runtime(String),
+  /// The element is configured but the runtime has no prebuilt component
+  /// e.g. styled["my-widget"] -> __yak_my_widget
+  Custom(String),
+}
+
+impl Default for StyledElements {
+  fn default() -> Self {
+    Self {
+      elements: VALID_ELEMENTS.clone(),
+      aliases: FxHashMap::default(),
+    }
+  }
+}
+
+impl StyledElements {
+  /// Resolves the configured elements - invalid entries are skipped
+  /// and reported by `validate_styled_elements`
+  pub fn new(config: &StyledElementsConfig) -> Self {
+    let mut elements: FxHashSet<String> = match &config.elements {
+      Some(elements) => elements
+        .iter()
+        .filter_map(|element| parse_element(element).ok())
+        .collect(),
+      None => VALID_ELEMENTS.clone(),
+    };
+    elements.extend(
+      config
+        .extend
+        .iter()
+        .filter_map(|element| parse_element(element).ok()),
+    );
+    let aliases = config
+      .aliases
+      .iter()
+      .filter_map(|(alias, element)| Some((alias.clone(), parse_element(element).ok()?)))
+      .filter(|(_, element)| elements.contains(element))
+      .collect();
+    Self { elements, aliases }
+  }
+
+  /// Resolves the shorthand name of a styled element
+  /// e.g. button -> Runtime("button"), View -> Runtime("div"), my-widget -> Custom("my-widget")
+  pub fn resolve(&self, name: &str) -> Option<StyledElement> {
+    let element = self
+      .aliases
+      .get(name)
+      .map_or(name, |element| element.as_str());
+    if !self.elements.contains(element) {
+      return None;
+    }
+    if VALID_ELEMENTS.contains(element) {
+      Some(StyledElement::Runtime(element.to_string()))
+    } else {
+      Some(StyledElement::Custom(element.to_string()))
+    }
+  }
+}
+
+/// Returns an error message for every invalid entry of the `styledElements` config
+pub fn validate_styled_elements(config: &StyledElementsConfig) -> Vec<String> {
+  let mut errors: Vec<String> = config
+    .elements
+    .iter()
+    .flatten()
+    .chain(&config.extend)
+    .filter_map(|element| parse_element(element).err())
+    .collect();
+  let elements = StyledElements::new(config);
+  let mut aliases: Vec<_> = config.aliases.iter().collect();
+  aliases.sort();
+  for (alias, element) in aliases {
+    match parse_element(element) {
+      Err(error) => errors.push(error),
+      // e.g. { "View": "div" } with `elements: ["span"]` would render a <View> tag
+      Ok(tag_name) if !elements.elements.contains(&tag_name) => errors.push(format!(
+        "The styled element alias \"{}\" renders \"{}\" which is not a configured element - add it to \"elements\" or \"extend\"",
+        alias, element
+      )),
+      Ok(_) => {}
+    }
+  }
+  errors
+}
+
+/// Validates a configured element and strips its namespace
+/// e.g. "svg:feGaussianBlur" -> "feGaussianBlur"
+///
+/// Html tag names are lowercase and custom elements must contain a dash
+/// whereas svg tag names are case-sensitive (e.g. foreignObject) and therefore
+/// must be prefixed with their namespace if they are not already known
+fn parse_element(element: &str) -> Result<String, String> {
+  let (namespace, tag_name) = match element.split_once(':') {
+    Some((namespace, tag_name)) => {
+      if !NAMESPACES.contains(&namespace) {
+        return Err(format!(
+          "Invalid namespace \"{}\" for styled element \"{}\" - expected one of {}",
+          namespace,
+          element,
+          NAMESPACES.join(", ")
+        ));
+      }
+      (Some(namespace), tag_name)
+    }
+    None => (None, element),
+  };
+  let is_valid_name = tag_name.starts_with(|c: char| c.is_ascii_alphabetic())
+    && tag_name
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+  if !is_valid_name {
+    return Err(format!("Invalid styled element \"{}\"", element));
+  }
+  let is_case_sensitive = namespace.is_some_and(|namespace| namespace != "html");
+  if !is_case_sensitive
+    && !VALID_ELEMENTS.contains(tag_name)
+    && tag_name.chars().any(|c| c.is_ascii_uppercase())
+  {
+    return Err(format!(
+      "Invalid styled element \"{}\" - html tag names are lowercase. Use \"svg:{}\" for svg elements",
+      element, tag_name
+    ));
+  }
+  Ok(tag_name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn config(elements: Option<Vec<&str>>, extend: Vec<&str>) -> StyledElementsConfig {
+    StyledElementsConfig {
+      elements: elements.map(|elements| elements.into_iter().map(String::from).collect()),
+      extend: extend.into_iter().map(String::from).collect(),
+      aliases: FxHashMap::default(),
+    }
+  }
+
+  #[test]
+  fn test_default_elements() {
+    let elements = StyledElements::default();
+    assert_eq!(
+      elements.resolve("button"),
+      Some(StyledElement::Runtime("button".into()))
+    );
+    assert_eq!(elements.resolve("my-widget"), None);
+  }
+
+  #[test]
+  fn test_extend_elements() {
+    let elements = StyledElements::new(&config(None, vec!["my-widget", "svg:feGaussianBlur"]));
+    assert_eq!(
+      elements.resolve("button"),
+      Some(StyledElement::Runtime("button".into()))
+    );
+    assert_eq!(
+      elements.resolve("my-widget"),
+      Some(StyledElement::Custom("my-widget".into()))
+    );
+    assert_eq!(
+      elements.resolve("feGaussianBlur"),
+      Some(StyledElement::Custom("feGaussianBlur".into()))
+    );
+  }
+
+  #[test]
+  fn test_replace_elements() {
+    let elements = StyledElements::new(&config(Some(vec!["div", "svg:path"]), vec![]));
+    assert_eq!(
+      elements.resolve("div"),
+      Some(StyledElement::Runtime("div".into()))
+    );
+    assert_eq!(
+      elements.resolve("path"),
+      Some(StyledElement::Runtime("path".into()))
+    );
+    assert_eq!(elements.resolve("button"), None);
+  }
+
+  #[test]
+  fn test_aliases() {
+    let mut config = config(None, vec![]);
+    config.aliases.insert("View".into(), "div".into());
+    let elements = StyledElements::new(&config);
+    assert_eq!(
+      elements.resolve("View"),
+      Some(StyledElement::Runtime("div".into()))
+    );
+  }
+
+  #[test]
+  fn test_alias_to_missing_element() {
+    let mut config = config(Some(vec!["span"]), vec![]);
+    config.aliases.insert("View".into(), "div".into());
+    assert_eq!(StyledElements::new(&config).resolve("View"), None);
+    assert_eq!(
+      validate_styled_elements(&config),
+      vec!["The styled element alias \"View\" renders \"div\" which is not a configured element - add it to \"elements\" or \"extend\""]
+    );
+  }
+
+  #[test]
+  fn test_svg_element_without_namespace() {
+    let config = config(None, vec!["feBlend", "my-widget"]);
+    assert_eq!(StyledElements::new(&config).resolve("feBlend"), None);
+    let errors = validate_styled_elements(&config);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Use \"svg:feBlend\" for svg elements"));
+  }
+
+  #[test]
+  fn test_invalid_namespace() {
+    let errors = validate_styled_elements(&config(None, vec!["xlink:href"]));
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("Invalid namespace \"xlink\""));
+  }
+}
@@ -0,0 +1,195 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::common::{BytePos, Spanned};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::{Visit, VisitWith};
+
+/// Collects all module level bindings and the position after which they are initialized
+/// e.g. `const compact = true;` -> (compact, end of the declaration)
+///
+/// Imported bindings are not included as they might not be initialized yet
+/// when the component is created (e.g. circular imports) and might change
+/// - except for next-yak itself e.g. `import { css } from "next-yak"` -> (css, 0)
+pub fn get_module_bindings(module: &Module) -> FxHashMap<Id, BytePos> {
+  let mut bindings = FxHashMap::default();
+  for item in &module.body {
+    let decl = match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+        let source = import.src.value.as_str();
+        if source == "next-yak" || source.starts_with("next-yak/") {
+          for specifier in &import.specifiers {
+            let local = match specifier {
+              ImportSpecifier::Named(named) => &named.local,
+              ImportSpecifier::Default(default) => &default.local,
+              ImportSpecifier::Namespace(namespace) => &namespace.local,
+            };
+            bindings.insert(local.to_id(), BytePos(0));
+          }
+        }
+        continue;
+      }
+      ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => decl,
+      _ => continue,
+    };
+    match decl {
+      // Function declarations are hoisted
+      Decl::Fn(fn_decl) => {
+        bindings.insert(fn_decl.ident.to_id(), BytePos(0));
+      }
+      Decl::Class(class_decl) => {
+        bindings.insert(class_decl.ident.to_id(), class_decl.span().hi);
+      }
+      Decl::Var(var_decl) => {
+        for declarator in &var_decl.decls {
+          if let Pat::Ident(binding) = &declarator.name {
+            bindings.insert(binding.id.to_id(), declarator.span.hi);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+  bindings
+}
+
+/// Returns the body of a runtime function which doesn't read its parameters
+/// e.g. `() => getSpacing()` -> `getSpacing()`
+///
+/// The body can be evaluated once when the component is created if it only references
+/// module level bindings which are initialized before `position` and never modified
+pub fn get_prop_independent_body(
+  expr: &Expr,
+  module_bindings: &FxHashMap<Id, BytePos>,
+  position: BytePos,
+  is_mutated: impl Fn(&Id) -> bool,
+) -> Option<Expr> {
+  let Expr::Arrow(arrow) = expr else {
+    return None;
+  };
+  if arrow.is_async || arrow.is_generator {
+    return None;
+  }
+  let BlockStmtOrExpr::Expr(body) = &*arrow.body else {
+    return None;
+  };
+  let mut visitor = ReferencesVisitor::default();
+  body.visit_with(&mut visitor);
+  let only_module_bindings = visitor.ids.iter().all(|id| {
+    module_bindings
+      .get(id)
+      .is_some_and(|initialized| *initialized <= position && !is_mutated(id))
+  });
+  (!visitor.has_this && only_module_bindings).then(|| *body.clone())
+}
+
+/// Checks if a precomputed value can be passed to the runtime as a mixin
+/// The runtime treats strings as class names and objects as styles,
+/// so only css() calls and falsy values are allowed
+/// e.g. `flags.compact && css("compact")`
+pub fn is_precomputable_mixin(expr: &Expr, css_idents: &FxHashSet<Id>) -> bool {
+  match expr.unwrap_parens() {
+    Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      ..
+    }) => matches!(&**callee, Expr::Ident(ident) if css_idents.contains(&ident.to_id())),
+    Expr::Bin(BinExpr {
+      op: op!("&&"),
+      right,
+      ..
+    }) => is_precomputable_mixin(right, css_idents),
+    Expr::Cond(CondExpr { cons, alt, .. }) => {
+      is_precomputable_mixin(cons, css_idents) && is_precomputable_mixin(alt, css_idents)
+    }
+    Expr::Lit(Lit::Null(_)) | Expr::Lit(Lit::Bool(Bool { value: false, .. })) => true,
+    Expr::Ident(ident) => ident.sym == "undefined",
+    _ => false,
+  }
+}
+
+/// Collects all identifiers of an expression including nested bindings
+#[derive(Default)]
+struct ReferencesVisitor {
+  ids: Vec<Id>,
+  has_this: bool,
+}
+
+impl Visit for ReferencesVisitor {
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.ids.push(ident.to_id());
+  }
+
+  fn visit_this_expr(&mut self, _: &ThisExpr) {
+    self.has_this = true;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::{FileName, SourceMap};
+  use swc_core::ecma::parser::{parse_file_as_module, Syntax};
+
+  fn parse(code: &str) -> Module {
+    let source_map = SourceMap::default();
+    let source_file = source_map.new_source_file(FileName::Anon.into(), code.to_string());
+    parse_file_as_module(
+      &source_file,
+      Syntax::Es(Default::default()),
+      EsVersion::latest(),
+      None,
+      &mut vec![],
+    )
+    .unwrap()
+  }
+
+  /// Returns the initializer of the last declaration in the module
+  fn last_init(module: &Module) -> &Expr {
+    let Some(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))) = module.body.last() else {
+      panic!("expected a variable declaration");
+    };
+    var_decl.decls[0].init.as_ref().unwrap()
+  }
+
+  #[test]
+  fn test_prop_independent_body() {
+    let module = parse(
+      "import { css } from 'next-yak';
+      function getSpacing(compact) { return compact ? 4 : 8; }
+      const flags = { compact: true };
+      const value = () => getSpacing(flags.compact) && css('a');",
+    );
+    let bindings = get_module_bindings(&module);
+    let expr = last_init(&module);
+    assert!(get_prop_independent_body(expr, &bindings, expr.span().lo, |_| false).is_some());
+    assert!(get_prop_independent_body(expr, &bindings, expr.span().lo, |_| true).is_none());
+  }
+
+  #[test]
+  fn test_prop_dependent_body() {
+    let module = parse(
+      "import { getSpacing } from './spacing';
+      const value = ({ $size }) => $size;
+      const later = () => flags.compact;
+      const global = () => window.innerWidth;
+      const imported = () => getSpacing(1);
+      const flags = { compact: true };",
+    );
+    let bindings = get_module_bindings(&module);
+    for item in &module.body[1..5] {
+      let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) = item else {
+        unreachable!()
+      };
+      let expr = var_decl.decls[0].init.as_ref().unwrap();
+      assert!(get_prop_independent_body(expr, &bindings, expr.span().lo, |_| false).is_none());
+    }
+  }
+
+  #[test]
+  fn test_precomputable_mixin() {
+    let module = parse("const value = flags.compact ? css('a') : null;");
+    let css_idents = FxHashSet::from_iter([Id::from(("css".into(), Default::default()))]);
+    assert!(is_precomputable_mixin(last_init(&module), &css_idents));
+    let module = parse("const value = flags.compact && 'a';");
+    assert!(!is_precomputable_mixin(last_init(&module), &css_idents));
+  }
+}
@@ -0,0 +1,289 @@
+use swc_core::{
+  common::{errors::HANDLER, source_map::PURE_SP, Mark, Span, Spanned, SyntaxContext, DUMMY_SP},
+  ecma::ast::*,
+};
+
+/// Classification of the component or element wrapped by `styled(...)`
+#[derive(Debug, PartialEq)]
+pub enum StyledTarget {
+  /// A plain reference which can be wrapped as is
+  /// e.g. styled(Button), styled(Layout.Header), styled("button")
+  Reference,
+  /// An inline expression without side effects
+  /// e.g. styled((props) => <div {...props} />)
+  Expression,
+  /// An expression which creates the component at module evaluation time
+  /// and therefore has to be hoisted to keep the styled component tree-shakeable
+  /// e.g. styled(forwardRef(...)), styled(lazy(() => import("./Icon")))
+  Hoistable,
+}
+
+#[derive(Debug)]
+pub enum StyledTargetError {
+  MissingTarget(Span),
+  TooManyArguments(Span),
+  SpreadArgument(Span),
+  InvalidTarget(Span),
+  JSXElement(Span),
+}
+
+impl StyledTargetError {
+  pub fn emit(&self) {
+    let (span, message) = match self {
+      StyledTargetError::MissingTarget(span) => (
+        *span,
+        "styled() requires a component or an html tag name e.g. styled(Button)`...`",
+      ),
+      StyledTargetError::TooManyArguments(span) => (
+        *span,
+        "styled() accepts only a single component e.g. styled(Button)`...`",
+      ),
+      StyledTargetError::SpreadArgument(span) => (
+        *span,
+        "Spread arguments are not supported in styled() - pass the component directly e.g. styled(Button)`...`",
+      ),
+      StyledTargetError::InvalidTarget(span) => (
+        *span,
+        "This value can not be wrapped by styled() - only React components or html tag names are allowed e.g. styled(Button)`...` or styled(\"button\")`...`",
+      ),
+      StyledTargetError::JSXElement(span) => (
+        *span,
+        "styled() expects a component and not a JSX element - use styled(Button)`...` instead of styled(<Button />)`...`",
+      ),
+    };
+    HANDLER.with(|handler| {
+      handler.struct_span_err(span, message).emit();
+    });
+  }
+}
+
+/// Find the `styled(...)` call of a styled component tag
+/// e.g. styled(Button) in styled(Button).attrs({ type: "button" })
+pub fn find_styled_call_mut(tag: &mut Expr) -> Option<&mut CallExpr> {
+  match tag {
+    Expr::Call(call) => {
+      let Callee::Expr(callee) = &call.callee else {
+        return None;
+      };
+      match &**callee {
+        // e.g. styled(Button)
+        Expr::Ident(_) => Some(call),
+        // e.g. styled(Button).attrs({ type: "button" })
+        Expr::Member(_) => {
+          let Callee::Expr(callee) = &mut call.callee else {
+            return None;
+          };
+          let Expr::Member(member) = &mut **callee else {
+            return None;
+          };
+          find_styled_call_mut(&mut member.obj)
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Checks if a styled component tag renders a custom element
+/// e.g. styled("my-widget") but not styled("button") or styled(Button)
+pub fn is_custom_element(tag: &mut Expr) -> bool {
+  find_styled_call_mut(tag).is_some_and(|call| {
+    matches!(
+      call.args.first().map(|arg| &*arg.expr),
+      Some(Expr::Lit(Lit::Str(tag_name))) if tag_name.value.contains('-')
+    )
+  })
+}
+
+/// Validates the arguments of a `styled(...)` call and classifies its target
+pub fn classify_styled_call(call: &CallExpr) -> Result<StyledTarget, StyledTargetError> {
+  match call.args.as_slice() {
+    [] => Err(StyledTargetError::MissingTarget(call.span)),
+    [argument] => {
+      if let Some(spread) = argument.spread {
+        return Err(StyledTargetError::SpreadArgument(spread));
+      }
+      classify_styled_target(&argument.expr)
+    }
+    [_, second, ..] => Err(StyledTargetError::TooManyArguments(second.expr.span())),
+  }
+}
+
+/// Classifies the component or element wrapped by `styled(...)`
+fn classify_styled_target(expr: &Expr) -> Result<StyledTarget, StyledTargetError> {
+  match expr {
+    // e.g. styled(undefined)
+    Expr::Ident(ident) if ident.sym == "undefined" => {
+      Err(StyledTargetError::InvalidTarget(ident.span))
+    }
+    // e.g. styled(Button) or styled("button")
+    Expr::Ident(_) | Expr::Lit(Lit::Str(_)) => Ok(StyledTarget::Reference),
+    // e.g. styled(Layout.Header) or styled(Layout["Header"])
+    Expr::Member(member) => classify_styled_target(&member.obj),
+    // e.g. styled(Layout?.Header)
+    Expr::OptChain(OptChainExpr { base, .. }) => match &**base {
+      OptChainBase::Member(member) => classify_styled_target(&member.obj),
+      OptChainBase::Call(_) => Ok(StyledTarget::Hoistable),
+    },
+    // e.g. styled(forwardRef(...)), styled(memo(Button)), styled(new Component())
+    Expr::Call(_) | Expr::New(_) => Ok(StyledTarget::Hoistable),
+    // e.g. styled((props) => <div {...props} />) or styled(class extends Component {})
+    Expr::Arrow(_) | Expr::Fn(_) | Expr::Class(_) => Ok(StyledTarget::Expression),
+    // e.g. styled(isMobile ? MobileButton : Button)
+    Expr::Cond(cond) => {
+      let cons = classify_styled_target(&cond.cons)?;
+      let alt = classify_styled_target(&cond.alt)?;
+      if cons == StyledTarget::Hoistable || alt == StyledTarget::Hoistable {
+        Ok(StyledTarget::Hoistable)
+      } else {
+        Ok(StyledTarget::Expression)
+      }
+    }
+    // e.g. styled((Button)) or styled(Button as any)
+    Expr::Paren(ParenExpr { expr, .. })
+    | Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+    | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => classify_styled_target(expr),
+    // e.g. styled(<Button />)
+    Expr::JSXElement(_) | Expr::JSXFragment(_) => Err(StyledTargetError::JSXElement(expr.span())),
+    // e.g. styled(42), styled(null), styled({}), styled(`button`)
+    _ => Err(StyledTargetError::InvalidTarget(expr.span())),
+  }
+}
+
+/// Replaces the target of a `styled(...)` call with an identifier
+/// and returns a module level declaration for the original target
+///
+/// e.g. `styled(forwardRef(...))` -> `styled(__yak_Button_target)`
+/// with `const __yak_Button_target = /*#__PURE__*/ forwardRef(...);`
+///
+/// The identifier gets its own syntax context so it never shadows or collides
+/// with user code - the hygiene pass renames it if the name is already taken
+pub fn hoist_styled_target(call: &mut CallExpr, name: &str) -> ModuleItem {
+  // e.g. Layout.Header -> Layout_Header
+  let name: String = name
+    .chars()
+    .map(|c| if c.is_alphanumeric() { c } else { '_' })
+    .collect();
+  let ident = Ident::new(
+    format!("__yak_{}_target", name).into(),
+    DUMMY_SP,
+    SyntaxContext::empty().apply_mark(Mark::new()),
+  );
+  let mut target = std::mem::replace(&mut call.args[0].expr, Box::new(Expr::Ident(ident.clone())));
+  // Mark the hoisted call as side effect free so unused
+  // styled components can still be removed by minifiers
+  if let Expr::Call(call) = &mut *target {
+    call.span = PURE_SP;
+  }
+  ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+    span: DUMMY_SP,
+    ctxt: Default::default(),
+    kind: VarDeclKind::Const,
+    declare: false,
+    decls: vec![VarDeclarator {
+      span: DUMMY_SP,
+      name: Pat::Ident(BindingIdent {
+        id: ident,
+        type_ann: None,
+      }),
+      init: Some(target),
+      definite: false,
+    }],
+  }))))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::GLOBALS;
+
+  fn styled_call(args: Vec<Expr>) -> CallExpr {
+    CallExpr {
+      span: DUMMY_SP,
+      ctxt: SyntaxContext::empty(),
+      callee: Callee::Expr(Box::new(Expr::Ident(Ident::from("styled")))),
+      args: args.into_iter().map(ExprOrSpread::from).collect(),
+      type_args: None,
+    }
+  }
+
+  fn ident(name: &str) -> Expr {
+    Expr::Ident(Ident::from(name))
+  }
+
+  #[test]
+  fn test_classify_references() {
+    assert_eq!(
+      classify_styled_call(&styled_call(vec![ident("Button")])).unwrap(),
+      StyledTarget::Reference
+    );
+    let member = Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(ident("Layout")),
+      prop: MemberProp::Ident(IdentName::new("Header".into(), DUMMY_SP)),
+    });
+    assert_eq!(
+      classify_styled_call(&styled_call(vec![member])).unwrap(),
+      StyledTarget::Reference
+    );
+  }
+
+  #[test]
+  fn test_classify_call_is_hoistable() {
+    let forward_ref = Expr::Call(CallExpr {
+      span: DUMMY_SP,
+      ctxt: SyntaxContext::empty(),
+      callee: Callee::Expr(Box::new(ident("forwardRef"))),
+      args: vec![],
+      type_args: None,
+    });
+    assert_eq!(
+      classify_styled_call(&styled_call(vec![forward_ref])).unwrap(),
+      StyledTarget::Hoistable
+    );
+  }
+
+  #[test]
+  fn test_classify_invalid_targets() {
+    assert!(matches!(
+      classify_styled_call(&styled_call(vec![])),
+      Err(StyledTargetError::MissingTarget(_))
+    ));
+    assert!(matches!(
+      classify_styled_call(&styled_call(vec![ident("A"), ident("B")])),
+      Err(StyledTargetError::TooManyArguments(_))
+    ));
+    assert!(matches!(
+      classify_styled_call(&styled_call(vec![ident("undefined")])),
+      Err(StyledTargetError::InvalidTarget(_))
+    ));
+    assert!(matches!(
+      classify_styled_call(&styled_call(vec![Expr::Lit(Lit::Num(42.0.into()))])),
+      Err(StyledTargetError::InvalidTarget(_))
+    ));
+  }
+
+  #[test]
+  fn test_hoisted_target_is_private() {
+    GLOBALS.set(&Default::default(), || {
+      let user_ident = Ident::from("__yak_Button_target");
+      let mut call = styled_call(vec![Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        ctxt: SyntaxContext::empty(),
+        callee: Callee::Expr(Box::new(ident("forwardRef"))),
+        args: vec![],
+        type_args: None,
+      })]);
+      hoist_styled_target(&mut call, "Button");
+      let Expr::Ident(hoisted_ident) = &*call.args[0].expr else {
+        panic!("expected the hoisted identifier");
+      };
+      assert_eq!(hoisted_ident.sym, user_ident.sym);
+      assert_ne!(hoisted_ident.to_id(), user_ident.to_id());
+    });
+  }
+}
@@ -0,0 +1,120 @@
+use css_in_js_parser::{CssScope, Declaration, ScopeType};
+
+/// Infers the `@property` syntax and initial value of a runtime css variable
+/// from the css property it is used in and the unit which follows it
+/// e.g. `left: ${({$x}) => $x}px` -> `<length>` with `0px`
+///
+/// Returns None if the type can't be inferred safely
+pub fn infer_css_variable_syntax(
+  property: &str,
+  unit: Option<&str>,
+) -> Option<(&'static str, &'static str)> {
+  if let Some(unit) = unit {
+    return match unit.to_ascii_lowercase().as_str() {
+      "%" => Some(("<percentage>", "0%")),
+      "px" | "em" | "rem" | "ex" | "ch" | "lh" | "rlh" | "vw" | "vh" | "vmin" | "vmax" | "svw"
+      | "svh" | "lvw" | "lvh" | "dvw" | "dvh" | "cqw" | "cqh" | "cqi" | "cqb" | "cm" | "mm"
+      | "q" | "in" | "pt" | "pc" => Some(("<length>", "0px")),
+      "deg" | "rad" | "grad" | "turn" => Some(("<angle>", "0deg")),
+      "s" | "ms" => Some(("<time>", "0s")),
+      _ => None,
+    };
+  }
+  match property {
+    "color"
+    | "background-color"
+    | "border-color"
+    | "border-top-color"
+    | "border-right-color"
+    | "border-bottom-color"
+    | "border-left-color"
+    | "outline-color"
+    | "text-decoration-color"
+    | "caret-color"
+    | "accent-color"
+    | "column-rule-color"
+    | "fill"
+    | "stroke"
+    | "stop-color"
+    | "flood-color"
+    | "lighting-color" => Some(("<color>", "transparent")),
+    // e.g. opacity: 0.5 or opacity: 50%
+    "opacity" | "fill-opacity" | "stroke-opacity" => Some(("<number> | <percentage>", "1")),
+    "flex-grow" => Some(("<number>", "0")),
+    "flex-shrink" => Some(("<number>", "1")),
+    "z-index" | "order" => Some(("<integer>", "0")),
+    _ => None,
+  }
+}
+
+/// Creates the declarations for a `@property` rule which are hoisted by `to_css`
+/// e.g. `@property --x { syntax: "<length>"; inherits: true; initial-value: 0px; }`
+///
+/// The variable is inherited like an unregistered css variable, so nested elements
+/// of the same component keep the value of their parent
+pub fn css_variable_property_declarations(
+  css_variable_name: &str,
+  syntax: &str,
+  initial_value: &str,
+) -> Vec<Declaration> {
+  let scope = vec![CssScope {
+    name: format!("@property {css_variable_name}"),
+    scope_type: ScopeType::AtRule,
+  }];
+  [
+    ("syntax", format!("\"{syntax}\"")),
+    ("inherits", "true".to_string()),
+    ("initial-value", initial_value.to_string()),
+  ]
+  .into_iter()
+  .map(|(property, value)| Declaration {
+    property: property.to_string(),
+    value,
+    closed: true,
+    scope: scope.clone(),
+  })
+  .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use css_in_js_parser::to_css;
+
+  #[test]
+  fn test_infer_css_variable_syntax() {
+    assert_eq!(
+      infer_css_variable_syntax("left", Some("px")),
+      Some(("<length>", "0px"))
+    );
+    assert_eq!(
+      infer_css_variable_syntax("width", Some("%")),
+      Some(("<percentage>", "0%"))
+    );
+    assert_eq!(
+      infer_css_variable_syntax("transform", Some("deg")),
+      Some(("<angle>", "0deg"))
+    );
+    assert_eq!(
+      infer_css_variable_syntax("color", None),
+      Some(("<color>", "transparent"))
+    );
+    assert_eq!(
+      infer_css_variable_syntax("opacity", None),
+      Some(("<number> | <percentage>", "1"))
+    );
+    assert_eq!(infer_css_variable_syntax("left", None), None);
+    assert_eq!(infer_css_variable_syntax("border", None), None);
+  }
+
+  #[test]
+  fn test_css_variable_property_declarations() {
+    let css = to_css(&css_variable_property_declarations(
+      "--x", "<length>", "0px",
+    ));
+    assert!(css.contains("@property --x {"));
+    assert!(css.contains("syntax: \"<length>\";"));
+    assert!(css.contains("inherits: true;"));
+    assert!(css.contains("initial-value: 0px;"));
+  }
+}
@@ -1,6 +1,6 @@
-use rustc_hash::FxHashMap;
-use swc_core::atoms::Atom;
-use swc_core::ecma::visit::{Fold, VisitMutWith};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::atoms::{atom, Atom};
+use swc_core::ecma::visit::{Fold, Visit, VisitMutWith, VisitWith};
 use swc_core::ecma::{ast::*, visit::VisitMut};
 
 use crate::utils::cross_file_selectors::ImportKind;
@@ -21,7 +21,26 @@ pub enum ImportSourceType {
 /// and their values from the AST
 pub struct VariableVisitor {
   variables: FxHashMap<Id, Box<Expr>>,
+  /// Variables declared inside functions, arrow functions or if statements
+  /// Thanks to the swc resolver their ids are unique for each scope
+  /// e.g. const Box = () => { const pad = 8; return <div css={css`padding: ${pad}px;`} />; }
+  scoped_variables: FxHashMap<Id, Box<Expr>>,
   imports: FxHashMap<Id, ImportKind>,
+  /// Sources of variables which are loaded with a dynamic import that can't
+  /// be resolved statically e.g. lazy(() => import("./Icon").then(load))
+  dynamic_imports: FxHashMap<Id, Atom>,
+  /// Exported names of local variables which are exported separately
+  /// e.g. export { Button as PrimaryButton } -> Button#0 -> [PrimaryButton]
+  local_exports: FxHashMap<Id, Vec<Atom>>,
+  /// Variables which are reassigned or whose value is mutated after the declaration
+  /// e.g. size = 20 or theme.colors.primary = "red"
+  mutated: FxHashSet<Id>,
+  /// Variables which are destructured from another variable
+  /// e.g. const { colors } = theme -> colors#0 -> theme#0
+  destructured_aliases: FxHashMap<Id, Id>,
+  /// Object properties which are passed to a function and might be mutated by it
+  /// e.g. theme.colors in `update(theme.colors)`
+  escaped_paths: Vec<ScopedVariableReference>,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -59,7 +78,13 @@ impl VariableVisitor {
   pub fn new() -> Self {
     Self {
       variables: FxHashMap::default(),
+      scoped_variables: FxHashMap::default(),
       imports: FxHashMap::default(),
+      dynamic_imports: FxHashMap::default(),
+      local_exports: FxHashMap::default(),
+      mutated: FxHashSet::default(),
+      destructured_aliases: FxHashMap::default(),
+      escaped_paths: vec![],
     }
   }
 
@@ -67,7 +92,20 @@ impl VariableVisitor {
   /// Supports normal constant values, object properties and array elements
   /// e.g. get_const_value(("primary#0", vec![atom!("primary"), atom!("red")]))
   pub fn get_const_value(&self, scoped_name: &ScopedVariableReference) -> Option<Box<Expr>> {
-    if let Some(expr) = self.variables.get(&scoped_name.id) {
+    // The initial value of a modified variable is not its value at runtime
+    if self.is_reference_mutated(scoped_name) {
+      return None;
+    }
+    self.get_initial_value(scoped_name)
+  }
+
+  /// Returns the value of a variable, object property or array element at its declaration
+  fn get_initial_value(&self, scoped_name: &ScopedVariableReference) -> Option<Box<Expr>> {
+    if let Some(expr) = self
+      .variables
+      .get(&scoped_name.id)
+      .or_else(|| self.scoped_variables.get(&scoped_name.id))
+    {
       // Start with the initial expression
       let mut current_expr: &Expr = expr;
       // Iterate over the parts (skipping the first one as it's the variable name)
@@ -122,6 +160,143 @@ impl VariableVisitor {
     }
   }
 
+  /// Returns true if a local variable is reassigned or mutated after its declaration
+  /// This includes mutations through aliases and properties which are passed to a function
+  /// e.g. `const colors = theme.colors; colors.primary = "red";` mutates `theme` and `colors`
+  pub fn is_mutated(&self, name: &Id) -> bool {
+    let mut visited = FxHashSet::default();
+    self.is_mutated_inner(name, true, &mut visited)
+  }
+
+  /// Returns true if the value of a variable, object property or array element
+  /// might be changed after its declaration
+  /// e.g. `theme.colors.primary` is mutated by `update(theme.colors)` but `theme.sizes` isn't
+  pub fn is_reference_mutated(&self, reference: &ScopedVariableReference) -> bool {
+    let mut visited = FxHashSet::default();
+    if self.is_mutated_inner(&reference.id, false, &mut visited) {
+      return true;
+    }
+    let reference = self.resolve_member_alias(reference.clone());
+    let destructured_source = self.destructured_aliases.get(&reference.id);
+    self.escaped_paths.iter().any(|escaped_path| {
+      // The parts of destructured variables can't be mapped e.g. `const { colors } = theme;`
+      destructured_source == Some(&escaped_path.id)
+        || (escaped_path.id == reference.id
+          && (escaped_path.parts.starts_with(&reference.parts)
+            || reference.parts.starts_with(&escaped_path.parts)))
+    })
+  }
+
+  fn is_mutated_inner(
+    &self,
+    name: &Id,
+    include_escaped: bool,
+    visited: &mut FxHashSet<Id>,
+  ) -> bool {
+    if !visited.insert(name.clone()) {
+      return false;
+    }
+    if self.mutated.contains(name)
+      || (include_escaped && self.escaped_paths.iter().any(|path| path.id == *name))
+    {
+      return true;
+    }
+    // e.g. `const colors = theme.colors;` changes if theme is mutated
+    let source = self
+      .variables
+      .get(name)
+      .or_else(|| self.scoped_variables.get(name))
+      .and_then(|init| get_root_ident(init));
+    if source.is_some_and(|source| self.is_mutated_inner(&source.to_id(), include_escaped, visited))
+    {
+      return true;
+    }
+    // e.g. `const { colors } = theme;` changes if theme is mutated
+    if let Some(source) = self.destructured_aliases.get(name) {
+      if self.is_mutated_inner(source, include_escaped, visited) {
+        return true;
+      }
+    }
+    // e.g. `theme` changes if `colors` is mutated
+    self
+      .variables
+      .iter()
+      .chain(self.scoped_variables.iter())
+      .filter(|(_, init)| get_root_ident(init).is_some_and(|root| root.to_id() == *name))
+      .map(|(alias, _)| alias)
+      .chain(
+        self
+          .destructured_aliases
+          .iter()
+          .filter(|(_, source)| *source == name)
+          .map(|(alias, _)| alias),
+      )
+      .any(|alias| self.is_mutated_inner(alias, include_escaped, visited))
+  }
+
+  /// Follows variables which are initialized with an object property
+  /// e.g. `colors.primary` -> `theme.colors.primary` for `const colors = theme.colors;`
+  fn resolve_member_alias(
+    &self,
+    mut reference: ScopedVariableReference,
+  ) -> ScopedVariableReference {
+    let mut visited = FxHashSet::default();
+    while visited.insert(reference.id.clone()) {
+      let Some(source) = self
+        .variables
+        .get(&reference.id)
+        .or_else(|| self.scoped_variables.get(&reference.id))
+        .and_then(|init| get_static_member_reference(init))
+        // Dynamic members e.g. `theme[key]` might be any property
+        .filter(|source| source.parts.len() > 1)
+      else {
+        break;
+      };
+      let mut parts = source.parts;
+      parts.extend(reference.parts.into_iter().skip(1));
+      reference = ScopedVariableReference::new(source.id, parts);
+    }
+    reference
+  }
+
+  /// Objects which are passed to a function might be mutated by it
+  /// - only the passed property e.g. `theme.colors` in `update(theme.colors)`
+  /// - escaped primitive values e.g. `darken(colors.primary)` can't be changed
+  /// - known read-only functions e.g. `console.log(theme)` or `useMemo(fn, [theme])` don't change them
+  fn add_escaped_values(&mut self, escaped_values: Vec<EscapedValue>) {
+    for EscapedValue { callee, value } in escaped_values {
+      if callee.is_some_and(|callee| self.is_read_only_function(&callee)) {
+        continue;
+      }
+      let is_primitive = self
+        .get_initial_value(&value)
+        .is_some_and(|value| is_primitive_value(&value));
+      if is_primitive {
+        continue;
+      }
+      let value = self.resolve_member_alias(value);
+      if value.parts.len() > 1 {
+        self.escaped_paths.push(value);
+      } else {
+        self.mutated.insert(value.id);
+      }
+    }
+  }
+
+  /// Functions which never change the objects which are passed to them
+  /// e.g. console.log, React hooks or clsx
+  fn is_read_only_function(&self, callee: &Id) -> bool {
+    match self.imports.get(callee) {
+      Some(import) => READ_ONLY_MODULES.contains(&import.import_source().as_str()),
+      // Globals which are not shadowed by a local variable
+      None => {
+        callee.0 == "console"
+          && !self.variables.contains_key(callee)
+          && !self.scoped_variables.contains_key(callee)
+      }
+    }
+  }
+
   /// Returns the source of an imported variable if it exists
   pub fn get_imported_variable(&mut self, name: &Id) -> Option<(ImportSourceType, &ImportKind)> {
     if let Some(src) = self.imports.get(name) {
@@ -138,18 +313,103 @@ impl VariableVisitor {
     }
     None
   }
+
+  /// Returns the source of a variable which is loaded with a dynamic import
+  /// that can't be resolved statically
+  pub fn get_dynamic_import_source(&self, name: &Id) -> Option<&Atom> {
+    self.dynamic_imports.get(name)
+  }
+
+  /// Returns the names under which a local variable is exported separately
+  /// e.g. [PrimaryButton, default] for
+  /// `const Button = styled.button``; export { Button as PrimaryButton }; export default Button;`
+  pub fn get_local_export_names(&self, name: &Id) -> &[Atom] {
+    self
+      .local_exports
+      .get(name)
+      .map(|names| names.as_slice())
+      .unwrap_or_default()
+  }
 }
 
 impl Fold for VariableVisitor {}
 
 impl VisitMut for VariableVisitor {
+  /// Collects all writes and nested declarations before the module level declarations are scanned
+  /// They can happen anywhere - including functions which are skipped for the module level
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    let mut bindings = BindingVisitor::default();
+    module.visit_with(&mut bindings);
+    self.mutated.extend(bindings.writes);
+    self.scoped_variables = bindings.scoped_variables;
+    self.destructured_aliases = bindings.destructured_aliases;
+    module.visit_mut_children_with(self);
+    self.add_escaped_values(bindings.escaped_values);
+  }
+
+  fn visit_mut_script(&mut self, script: &mut Script) {
+    let mut bindings = BindingVisitor::default();
+    script.visit_with(&mut bindings);
+    self.mutated.extend(bindings.writes);
+    self.scoped_variables = bindings.scoped_variables;
+    self.destructured_aliases = bindings.destructured_aliases;
+    script.visit_mut_children_with(self);
+    self.add_escaped_values(bindings.escaped_values);
+  }
+
   /// Scans the AST for variable declarations and extracts the variable names
   fn visit_mut_var_decl(&mut self, var: &mut VarDecl) {
     var.decls.iter_mut().for_each(|decl| {
-      if let Pat::Ident(ident) = &decl.name {
-        if let Some(init) = &decl.init {
-          self.variables.insert(ident.to_id(), init.clone());
+      let Some(init) = &decl.init else {
+        return;
+      };
+      match &decl.name {
+        Pat::Ident(ident) => {
+          // A redeclared var is a reassignment e.g. var size = 10; var size = 20;
+          if self.variables.contains_key(&ident.to_id()) {
+            self.mutated.insert(ident.to_id());
+          }
+          // Module imports are resolved as imports and not by their initializer
+          // e.g. const Title = require("./Title").default
+          match get_module_import(init) {
+            Some(ModuleImport::Resolved(import_kind)) => {
+              self.imports.insert(ident.to_id(), import_kind);
+            }
+            Some(ModuleImport::Unresolved(import_source)) => {
+              self.dynamic_imports.insert(ident.to_id(), import_source);
+            }
+            None => {
+              self.variables.insert(ident.to_id(), init.clone());
+            }
+          }
         }
+        // e.g. const { Icon, Button: PrimaryButton } = require("./components")
+        // e.g. const { Icon } = await import("./components")
+        Pat::Object(object) => {
+          if let Some(import_source) = get_module_source(init) {
+            for prop in &object.props {
+              let (external_name, local_name) = match prop {
+                ObjectPatProp::Assign(AssignPatProp { key, .. }) => (key.sym.clone(), key.to_id()),
+                ObjectPatProp::KeyValue(KeyValuePatProp {
+                  key: PropName::Ident(key),
+                  value,
+                }) => match &**value {
+                  Pat::Ident(local) => (key.sym.clone(), local.to_id()),
+                  _ => continue,
+                },
+                _ => continue,
+              };
+              self.imports.insert(
+                local_name,
+                ImportKind::Named {
+                  external_name,
+                  import_source: import_source.clone(),
+                },
+              );
+            }
+          }
+        }
+        _ => {}
       }
     });
     var.visit_mut_children_with(self);
@@ -200,6 +460,47 @@ impl VisitMut for VariableVisitor {
     });
     import.visit_mut_children_with(self);
   }
+  /// Scans the AST for local re-exports
+  /// e.g. export { Button, Button as PrimaryButton }
+  fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+    // Re-exports of other modules are followed by the loader
+    // e.g. export { Button } from "./Button"
+    if export.src.is_some() {
+      return;
+    }
+    for specifier in &export.specifiers {
+      if let ExportSpecifier::Named(ExportNamedSpecifier {
+        orig: ModuleExportName::Ident(orig),
+        exported,
+        ..
+      }) = specifier
+      {
+        let exported_name = match exported {
+          Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+          Some(ModuleExportName::Str(str_lit)) => str_lit.value.clone(),
+          None => orig.sym.clone(),
+        };
+        self
+          .local_exports
+          .entry(orig.to_id())
+          .or_default()
+          .push(exported_name);
+      }
+    }
+  }
+
+  /// Scans the AST for default exports of local variables
+  /// e.g. export default Button
+  fn visit_mut_export_default_expr(&mut self, export: &mut ExportDefaultExpr) {
+    if let Expr::Ident(ident) = &*export.expr {
+      self
+        .local_exports
+        .entry(ident.to_id())
+        .or_default()
+        .push(atom!("default"));
+    }
+  }
+
   /// Ignores function declarations (for speed)
   fn visit_mut_fn_decl(&mut self, _: &mut FnDecl) {}
 
@@ -216,6 +517,484 @@ impl VisitMut for VariableVisitor {
   fn visit_mut_if_stmt(&mut self, _: &mut IfStmt) {}
 }
 
+/// Scans the entire program including all function bodies
+///
+/// Collects variables which are written after their declaration
+/// e.g. size = 20, size++, theme.colors.primary = "red", delete theme.colors,
+/// Object.assign(theme, overrides) or sizes.push(4)
+///
+/// And variables which are declared in a nested scope
+/// e.g. const pad = 8; inside of a component
+#[derive(Default)]
+struct BindingVisitor {
+  writes: FxHashSet<Id>,
+  scoped_variables: FxHashMap<Id, Box<Expr>>,
+  /// e.g. const { colors } = theme -> colors#0 -> theme#0
+  destructured_aliases: FxHashMap<Id, Id>,
+  /// Values which are passed to a function e.g. theme.colors in `update(theme.colors)`
+  escaped_values: Vec<EscapedValue>,
+  /// Number of functions or if statements surrounding the current node
+  scope_depth: usize,
+}
+
+impl BindingVisitor {
+  fn add_expr(&mut self, expr: &Expr) {
+    if let Some(ident) = get_root_ident(expr) {
+      self.writes.insert(ident.to_id());
+    }
+  }
+
+  /// e.g. `theme.colors` in `update(theme.colors)`
+  fn add_escaped_value(&mut self, callee: Option<&Expr>, expr: &Expr) {
+    if let Some(value) = get_static_member_reference(expr) {
+      self.escaped_values.push(EscapedValue {
+        callee: callee.and_then(get_root_ident).map(|ident| ident.to_id()),
+        value,
+      });
+    }
+  }
+
+  /// e.g. [size, theme.color] = values or ({ size } = values)
+  fn add_pat(&mut self, pat: &Pat) {
+    match pat {
+      Pat::Ident(ident) => {
+        self.writes.insert(ident.to_id());
+      }
+      Pat::Expr(expr) => self.add_expr(expr),
+      Pat::Array(array) => array
+        .elems
+        .iter()
+        .flatten()
+        .for_each(|elem| self.add_pat(elem)),
+      Pat::Object(object) => object.props.iter().for_each(|prop| match prop {
+        ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => self.add_pat(value),
+        ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+          self.writes.insert(key.to_id());
+        }
+        ObjectPatProp::Rest(RestPat { arg, .. }) => self.add_pat(arg),
+      }),
+      Pat::Rest(RestPat { arg, .. }) => self.add_pat(arg),
+      Pat::Assign(AssignPat { left, .. }) => self.add_pat(left),
+      Pat::Invalid(_) => {}
+    }
+  }
+}
+
+impl Visit for BindingVisitor {
+  fn visit_var_decl(&mut self, var: &VarDecl) {
+    // e.g. const { colors, sizes: [small] } = theme
+    for decl in &var.decls {
+      let (Pat::Object(_) | Pat::Array(_), Some(init)) = (&decl.name, &decl.init) else {
+        continue;
+      };
+      if let Some(source) = get_root_ident(init) {
+        let mut bindings: Vec<BindingIdent> = vec![];
+        decl.name.visit_with(&mut BindingCollector(&mut bindings));
+        for binding in bindings {
+          self
+            .destructured_aliases
+            .insert(binding.to_id(), source.to_id());
+        }
+      }
+    }
+    if self.scope_depth > 0 {
+      for decl in &var.decls {
+        if let (Pat::Ident(ident), Some(init)) = (&decl.name, &decl.init) {
+          // A redeclared var is a reassignment e.g. var size = 10; var size = 20;
+          if self.scoped_variables.contains_key(&ident.to_id()) {
+            self.writes.insert(ident.to_id());
+          }
+          self.scoped_variables.insert(ident.to_id(), init.clone());
+        }
+      }
+    }
+    var.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.scope_depth += 1;
+    function.visit_children_with(self);
+    self.scope_depth -= 1;
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    self.scope_depth += 1;
+    arrow.visit_children_with(self);
+    self.scope_depth -= 1;
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt) {
+    self.scope_depth += 1;
+    if_stmt.visit_children_with(self);
+    self.scope_depth -= 1;
+  }
+
+  fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+    match &assign.left {
+      AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => {
+        self.writes.insert(ident.to_id());
+      }
+      AssignTarget::Simple(SimpleAssignTarget::Member(member)) => {
+        self.add_expr(&member.obj);
+      }
+      AssignTarget::Simple(_) => {}
+      AssignTarget::Pat(AssignTargetPat::Array(array)) => array
+        .elems
+        .iter()
+        .flatten()
+        .for_each(|elem| self.add_pat(elem)),
+      AssignTarget::Pat(AssignTargetPat::Object(object)) => {
+        self.add_pat(&Pat::Object(object.clone()))
+      }
+      AssignTarget::Pat(AssignTargetPat::Invalid(_)) => {}
+    }
+    assign.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update: &UpdateExpr) {
+    self.add_expr(&update.arg);
+    update.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary: &UnaryExpr) {
+    if unary.op == UnaryOp::Delete {
+      self.add_expr(&unary.arg);
+    }
+    unary.visit_children_with(self);
+  }
+
+  /// e.g. for (size of sizes) {}
+  fn visit_for_head(&mut self, head: &ForHead) {
+    if let ForHead::Pat(pat) = head {
+      self.add_pat(pat);
+    }
+    head.visit_children_with(self);
+  }
+
+  /// e.g. Object.assign(theme, overrides), sizes.push(4) or update(theme)
+  fn visit_call_expr(&mut self, call: &CallExpr) {
+    let mut is_read_only = false;
+    if let Callee::Expr(callee) = &call.callee {
+      if let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(method),
+        ..
+      }) = &**callee
+      {
+        let is_global_object =
+          |name: &str| matches!(&**obj, Expr::Ident(object) if object.sym == name);
+        if is_global_object("Object")
+          && matches!(
+            method.sym.as_str(),
+            "assign" | "defineProperty" | "defineProperties" | "setPrototypeOf"
+          )
+        {
+          if let Some(target) = call.args.first() {
+            self.add_expr(&target.expr);
+          }
+        } else if MUTATING_METHODS.contains(&method.sym.as_str()) {
+          self.add_expr(obj);
+        }
+        is_read_only = (is_global_object("Object")
+          && matches!(
+            method.sym.as_str(),
+            "keys" | "values" | "entries" | "freeze" | "isFrozen"
+          ))
+          || (is_global_object("JSON") && method.sym == "stringify");
+      }
+    }
+    // Functions might change the objects which are passed to them
+    if !is_read_only {
+      let callee = match &call.callee {
+        Callee::Expr(callee) => Some(&**callee),
+        _ => None,
+      };
+      for arg in &call.args {
+        self.add_escaped_value(callee, &arg.expr);
+      }
+    }
+    call.visit_children_with(self);
+  }
+
+  /// e.g. new Proxy(theme, handler)
+  fn visit_new_expr(&mut self, new: &NewExpr) {
+    for arg in new.args.iter().flatten() {
+      self.add_escaped_value(Some(&new.callee), &arg.expr);
+    }
+    new.visit_children_with(self);
+  }
+}
+
+/// A value which is passed to a function
+struct EscapedValue {
+  /// Variable at the root of the called function e.g. `console` for `console.log(theme)`
+  callee: Option<Id>,
+  /// e.g. `theme.colors` in `update(theme.colors)`
+  value: ScopedVariableReference,
+}
+
+/// Modules whose functions never change the objects which are passed to them
+/// e.g. `useMemo(() => ..., [theme])` or `clsx(styles)`
+const READ_ONLY_MODULES: &[&str] = &["react", "clsx", "classnames", "next-yak"];
+
+/// Methods of arrays, maps and sets which change the object
+/// e.g. sizes.push(4) or sizes.sort()
+const MUTATING_METHODS: &[&str] = &[
+  "push",
+  "pop",
+  "shift",
+  "unshift",
+  "splice",
+  "sort",
+  "reverse",
+  "fill",
+  "copyWithin",
+  "set",
+  "add",
+  "delete",
+  "clear",
+];
+
+/// Collects all variables declared by a pattern
+struct BindingCollector<'a>(&'a mut Vec<BindingIdent>);
+
+impl Visit for BindingCollector<'_> {
+  fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+    self.0.push(binding.clone());
+  }
+
+  /// e.g. `colors` in `const { colors } = theme`
+  fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp) {
+    self.0.push(prop.key.clone());
+    prop.value.visit_with(self);
+  }
+
+  /// Default values are no bindings e.g. `fallback` in `const { size = fallback } = theme`
+  fn visit_expr(&mut self, _: &Expr) {}
+}
+
+/// Returns the reference of a variable or a static member expression
+/// e.g. theme.colors -> (theme#0, [theme, colors]) or theme[key] -> (theme#0, [theme])
+fn get_static_member_reference(expr: &Expr) -> Option<ScopedVariableReference> {
+  match expr {
+    Expr::Ident(ident) => Some(ScopedVariableReference::new(
+      ident.to_id(),
+      vec![ident.sym.clone()],
+    )),
+    Expr::Member(MemberExpr { obj, prop, .. }) => {
+      let mut reference = get_static_member_reference(obj)?;
+      // Dynamic members might be any property of the object
+      if reference.parts.len() == reference_depth(obj) {
+        match prop {
+          MemberProp::Ident(prop) => reference.parts.push(prop.sym.clone()),
+          MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(str)) => reference.parts.push(str.value.clone()),
+            Expr::Lit(Lit::Num(num)) => reference.parts.push(num.value.to_string().into()),
+            _ => {}
+          },
+          MemberProp::PrivateName(_) => {}
+        }
+      }
+      Some(reference)
+    }
+    Expr::Paren(ParenExpr { expr, .. }) => get_static_member_reference(expr),
+    _ => None,
+  }
+}
+
+/// Number of parts of a member expression e.g. 2 for theme.colors
+fn reference_depth(expr: &Expr) -> usize {
+  match expr {
+    Expr::Member(MemberExpr { obj, .. }) => reference_depth(obj) + 1,
+    Expr::Paren(ParenExpr { expr, .. }) => reference_depth(expr),
+    _ => 1,
+  }
+}
+
+/// Values which can't be mutated e.g. strings or numbers
+fn is_primitive_value(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(_) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Unary(UnaryExpr { arg, .. }) => matches!(&**arg, Expr::Lit(_)),
+    Expr::Paren(ParenExpr { expr, .. }) => is_primitive_value(expr),
+    _ => false,
+  }
+}
+
+/// Returns the variable at the root of a member expression
+/// e.g. theme for theme.colors.primary
+fn get_root_ident(expr: &Expr) -> Option<&Ident> {
+  match expr {
+    Expr::Ident(ident) => Some(ident),
+    Expr::Member(MemberExpr { obj, .. }) => get_root_ident(obj),
+    Expr::Paren(ParenExpr { expr, .. }) => get_root_ident(expr),
+    _ => None,
+  }
+}
+
+/// A variable which is initialized from another module
+enum ModuleImport {
+  /// The imported binding can be resolved statically
+  Resolved(ImportKind),
+  /// The module is imported dynamically and the binding is unknown
+  /// e.g. lazy(() => import("./Icon").then(load))
+  Unresolved(Atom),
+}
+
+/// Detects variables which are initialized from another module without an import declaration
+/// e.g. const Icon = require("./Icon").Icon
+/// e.g. const Icon = lazy(() => import("./Icon"))
+fn get_module_import(init: &Expr) -> Option<ModuleImport> {
+  match init {
+    // e.g. require("./Icon").Icon or (await import("./Icon")).Icon
+    Expr::Member(MemberExpr {
+      obj,
+      prop: MemberProp::Ident(prop),
+      ..
+    }) => {
+      let import_source = get_module_source(obj)?;
+      Some(ModuleImport::Resolved(if prop.sym == "default" {
+        ImportKind::Default { import_source }
+      } else {
+        ImportKind::Named {
+          external_name: prop.sym.clone(),
+          import_source,
+        }
+      }))
+    }
+    // e.g. lazy(() => import("./Icon")) or dynamic(() => import("./Icon"))
+    Expr::Call(CallExpr { args, .. }) if args.len() == 1 && get_module_source(init).is_none() => {
+      let body = match &*args[0].expr {
+        Expr::Arrow(ArrowExpr { body, .. }) => match &**body {
+          BlockStmtOrExpr::Expr(expr) => Some(&**expr),
+          BlockStmtOrExpr::BlockStmt(block) => block.stmts.iter().find_map(|stmt| match stmt {
+            Stmt::Return(ReturnStmt { arg: Some(arg), .. }) => Some(&**arg),
+            _ => None,
+          }),
+        },
+        _ => None,
+      }?;
+      get_lazy_module_import(body)
+    }
+    _ => get_module_source(init)
+      .map(|import_source| ModuleImport::Resolved(ImportKind::Namespace { import_source })),
+  }
+}
+
+/// Detects the component of a lazily loaded module
+/// e.g. import("./Icon") or import("./Icon").then((module) => module.Icon)
+fn get_lazy_module_import(body: &Expr) -> Option<ModuleImport> {
+  if let Some(import_source) = get_dynamic_import_source(body) {
+    return Some(ModuleImport::Resolved(ImportKind::Default {
+      import_source,
+    }));
+  }
+  let Expr::Call(CallExpr {
+    callee: Callee::Expr(callee),
+    args,
+    ..
+  }) = body
+  else {
+    return None;
+  };
+  let Expr::Member(MemberExpr {
+    obj,
+    prop: MemberProp::Ident(then),
+    ..
+  }) = &**callee
+  else {
+    return None;
+  };
+  let import_source = get_dynamic_import_source(obj)?;
+  if then.sym != "then" {
+    return Some(ModuleImport::Unresolved(import_source));
+  }
+  // e.g. (module) => module.Icon or (module) => ({ default: module.Icon })
+  let external_name = args.first().and_then(|arg| match &*arg.expr {
+    Expr::Arrow(ArrowExpr { params, body, .. }) => {
+      let [Pat::Ident(module)] = params.as_slice() else {
+        return None;
+      };
+      let BlockStmtOrExpr::Expr(expr) = &**body else {
+        return None;
+      };
+      let mut expr = &**expr;
+      if let Expr::Paren(ParenExpr { expr: inner, .. }) = expr {
+        expr = inner;
+      }
+      if let Expr::Object(object) = expr {
+        expr = object.props.iter().find_map(|prop| match prop {
+          PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(KeyValueProp {
+              key: PropName::Ident(key),
+              value,
+            }) if key.sym == "default" => Some(&**value),
+            _ => None,
+          },
+          _ => None,
+        })?;
+      }
+      match expr {
+        Expr::Member(MemberExpr {
+          obj,
+          prop: MemberProp::Ident(prop),
+          ..
+        }) if matches!(&**obj, Expr::Ident(obj) if obj.sym == module.sym) => Some(prop.sym.clone()),
+        _ => None,
+      }
+    }
+    _ => None,
+  });
+  Some(match external_name {
+    Some(external_name) => ModuleImport::Resolved(ImportKind::Named {
+      external_name,
+      import_source,
+    }),
+    None => ModuleImport::Unresolved(import_source),
+  })
+}
+
+/// Returns the source of a required or awaited module
+/// e.g. "./Icon" for require("./Icon") or await import("./Icon")
+fn get_module_source(expr: &Expr) -> Option<Atom> {
+  match expr {
+    Expr::Paren(ParenExpr { expr, .. }) => get_module_source(expr),
+    Expr::Await(AwaitExpr { arg, .. }) => get_dynamic_import_source(arg),
+    Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      args,
+      ..
+    }) if matches!(&**callee, Expr::Ident(ident) if ident.sym == "require") => {
+      get_string_argument(args)
+    }
+    _ => None,
+  }
+}
+
+/// Returns the source of a dynamic import
+/// e.g. "./Icon" for import("./Icon")
+fn get_dynamic_import_source(expr: &Expr) -> Option<Atom> {
+  match expr {
+    Expr::Call(CallExpr {
+      callee: Callee::Import(_),
+      args,
+      ..
+    }) => get_string_argument(args),
+    _ => None,
+  }
+}
+
+fn get_string_argument(args: &[ExprOrSpread]) -> Option<Atom> {
+  match args {
+    [ExprOrSpread { spread: None, expr }] => match &**expr {
+      Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -538,4 +1317,201 @@ mod tests {
     let result = visitor.get_const_value(&non_existent_ref);
     assert!(result.is_none());
   }
+
+  #[test]
+  fn test_local_exports() {
+    let mut visitor = VariableVisitor::new();
+    let code = r#"
+      import { Icon } from "./icon";
+      const Button = "button";
+      const Title = "title";
+      export { Button, Button as PrimaryButton };
+      export { Icon as ButtonIcon } from "./icon";
+      export default Title;
+      "#;
+
+    test_transform(
+      Default::default(),
+      Some(true),
+      |_| visit_mut_pass(&mut visitor),
+      code,
+      code,
+    );
+
+    let button_id = Id::from((Atom::from("Button"), SyntaxContext::from_u32(0)));
+    assert_eq!(
+      visitor.get_local_export_names(&button_id),
+      &[atom!("Button"), atom!("PrimaryButton")]
+    );
+    let title_id = Id::from((Atom::from("Title"), SyntaxContext::from_u32(0)));
+    assert_eq!(
+      visitor.get_local_export_names(&title_id),
+      &[atom!("default")]
+    );
+    let icon_id = Id::from((Atom::from("Icon"), SyntaxContext::from_u32(0)));
+    assert!(visitor.get_local_export_names(&icon_id).is_empty());
+  }
+
+  #[test]
+  fn test_mutated_variables() {
+    let mut visitor = VariableVisitor::new();
+    let code = r#"
+      let size = 10;
+      let count = 0;
+      var gap = 4;
+      var gap = 8;
+      let unchanged = 1;
+      const theme = { colors: { primary: "red" } };
+      const colors = theme.colors;
+      const spacing = { small: 4 };
+      const radius = { small: 2 };
+      const shadow = { small: "none" };
+      const sizes = [4, 8];
+      const order = [2, 1];
+      const registry = { button: "blue" };
+      const fonts = { size: 12 };
+      const settings = { palette: { primary: "red" } };
+      const { palette } = settings;
+      function update() {
+        size = 20;
+        count++;
+        colors.primary = "blue";
+        delete spacing.small;
+        Object.assign(radius, { small: 4 });
+        [shadow.small] = ["0 0 1px"];
+        sizes.push(16);
+        order.sort();
+        register(registry);
+        darken(fonts.size);
+        palette.primary = "blue";
+      }
+      "#;
+
+    test_transform(
+      Default::default(),
+      Some(true),
+      |_| visit_mut_pass(&mut visitor),
+      code,
+      code,
+    );
+
+    let id = |name: &str| Id::from((Atom::from(name), SyntaxContext::from_u32(0)));
+    for name in [
+      "size", "count", "gap", "theme", "colors", "spacing", "radius", "shadow", "sizes", "order",
+      "registry", "settings", "palette",
+    ] {
+      assert!(visitor.is_mutated(&id(name)), "{name} should be mutated");
+    }
+    assert!(!visitor.is_mutated(&id("unchanged")));
+    assert!(!visitor.is_mutated(&id("fonts")));
+    assert!(visitor
+      .get_const_value(&ScopedVariableReference::new(
+        id("theme"),
+        vec![atom!("theme"), atom!("colors"), atom!("primary")],
+      ))
+      .is_none());
+    assert!(visitor
+      .get_const_value(&ScopedVariableReference::new(
+        id("unchanged"),
+        vec![atom!("unchanged")],
+      ))
+      .is_some());
+  }
+
+  #[test]
+  fn test_escaped_values() {
+    let mut visitor = VariableVisitor::new();
+    let code = r#"
+      import { useMemo } from "react";
+      import clsx from "clsx";
+      const theme = { colors: { primary: "red" }, sizes: { small: 4 } };
+      const styles = { button: "button" };
+      const palette = { primary: "red" };
+      const settings = { colors: { primary: "red" } };
+      const colors = settings.colors;
+      function Button() {
+        console.log(theme);
+        useMemo(() => theme.sizes.small, [theme]);
+        clsx(styles);
+        update(theme.colors);
+        createTheme(palette);
+        update(colors);
+      }
+      "#;
+
+    test_transform(
+      Default::default(),
+      Some(true),
+      |_| visit_mut_pass(&mut visitor),
+      code,
+      code,
+    );
+
+    let id = |name: &str| Id::from((Atom::from(name), SyntaxContext::from_u32(0)));
+    let reference = |parts: &[&str]| {
+      ScopedVariableReference::new(
+        id(parts[0]),
+        parts.iter().map(|part| Atom::from(*part)).collect(),
+      )
+    };
+    // Read-only functions don't mutate their arguments
+    assert!(!visitor.is_mutated(&id("styles")));
+    // Only the passed property might be mutated
+    assert!(visitor.is_mutated(&id("theme")));
+    assert!(visitor
+      .get_const_value(&reference(&["theme", "colors", "primary"]))
+      .is_none());
+    assert!(visitor
+      .get_const_value(&reference(&["theme", "sizes", "small"]))
+      .is_some());
+    // Passing the whole variable might mutate all of its properties
+    assert!(visitor
+      .get_const_value(&reference(&["palette", "primary"]))
+      .is_none());
+    // Aliases of properties are mapped to the original object
+    assert!(visitor
+      .get_const_value(&reference(&["settings", "colors", "primary"]))
+      .is_none());
+    assert!(visitor
+      .get_const_value(&reference(&["colors", "primary"]))
+      .is_none());
+  }
+
+  #[test]
+  fn test_function_scope_variables() {
+    let mut visitor = VariableVisitor::new();
+    let code = r#"
+      const Card = () => {
+        const pad = 8;
+        return pad;
+      };
+      function Badge() {
+        if (true) {
+          const gap = 2;
+        }
+        let size = 1;
+        size = 2;
+      }
+      "#;
+
+    test_transform(
+      Default::default(),
+      Some(true),
+      |_| visit_mut_pass(&mut visitor),
+      code,
+      code,
+    );
+
+    let reference = |name: &str| {
+      ScopedVariableReference::new(
+        Id::from((Atom::from(name), SyntaxContext::from_u32(0))),
+        vec![Atom::from(name)],
+      )
+    };
+    let pad = visitor.get_const_value(&reference("pad")).unwrap();
+    assert_eq!(get_expr_value(&pad), Some("8".to_string()));
+    let gap = visitor.get_const_value(&reference("gap")).unwrap();
+    assert_eq!(get_expr_value(&gap), Some("2".to_string()));
+    assert!(visitor.get_const_value(&reference("size")).is_none());
+  }
 }
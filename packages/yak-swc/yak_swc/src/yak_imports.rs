@@ -3,12 +3,12 @@ use swc_core::atoms::Atom;
 use swc_core::ecma::visit::Fold;
 use swc_core::ecma::visit::VisitMutWith;
 use swc_core::{
-  common::DUMMY_SP,
+  common::{source_map::PURE_SP, Mark, SyntaxContext, DUMMY_SP},
   ecma::{ast::*, visit::VisitMut},
 };
 
 use crate::utils::ast_helper::create_member_prop_from_string;
-use crate::utils::native_elements::VALID_ELEMENTS;
+use crate::utils::native_elements::{StyledElement, StyledElements};
 
 #[derive(Debug)]
 
@@ -22,6 +22,11 @@ pub struct YakImports {
   /// Direct component imports from "next-yak/internal"
   /// e.g. __yak_button
   yak_component_import: Option<Ident>,
+  /// Elements which are allowed as `styled.<element>` shorthand
+  styled_elements: StyledElements,
+  /// Configured elements without a prebuilt runtime component \
+  /// e.g. `const __yak_my_widget = styled("my-widget")` for my-widget
+  yak_custom_elements: Vec<(String, Ident, Ident)>,
   /// Local Identifiers for the next-yak css function \
   /// Most of the time it is just `css#0` for `import { css } from "next-yak"` \
   /// but it might also contain renamings like `import { css as css_ } from "next-yak"`
@@ -53,7 +58,13 @@ pub fn visit_module_imports(module: &mut Module) -> YakImports {
   yak_import_visitor.into()
 }
 
-const UTILITIES: &[&str] = &["unitPostFix", "mergeCssProp"];
+const UTILITIES: &[&str] = &[
+  "unitPostFix",
+  "mergeCssProp",
+  "staticFallback",
+  "cssUrl",
+  "cssString",
+];
 
 impl From<YakImportVisitor> for YakImports {
   fn from(value: YakImportVisitor) -> Self {
@@ -74,6 +85,8 @@ impl YakImports {
     Self {
       yak_utilities: FxHashMap::default(),
       yak_component_import: None,
+      styled_elements: StyledElements::default(),
+      yak_custom_elements: vec![],
       yak_library_imports,
       yak_css_idents,
       yak_keyframes_idents,
@@ -145,25 +158,119 @@ impl YakImports {
     }
   }
 
+  /// Ids of the utility functions which are imported from "next-yak/internal"
+  /// e.g. __yak_unitPostFix
+  pub fn yak_utility_ids(&self) -> impl Iterator<Item = Id> + '_ {
+    self.yak_utilities.values().map(|ident| ident.to_id())
+  }
+
+  /// Replace the elements which are allowed as `styled.<element>` shorthand
+  pub fn set_styled_elements(&mut self, styled_elements: StyledElements) {
+    self.styled_elements = styled_elements;
+  }
+
+  /// Resolve the element for a `styled.<element>` shorthand
+  /// e.g. button -> Runtime("button")
+  pub fn resolve_styled_element(&self, name: &str) -> Option<StyledElement> {
+    self.styled_elements.resolve(name)
+  }
+
   /// Returns the expression for the given component
   /// e.g. __yak.__yak_button for button
   /// Importing components as `import * as __yak from "next-yak/internal"` allows
   /// webpack to optimize usages
   /// Without this webpack injects `(0, s.As)` for `__yak_button` instead of `s.As`
-  pub fn get_yak_component_import(&mut self, name: impl AsRef<str>) -> Option<Box<Expr>> {
-    if !VALID_ELEMENTS.contains(name.as_ref()) {
-      return None;
+  pub fn get_yak_component_import(&mut self, name: impl AsRef<str>) -> Box<Expr> {
+    let yak_ident = self
+      .yak_component_import
+      .get_or_insert_with(|| Ident::from("__yak"))
+      .clone();
+    Box::new(Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(Expr::Ident(yak_ident)),
+      prop: create_member_prop_from_string(format!("__yak_{}", name.as_ref())),
+    }))
+  }
+
+  /// Returns the expression for a configured element without a prebuilt runtime component
+  /// e.g. __yak_my_widget for my-widget
+  /// The component is created once per file (see `get_yak_custom_element_declarations`)
+  pub fn get_yak_custom_element_import(&mut self, name: &str, styled_ident: &Ident) -> Box<Expr> {
+    if let Some((_, local, _)) = self
+      .yak_custom_elements
+      .iter()
+      .find(|(element, _, _)| element == name)
+    {
+      return Box::new(Expr::Ident(local.clone()));
     }
-    if self.yak_component_import.is_none() {
-      self.yak_component_import = Some(Ident::from("__yak"));
+    // e.g. my-widget -> __yak_my_widget
+    let base_name = format!(
+      "__yak_{}",
+      name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+    );
+    let mut local_name = base_name.clone();
+    let mut counter = 1;
+    while self
+      .yak_custom_elements
+      .iter()
+      .any(|(_, local, _)| local.sym == local_name)
+    {
+      local_name = format!("{}{}", base_name, counter);
+      counter += 1;
     }
-    self.yak_component_import.clone().map(|yak_ident| {
-      Box::new(Expr::Member(MemberExpr {
-        span: DUMMY_SP,
-        obj: Box::new(Expr::Ident(yak_ident)),
-        prop: create_member_prop_from_string(format!("__yak_{}", name.as_ref())),
-      }))
-    })
+    // The identifier gets its own syntax context so it never shadows or collides
+    // with user code - the hygiene pass renames it if the name is already taken
+    let local = Ident::new(
+      local_name.into(),
+      DUMMY_SP,
+      SyntaxContext::empty().apply_mark(Mark::new()),
+    );
+    // The declaration is moved to the top of the module
+    // so it must not keep the span (and therefore the comments) of its first usage
+    let styled_ident = Ident::new(styled_ident.sym.clone(), DUMMY_SP, styled_ident.ctxt);
+    self
+      .yak_custom_elements
+      .push((name.to_string(), local.clone(), styled_ident));
+    Box::new(Expr::Ident(local))
+  }
+
+  /// Get the declarations for all used configured elements without a prebuilt runtime component
+  /// i.e. `const __yak_my_widget = /*#__PURE__*/ styled("my-widget")`
+  pub fn get_yak_custom_element_declarations(&self) -> Vec<ModuleItem> {
+    self
+      .yak_custom_elements
+      .iter()
+      .map(|(element, local, styled_ident)| {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+          span: DUMMY_SP,
+          ctxt: Default::default(),
+          kind: VarDeclKind::Const,
+          declare: false,
+          decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+              id: local.clone(),
+              type_ann: None,
+            }),
+            init: Some(Box::new(Expr::Call(CallExpr {
+              span: PURE_SP,
+              ctxt: Default::default(),
+              callee: Callee::Expr(Box::new(Expr::Ident(styled_ident.clone()))),
+              args: vec![ExprOrSpread::from(Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: element.as_str().into(),
+                raw: None,
+              }))))],
+              type_args: None,
+            }))),
+            definite: false,
+          }],
+        }))))
+      })
+      .collect()
   }
 
   /// Get the import declaration specifiers for all used utility functions
@@ -264,7 +371,7 @@ impl Fold for YakImportVisitor {}
 mod tests {
   use super::*;
   use swc_core::atoms::atom;
-  use swc_core::common::SyntaxContext;
+  use swc_core::common::GLOBALS;
   use swc_core::ecma::transforms::testing::test_transform;
   use swc_core::ecma::visit::visit_mut_pass;
 
@@ -411,5 +518,23 @@ mod tests {
     assert_eq!(ident.sym, "__yak_unitPostFix");
     let ident = imports.get_yak_utility_ident("mergeCssProp");
     assert_eq!(ident.sym, "__yak_mergeCssProp");
+    let ident = imports.get_yak_utility_ident("staticFallback");
+    assert_eq!(ident.sym, "__yak_staticFallback");
+    let ident = imports.get_yak_utility_ident("cssUrl");
+    assert_eq!(ident.sym, "__yak_cssUrl");
+  }
+
+  #[test]
+  fn test_custom_element_import_is_private() {
+    GLOBALS.set(&Default::default(), || {
+      let mut imports: YakImports = YakImportVisitor::new().into();
+      let user_ident = Ident::from("__yak_my_widget");
+      let Expr::Ident(local) = *imports.get_yak_custom_element_import("my-widget", &user_ident)
+      else {
+        panic!("expected the hoisted identifier");
+      };
+      assert_eq!(local.sym, user_ident.sym);
+      assert_ne!(local.to_id(), user_ident.to_id());
+    });
   }
 }
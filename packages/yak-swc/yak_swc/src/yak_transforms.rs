@@ -4,11 +4,14 @@ use swc_core::atoms::Atom;
 use swc_core::common::util::move_map::MoveMap;
 
 use crate::utils::ast_helper::expr_hash_map_to_object;
+use crate::utils::atomic_css::AtomicClassNames;
+use crate::utils::consumed_props::get_consumed_props;
 use crate::utils::cross_file_selectors::encode_percent;
+use crate::utils::native_elements::StyledElement;
+use crate::utils::styled_target::is_custom_element;
 use crate::variable_visitor::ScopedVariableReference;
 use crate::yak_imports::YakImports;
-use css_in_js_parser::{CssScope, Declaration, ParserState, ScopeType};
-use swc_core::common::errors::HANDLER;
+use css_in_js_parser::{to_css, CssScope, Declaration, ParserState, ScopeType};
 use swc_core::common::{source_map::PURE_SP, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::ast::*;
 
@@ -17,6 +20,9 @@ use crate::naming_convention::{NamingConvention, TranspilationMode};
 /// Represents a CSS result after the transformation
 #[derive(Debug)]
 pub struct YakCss {
+  /// Comments which are added in front of the extracted css comment
+  /// e.g. `YAK EXPORTED MIXIN:fonts:h1\nfont-size: 2rem;\n`
+  pub leading_comments: Vec<String>,
   pub comment_prefix: Option<String>,
   /// The generated CSS code
   pub declarations: Vec<Declaration>,
@@ -47,6 +53,10 @@ pub trait YakTransform {
   fn get_css_reference_name(&self) -> Option<String> {
     None
   }
+  /// Get the atomic class names used by the transformed expression
+  fn get_atomic_class_names(&self) -> &[String] {
+    &[]
+  }
 }
 
 /// Transform for nested css mixins
@@ -122,6 +132,7 @@ impl YakTransform for TransformNestedCss {
     }
     YakTransformResult {
       css: YakCss {
+        leading_comments: vec![],
         comment_prefix: None,
         declarations: declarations.to_vec(),
       },
@@ -144,7 +155,8 @@ impl YakTransform for TransformNestedCss {
 pub struct TransformCssMixin {
   /// ClassName of the mixin
   export_name: ScopedVariableReference,
-  is_exported: bool,
+  /// Names under which the mixin is exported (empty if not exported)
+  export_names: Vec<Atom>,
   is_within_jsx_attribute: bool,
   class_name: String,
   transpilation_mode: TranspilationMode,
@@ -154,7 +166,7 @@ impl TransformCssMixin {
   pub fn new(
     naming_convention: &mut NamingConvention,
     declaration_name: ScopedVariableReference,
-    is_exported: bool,
+    export_names: Vec<Atom>,
     is_within_jsx_attribute: bool,
     transpilation_mode: TranspilationMode,
   ) -> TransformCssMixin {
@@ -162,12 +174,43 @@ impl TransformCssMixin {
       naming_convention.get_css_variable_name(&declaration_name.to_readable_string());
     TransformCssMixin {
       export_name: declaration_name,
-      is_exported,
+      export_names,
       is_within_jsx_attribute,
       class_name,
       transpilation_mode,
     }
   }
+
+  /// Creates the comments which share the mixin with other files
+  /// e.g. `YAK EXPORTED MIXIN:fonts:h1\ncss\n` and `YAK EXPORTED MIXIN:typography:h1\ncss\n`
+  /// for `export { typography, typography as fonts }`
+  ///
+  /// Mixins which need the props of the importing component are additionally marked
+  /// e.g. `YAK DYNAMIC MIXIN:fonts:h1`
+  fn exported_mixin_comments(&self, css: &str, is_dynamic: bool) -> Vec<String> {
+    let export_chains = self
+      .export_names
+      .iter()
+      .map(|export_name| {
+        std::iter::once(export_name)
+          .chain(self.export_name.parts.iter().skip(1))
+          .map(|atom| encode_percent(atom.as_str()))
+          .join(":")
+      })
+      .collect::<Vec<_>>();
+    let mut comments = export_chains
+      .iter()
+      .map(|export_chain| format!("YAK EXPORTED MIXIN:{}\n{}\n", export_chain, css))
+      .collect::<Vec<_>>();
+    if is_dynamic {
+      comments.extend(
+        export_chains
+          .iter()
+          .map(|export_chain| format!("YAK DYNAMIC MIXIN:{}", export_chain)),
+      );
+    }
+    comments
+  }
 }
 
 impl YakTransform for TransformCssMixin {
@@ -188,21 +231,6 @@ impl YakTransform for TransformCssMixin {
     runtime_css_variables: FxHashMap<String, Expr>,
     _yak_imports: &mut YakImports,
   ) -> YakTransformResult {
-    let has_dynamic_content = !runtime_expressions.is_empty() || !runtime_css_variables.is_empty();
-
-    if self.is_exported && has_dynamic_content && !self.is_within_jsx_attribute {
-      // For now dynamic mixins are not supported cross file
-      // as the scope handling is quite complicated
-      HANDLER.with(|handler| {
-        handler
-          .struct_span_err(
-            expression.span,
-            "Dynamic mixins must not be exported. Please ensure that this mixin requires no props.",
-          )
-          .emit();
-      });
-    }
-
     let mut arguments: Vec<ExprOrSpread> = vec![];
     arguments.extend(runtime_expressions.into_iter().map(ExprOrSpread::from));
     if !runtime_css_variables.is_empty() {
@@ -225,22 +253,53 @@ impl YakTransform for TransformCssMixin {
         .into(),
       );
       Some("YAK Extracted CSS:".to_string())
-    } else if self.is_exported {
-      Some(format!(
-        "YAK EXPORTED MIXIN:{}",
-        self
-          .export_name
-          .parts
-          .iter()
-          .map(|atom| encode_percent(atom.as_str()))
-          .join(":")
-      ))
     } else {
       None
     };
 
+    // Dynamic mixins are shared across files in two parts:
+    // - the static css is inlined by the importing file (YAK EXPORTED MIXIN)
+    // - the conditional css is extracted as global classes of this file which are
+    //   added by the runtime function of the mixin e.g. css(({$active}) => $active && css("..."))
+    //
+    // The importing file only calls the runtime function with its props if it knows
+    // that the mixin is dynamic (YAK DYNAMIC MIXIN)
+    if !self.export_names.is_empty() && !self.is_within_jsx_attribute {
+      let is_dynamic = !arguments.is_empty();
+      let root_scope = self.transpilation_mode.css_class_name(&self.class_name);
+      let (static_declarations, conditional_declarations): (Vec<_>, Vec<_>) =
+        declarations.iter().cloned().partition(|declaration| {
+          declaration
+            .scope
+            .first()
+            .is_none_or(|scope| scope.name == root_scope)
+        });
+      let static_declarations = static_declarations.move_map(|mut declaration| {
+        declaration.scope.remove(0);
+        declaration
+      });
+      let leading_comments =
+        self.exported_mixin_comments(to_css(&static_declarations).trim(), is_dynamic);
+      return YakTransformResult {
+        css: YakCss {
+          leading_comments,
+          comment_prefix: (!conditional_declarations.is_empty())
+            .then(|| "YAK Extracted CSS:".to_string()),
+          declarations: conditional_declarations,
+        },
+        expression: Box::new(Expr::Call(CallExpr {
+          span: expression.span,
+          ctxt: SyntaxContext::empty(),
+          callee: Callee::Expr(expression.tag.clone()),
+          args: arguments,
+          type_args: None,
+        })),
+      };
+    }
+
     YakTransformResult {
       css: YakCss {
+        leading_comments: vec![],
         comment_prefix: css_prefix,
         declarations: declarations.to_vec().move_map(|mut declaration| {
           // TODO: Fix nested mixins
@@ -276,26 +335,42 @@ pub struct TransformStyled {
   class_name: String,
   declaration_name: ScopedVariableReference,
   assign_display_name: bool,
-  is_exported: bool,
+  /// Names under which the styled component is exported (empty if not exported)
+  export_names: Vec<Atom>,
   transpilation_mode: TranspilationMode,
+  /// Class name generator for atomic declarations (only in atomic mode)
+  atomic_class_names: Option<AtomicClassNames>,
+  /// Atomic class names of the transformed styled component
+  generated_atomic_class_names: Vec<String>,
+  /// Passes the props which are only read by the styles to the runtime
+  strip_consumed_props: bool,
 }
 
 impl TransformStyled {
+  /// Uses the given class name if it was already assigned before the transformation
   pub fn new(
     naming_convention: &mut NamingConvention,
+    class_name: Option<String>,
     declaration_name: ScopedVariableReference,
     assign_display_name: bool,
-    is_exported: bool,
+    export_names: Vec<Atom>,
     transpilation_mode: TranspilationMode,
+    strip_consumed_props: bool,
   ) -> TransformStyled {
-    let class_name =
-      naming_convention.get_css_variable_name(&declaration_name.to_readable_string());
+    let class_name = class_name.unwrap_or_else(|| {
+      naming_convention.get_css_variable_name(&declaration_name.to_readable_string())
+    });
+    let atomic_class_names = (transpilation_mode == TranspilationMode::Atomic)
+      .then(|| naming_convention.get_atomic_class_names());
     TransformStyled {
       class_name,
       declaration_name,
       assign_display_name,
-      is_exported,
+      export_names,
       transpilation_mode,
+      atomic_class_names,
+      generated_atomic_class_names: vec![],
+      strip_consumed_props,
     }
   }
 
@@ -359,25 +434,35 @@ fn transform_styled_usages(expression: Box<Expr>, yak_imports: &mut YakImports)
   match *expression.clone() {
     Expr::Member(member) => {
       if let Expr::Ident(ident) = *member.obj {
-        // styled.element``usages
-        if let MemberProp::Ident(member_ident) = member.prop {
-          let member_name = member_ident.sym.as_str();
-          return if let Some(member) = yak_imports.get_yak_component_import(member_name) {
-            // styled.button -> __yak_button
-            member
-          } else {
+        // styled.element`` or styled["custom-element"]`` usages
+        let member_name = match &member.prop {
+          MemberProp::Ident(member_ident) => Some(member_ident.sym.clone()),
+          MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+            _ => None,
+          },
+          MemberProp::PrivateName(_) => None,
+        };
+        if let Some(member_name) = member_name {
+          return match yak_imports.resolve_styled_element(&member_name) {
+            // styled.button -> __yak.__yak_button
+            Some(StyledElement::Runtime(element)) => yak_imports.get_yak_component_import(element),
+            // styled["my-widget"] -> __yak_my_widget
+            Some(StyledElement::Custom(element)) => {
+              yak_imports.get_yak_custom_element_import(&element, &ident)
+            }
             // Transform elements without yakcomponent import to styled("element-name")
-            Box::new(Expr::Call(CallExpr {
+            None => Box::new(Expr::Call(CallExpr {
               span: member.span,
               ctxt: SyntaxContext::empty(),
               callee: Callee::Expr(Box::new(Expr::Ident(ident.clone()))),
               args: vec![ExprOrSpread::from(Box::new(Expr::Lit(Lit::Str(Str {
                 span: DUMMY_SP,
-                value: Atom::new(member_name),
+                value: member_name,
                 raw: None,
               }))))],
               type_args: None,
-            }))
+            })),
           };
         }
       }
@@ -391,7 +476,17 @@ fn transform_styled_usages(expression: Box<Expr>, yak_imports: &mut YakImports)
       span,
     }) => {
       // e.g. styled(Component)
-      if let Expr::Ident(_) = *callee.clone() {
+      if let Expr::Ident(ident) = &*callee {
+        // e.g. styled("my-widget") -> __yak_my_widget
+        if let [ExprOrSpread { spread: None, expr }] = args.as_slice() {
+          if let Expr::Lit(Lit::Str(str)) = &**expr {
+            if let Some(StyledElement::Custom(element)) =
+              yak_imports.resolve_styled_element(&str.value)
+            {
+              return yak_imports.get_yak_custom_element_import(&element, ident);
+            }
+          }
+        }
         return expression;
       }
 
@@ -440,18 +535,55 @@ impl YakTransform for TransformStyled {
     runtime_css_variables: FxHashMap<String, Expr>,
     yak_imports: &mut YakImports,
   ) -> YakTransformResult {
+    // In atomic mode every static declaration is moved to its own class
+    // e.g. styled.div`color: red;` -> styled.div("Wrapper color_a1b2c3")
+    let declarations = match &self.atomic_class_names {
+      Some(atomic_class_names) => {
+        let (class_names, declarations) = atomic_class_names.atomize(
+          &self.transpilation_mode.css_class_name(&self.class_name),
+          declarations,
+          self.transpilation_mode,
+        );
+        self.generated_atomic_class_names = class_names;
+        declarations
+      }
+      None => declarations.to_vec(),
+    };
     let mut arguments: Vec<ExprOrSpread> = vec![];
-    if !declarations.is_empty() || self.is_exported {
+    if !declarations.is_empty() || !self.export_names.is_empty() {
       // As yak generates the final class name, this name can use it directly in the js code
+      // The component class name is kept for selectors like `${Button}:hover &`
+      let class_names = std::iter::once(&self.class_name)
+        .chain(self.generated_atomic_class_names.iter())
+        .join(" ");
       arguments.push(
         Expr::Lit(Lit::Str(Str {
           span: DUMMY_SP,
-          value: self.class_name.clone().into(),
+          value: class_names.into(),
           raw: None,
         }))
         .into(),
       );
     }
+    // Props which are only read by the styles are not forwarded to native elements
+    // e.g. styled.div`color: ${({ tone }) => tone};` -> { consumedProps: ["tone"] }
+    // Custom elements receive all props as attributes e.g. styled("my-widget")
+    let consumed_props = if self.strip_consumed_props && !is_custom_element(&mut expression.tag) {
+      let yak_functions = yak_imports
+        .yak_css_idents()
+        .iter()
+        .cloned()
+        .chain(yak_imports.yak_utility_ids())
+        .collect();
+      get_consumed_props(
+        runtime_expressions
+          .iter()
+          .chain(runtime_css_variables.values()),
+        &yak_functions,
+      )
+    } else {
+      vec![]
+    };
     arguments.extend(runtime_expressions.into_iter().map(ExprOrSpread::from));
     if !runtime_css_variables.is_empty() {
       arguments.push(
@@ -462,6 +594,21 @@ impl YakTransform for TransformStyled {
         .into(),
       );
     }
+    if !consumed_props.is_empty() {
+      arguments.push(
+        expr_hash_map_to_object(FxHashMap::from_iter([(
+          "consumedProps".to_string(),
+          Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: consumed_props
+              .into_iter()
+              .map(|prop| Some(Expr::Lit(Lit::Str(prop.into())).into()))
+              .collect(),
+          }),
+        )]))
+        .into(),
+      );
+    }
     let tag_expression = transform_styled_usages(expression.tag.clone(), yak_imports);
     let result_expr = Box::new(Expr::Call(CallExpr {
       span: expression.span,
@@ -479,20 +626,29 @@ impl YakTransform for TransformStyled {
 
     // Add the class name For cross file selectors to allow the css loader to
     // extract the generated class name
-    let css_prefix = if self.is_exported {
-      Some(format!(
-        "YAK EXPORTED STYLED:{}:{}*//*YAK Extracted CSS:",
-        self.declaration_name.to_readable_string(),
-        self.class_name
-      ))
-    } else {
-      Some("YAK Extracted CSS:".to_string())
-    };
+    // e.g. const Button = styled.button``; export { Button, Button as PrimaryButton };
+    let css_prefix = Some(
+      self
+        .export_names
+        .iter()
+        .map(|export_name| {
+          format!(
+            "YAK EXPORTED STYLED:{}:{}*//*",
+            std::iter::once(export_name)
+              .chain(self.declaration_name.parts.iter().skip(1))
+              .join("."),
+            self.class_name
+          )
+        })
+        .chain(std::iter::once("YAK Extracted CSS:".to_string()))
+        .collect::<String>(),
+    );
 
     YakTransformResult {
       css: YakCss {
+        leading_comments: vec![],
         comment_prefix: css_prefix,
-        declarations: declarations.to_vec(),
+        declarations,
       },
       expression: result_expr,
     }
@@ -502,6 +658,10 @@ impl YakTransform for TransformStyled {
   fn get_css_reference_name(&self) -> Option<String> {
     Some(self.transpilation_mode.css_class_name(&self.class_name))
   }
+
+  fn get_atomic_class_names(&self) -> &[String] {
+    &self.generated_atomic_class_names
+  }
 }
 
 /// Transform for keyframe animations
@@ -529,8 +689,12 @@ impl YakTransform for TransformKeyframes {
     let mut parser_state = ParserState::new();
     parser_state.current_scopes = vec![CssScope {
       name: match &self.transpilation_mode {
-        TranspilationMode::CssModule => format!("@keyframes :global({})", self.animation_name),
-        TranspilationMode::Css => format!("@keyframes {}", self.animation_name),
+        TranspilationMode::CssModule | TranspilationMode::Atomic => {
+          format!("@keyframes :global({})", self.animation_name)
+        }
+        TranspilationMode::Css | TranspilationMode::Scoped => {
+          format!("@keyframes {}", self.animation_name)
+        }
       },
       scope_type: ScopeType::AtRule,
     }];
@@ -567,6 +731,7 @@ impl YakTransform for TransformKeyframes {
     }
     YakTransformResult {
       css: YakCss {
+        leading_comments: vec![],
         comment_prefix: Some("YAK Extracted CSS:".to_string()),
         declarations: declarations.to_vec(),
       },
@@ -583,8 +748,10 @@ impl YakTransform for TransformKeyframes {
   /// Get the selector for the keyframe to be used in other expressions
   fn get_css_reference_name(&self) -> Option<String> {
     Some(match &self.transpilation_mode {
-      TranspilationMode::CssModule => format!("global({})", self.animation_name),
-      TranspilationMode::Css => self.animation_name.clone(),
+      TranspilationMode::CssModule | TranspilationMode::Atomic => {
+        format!("global({})", self.animation_name)
+      }
+      TranspilationMode::Css | TranspilationMode::Scoped => self.animation_name.clone(),
     })
   }
 }
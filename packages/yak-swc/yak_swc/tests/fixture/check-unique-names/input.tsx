@@ -0,0 +1,16 @@
+import { styled, css, keyframes } from "next-yak";
+
+const fadeIn = keyframes`
+  from {
+    opacity: 0;
+  }
+`;
+
+export const Button = styled.button<{ $primary?: boolean }>`
+  animation: ${fadeIn} 1s;
+  ${({ $primary }) =>
+    $primary &&
+    css`
+      color: red;
+    `}
+`;
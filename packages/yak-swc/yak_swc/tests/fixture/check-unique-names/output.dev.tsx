@@ -0,0 +1,20 @@
+import { styled, css, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const fadeIn = /*YAK GENERATED NAMES:[{"column":16,"line":3,"name":"fadeIn_m7uBBu","readableName":"fadeIn"}]*/ /*YAK Extracted CSS:
+@keyframes :global(fadeIn_m7uBBu) {
+  from {
+    opacity: 0;
+  }
+}
+*/ /*#__PURE__*/ keyframes("fadeIn_m7uBBu");
+export const Button = /*YAK GENERATED NAMES:[{"column":23,"line":9,"name":"input_Button_m7uBBu","readableName":"Button"},{"column":23,"line":9,"name":"input_Button__$primary_m7uBBu","readableName":"Button__$primary"}]*/ /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  animation: global(fadeIn_m7uBBu) 1s;
+}
+:global(.input_Button__\$primary_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", ({ $primary })=>$primary && /*#__PURE__*/ css("input_Button__$primary_m7uBBu")), {
+    "displayName": "Button"
+});
@@ -0,0 +1,18 @@
+import { styled, css, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const fadeIn = /*YAK GENERATED NAMES:[{"column":16,"line":3,"name":"ym7uBBu","readableName":"fadeIn"}]*/ /*YAK Extracted CSS:
+@keyframes :global(ym7uBBu) {
+  from {
+    opacity: 0;
+  }
+}
+*/ /*#__PURE__*/ keyframes("ym7uBBu");
+export const Button = /*YAK GENERATED NAMES:[{"column":23,"line":9,"name":"ym7uBBu1","readableName":"Button"},{"column":23,"line":9,"name":"ym7uBBu2","readableName":"Button__$primary"}]*/ /*YAK EXPORTED STYLED:Button:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  animation: global(ym7uBBu) 1s;
+}
+:global(.ym7uBBu2) {
+  color: red;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu1", ({ $primary })=>$primary && /*#__PURE__*/ css("ym7uBBu2"));
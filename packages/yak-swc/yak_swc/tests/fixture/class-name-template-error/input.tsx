@@ -0,0 +1,5 @@
+import { styled } from "next-yak";
+
+export const Button = styled.button`
+  color: red;
+`;
@@ -0,0 +1,8 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
@@ -0,0 +1,16 @@
+import { styled, css, keyframes } from "next-yak";
+
+const fadeIn = keyframes`
+  from { opacity: 0; }
+  to { opacity: 1; }
+`;
+
+export const Button = styled.button`
+  color: ${({ $color }) => $color};
+  animation: ${fadeIn} 1s;
+  ${({ $primary }) =>
+    $primary &&
+    css`
+      background: blue;
+    `}
+`;
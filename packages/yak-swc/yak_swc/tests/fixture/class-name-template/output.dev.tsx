@@ -0,0 +1,28 @@
+import { styled, css, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const fadeIn = /*YAK Extracted CSS:
+@keyframes :global(acme-input-fadeIn-m7uB) {
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+}
+*/ /*#__PURE__*/ keyframes("acme-input-fadeIn-m7uB");
+export const Button = /*YAK EXPORTED STYLED:Button:acme-input-Button-m7uB*//*YAK Extracted CSS:
+:global(.acme-input-Button-m7uB) {
+  color: var(--acme-input-Button__color-m7uB);
+  animation: global(acme-input-fadeIn-m7uB) 1s;
+}
+:global(.acme-input-Button__\$primary-m7uB) {
+  background: blue;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("acme-input-Button-m7uB", ({ $primary })=>$primary && /*#__PURE__*/ css("acme-input-Button__$primary-m7uB"), {
+    "style": {
+        "--acme-input-Button__color-m7uB": ({ $color })=>$color
+    }
+}), {
+    "displayName": "Button"
+});
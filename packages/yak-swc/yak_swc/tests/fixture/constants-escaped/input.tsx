@@ -0,0 +1,27 @@
+import { styled } from "next-yak";
+import { useMemo } from "react";
+import clsx from "clsx";
+
+const theme = {
+  colors: {
+    primary: "red",
+  },
+  sizes: {
+    small: 4,
+  },
+  breakpoints: {
+    md: 768,
+  },
+};
+
+export function useTheme(active: boolean) {
+  console.log(theme);
+  useMemo(() => theme.sizes.small * 2, [theme]);
+  trackBreakpoints(theme.breakpoints);
+  return clsx(theme.colors, active && "active");
+}
+
+export const Button = styled.button`
+  color: ${theme.colors.primary};
+  padding: ${theme.sizes.small}px;
+`;
@@ -0,0 +1,32 @@
+import { styled } from "next-yak/internal";
+import { useMemo } from "react";
+import clsx from "clsx";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const theme = {
+    colors: {
+        primary: "red"
+    },
+    sizes: {
+        small: 4
+    },
+    breakpoints: {
+        md: 768
+    }
+};
+export function useTheme(active: boolean) {
+    console.log(theme);
+    useMemo(()=>theme.sizes.small * 2, [
+        theme
+    ]);
+    trackBreakpoints(theme.breakpoints);
+    return clsx(theme.colors, active && "active");
+}
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  color: red;
+  padding: 4px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+    "displayName": "Button"
+});
@@ -0,0 +1,30 @@
+import { styled } from "next-yak/internal";
+import { useMemo } from "react";
+import clsx from "clsx";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const theme = {
+    colors: {
+        primary: "red"
+    },
+    sizes: {
+        small: 4
+    },
+    breakpoints: {
+        md: 768
+    }
+};
+export function useTheme(active: boolean) {
+    console.log(theme);
+    useMemo(()=>theme.sizes.small * 2, [
+        theme
+    ]);
+    trackBreakpoints(theme.breakpoints);
+    return clsx(theme.colors, active && "active");
+}
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+  padding: 4px;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
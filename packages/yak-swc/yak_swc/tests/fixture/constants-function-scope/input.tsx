@@ -0,0 +1,40 @@
+import { styled, css } from "next-yak";
+
+const pad = 4;
+
+export const Card = () => {
+  const pad = 8;
+  const colors = { primary: "red" };
+  return (
+    <div
+      css={css`
+        padding: ${pad}px;
+        color: ${colors.primary};
+      `}
+    />
+  );
+};
+
+export function Badge() {
+  const pad = 2;
+  return (
+    <span
+      css={css`
+        padding: ${pad}px;
+      `}
+    />
+  );
+}
+
+export const Button = styled.button<{ $large: boolean }>`
+  padding: ${pad}px;
+  ${({ $large }) => {
+    const largePad = 16;
+    return (
+      $large &&
+      css`
+        padding: ${largePad}px;
+      `
+    );
+  }}
+`;
@@ -0,0 +1,37 @@
+import { styled, css } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const pad = 4;
+export const Card = ()=>{
+    const pad = 8;
+    const colors = {
+        primary: "red"
+    };
+    return <div className={/*YAK Extracted CSS:
+:global(.input_Card_m7uBBu) {
+  padding: 8px;
+  color: red;
+}
+*/ "input_Card_m7uBBu"}/>;
+};
+export function Badge() {
+    const pad = 2;
+    return <span className={/*YAK Extracted CSS:
+:global(.input_yak_m7uBBu) {
+  padding: 2px;
+}
+*/ "input_yak_m7uBBu"}/>;
+}
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  padding: 4px;
+}
+:global(.input_Button__\$large_m7uBBu) {
+  padding: 16px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", ({ $large })=>{
+    const largePad = 16;
+    return $large && /*#__PURE__*/ css("input_Button__$large_m7uBBu");
+}), {
+    "displayName": "Button"
+});
@@ -0,0 +1,35 @@
+import { styled, css } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const pad = 4;
+export const Card = ()=>{
+    const pad = 8;
+    const colors = {
+        primary: "red"
+    };
+    return <div className={/*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  padding: 8px;
+  color: red;
+}
+*/ "ym7uBBu"}/>;
+};
+export function Badge() {
+    const pad = 2;
+    return <span className={/*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  padding: 2px;
+}
+*/ "ym7uBBu1"}/>;
+}
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  padding: 4px;
+}
+:global(.ym7uBBu3) {
+  padding: 16px;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu2", ({ $large })=>{
+    const largePad = 16;
+    return $large && /*#__PURE__*/ css("ym7uBBu3");
+});
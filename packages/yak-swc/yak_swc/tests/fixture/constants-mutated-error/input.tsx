@@ -0,0 +1,28 @@
+import { styled } from "next-yak";
+
+let size = 10;
+const theme = {
+  colors: {
+    primary: "red",
+  },
+};
+const colors = theme.colors;
+const spacing = 4;
+
+export function setSize(newSize: number) {
+  size = newSize;
+}
+
+export function setPrimary(color: string) {
+  colors.primary = color;
+}
+
+export const Button = styled.button`
+  padding: ${size}px;
+  color: ${theme.colors.primary};
+  margin: ${spacing}px;
+`;
+
+export const DynamicButton = styled.button`
+  padding: ${() => size}px;
+`;
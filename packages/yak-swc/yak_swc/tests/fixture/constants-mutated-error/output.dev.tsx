@@ -0,0 +1,37 @@
+import { styled, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+let size = 10;
+const theme = {
+    colors: {
+        primary: "red"
+    }
+};
+const colors = theme.colors;
+const spacing = 4;
+export function setSize(newSize: number) {
+    size = newSize;
+}
+export function setPrimary(color: string) {
+    colors.primary = color;
+}
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  padding: px;
+  color: ;
+  margin: 4px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+    "displayName": "Button"
+});
+export const DynamicButton = /*YAK EXPORTED STYLED:DynamicButton:input_DynamicButton_m7uBBu*//*YAK Extracted CSS:
+:global(.input_DynamicButton_m7uBBu) {
+  padding: var(--input_DynamicButton__padding_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_DynamicButton_m7uBBu", {
+    "style": {
+        "--input_DynamicButton__padding_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>size, "px")
+    }
+}), {
+    "displayName": "DynamicButton"
+});
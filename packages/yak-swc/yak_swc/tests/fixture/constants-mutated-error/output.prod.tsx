@@ -0,0 +1,33 @@
+import { styled, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+let size = 10;
+const theme = {
+    colors: {
+        primary: "red"
+    }
+};
+const colors = theme.colors;
+const spacing = 4;
+export function setSize(newSize: number) {
+    size = newSize;
+}
+export function setPrimary(color: string) {
+    colors.primary = color;
+}
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  padding: px;
+  color: ;
+  margin: 4px;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
+export const DynamicButton = /*YAK EXPORTED STYLED:DynamicButton:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  padding: var(--ym7uBBu2);
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu1", {
+    "style": {
+        "--ym7uBBu2": /*#__PURE__*/ __yak_unitPostFix(()=>size, "px")
+    }
+});
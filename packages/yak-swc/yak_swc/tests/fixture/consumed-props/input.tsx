@@ -0,0 +1,33 @@
+import { styled, css } from "next-yak";
+
+export const Button = styled.button<{
+  tone: "primary" | "secondary";
+  size: number;
+  elevated?: boolean;
+  $active?: boolean;
+}>`
+  color: ${({ tone }) => (tone === "primary" ? "white" : "black")};
+  padding: ${(props) => props.size}px;
+  ${({ disabled, $active }) =>
+    (disabled || $active) &&
+    css`
+      opacity: 0.5;
+    `}
+  ${(props) =>
+    props.elevated &&
+    css`
+      box-shadow: 0 ${({ theme }) => theme.shadow}px 4px black;
+    `}
+`;
+
+export const Static = styled.div`
+  color: red;
+`;
+
+export const Widget = styled("my-widget")<{ tone: string }>`
+  color: ${({ tone }) => tone};
+`;
+
+export const List = styled.ul<{ items: { active: boolean; color: string }[] }>`
+  color: ${({ items }) => items.find(({ active }) => active)?.color};
+`;
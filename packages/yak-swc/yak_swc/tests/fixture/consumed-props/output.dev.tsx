@@ -0,0 +1,64 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  color: var(--input_Button__color_m7uBBu);
+  padding: var(--input_Button__padding_m7uBBu);
+}
+:global(.input_Button___m7uBBu) {
+  opacity: 0.5;
+}
+:global(.input_Button__props_elevated_m7uBBu) {
+  box-shadow: 0 var(--input_Button__box-shadow_m7uBBu) 4px black;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", ({ disabled, $active })=>(disabled || $active) && /*#__PURE__*/ css("input_Button___m7uBBu"), (props)=>props.elevated && /*#__PURE__*/ css("input_Button__props_elevated_m7uBBu", {
+        "style": {
+            "--input_Button__box-shadow_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ theme })=>theme.shadow, "px")
+        }
+    }), {
+    "style": {
+        "--input_Button__color_m7uBBu": ({ tone })=>tone === "primary" ? "white" : "black",
+        "--input_Button__padding_m7uBBu": /*#__PURE__*/ __yak_unitPostFix((props)=>props.size, "px")
+    }
+}, {
+    "consumedProps": [
+        "elevated",
+        "tone"
+    ]
+}), {
+    "displayName": "Button"
+});
+export const Static = /*YAK EXPORTED STYLED:Static:input_Static_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Static_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Static_m7uBBu"), {
+    "displayName": "Static"
+});
+export const Widget = /*YAK EXPORTED STYLED:Widget:input_Widget_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Widget_m7uBBu) {
+  color: var(--input_Widget__color_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled("my-widget")("input_Widget_m7uBBu", {
+    "style": {
+        "--input_Widget__color_m7uBBu": ({ tone })=>tone
+    }
+}), {
+    "displayName": "Widget"
+});
+export const List = /*YAK EXPORTED STYLED:List:input_List_m7uBBu*//*YAK Extracted CSS:
+:global(.input_List_m7uBBu) {
+  color: var(--input_List__color_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_ul("input_List_m7uBBu", {
+    "style": {
+        "--input_List__color_m7uBBu": ({ items })=>items.find(({ active })=>active)?.color
+    }
+}, {
+    "consumedProps": [
+        "items"
+    ]
+}), {
+    "displayName": "List"
+});
@@ -0,0 +1,56 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: var(--ym7uBBu1);
+  padding: var(--ym7uBBu2);
+}
+:global(.ym7uBBu3) {
+  opacity: 0.5;
+}
+:global(.ym7uBBu4) {
+  box-shadow: 0 var(--ym7uBBu5) 4px black;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu", ({ disabled, $active })=>(disabled || $active) && /*#__PURE__*/ css("ym7uBBu3"), (props)=>props.elevated && /*#__PURE__*/ css("ym7uBBu4", {
+        "style": {
+            "--ym7uBBu5": /*#__PURE__*/ __yak_unitPostFix(({ theme })=>theme.shadow, "px")
+        }
+    }), {
+    "style": {
+        "--ym7uBBu1": ({ tone })=>tone === "primary" ? "white" : "black",
+        "--ym7uBBu2": /*#__PURE__*/ __yak_unitPostFix((props)=>props.size, "px")
+    }
+}, {
+    "consumedProps": [
+        "elevated",
+        "tone"
+    ]
+});
+export const Static = /*YAK EXPORTED STYLED:Static:ym7uBBu6*//*YAK Extracted CSS:
+:global(.ym7uBBu6) {
+  color: red;
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu6");
+export const Widget = /*YAK EXPORTED STYLED:Widget:ym7uBBu7*//*YAK Extracted CSS:
+:global(.ym7uBBu7) {
+  color: var(--ym7uBBu8);
+}
+*/ /*#__PURE__*/ styled("my-widget")("ym7uBBu7", {
+    "style": {
+        "--ym7uBBu8": ({ tone })=>tone
+    }
+});
+export const List = /*YAK EXPORTED STYLED:List:ym7uBBu9*//*YAK Extracted CSS:
+:global(.ym7uBBu9) {
+  color: var(--ym7uBBuA);
+}
+*/ /*#__PURE__*/ __yak.__yak_ul("ym7uBBu9", {
+    "style": {
+        "--ym7uBBuA": ({ items })=>items.find(({ active })=>active)?.color
+    }
+}, {
+    "consumedProps": [
+        "items"
+    ]
+});
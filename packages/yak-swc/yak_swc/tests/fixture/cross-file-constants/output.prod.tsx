@@ -9,13 +9,13 @@ import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
 export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
 :global(.ym7uBBu) {
-  font-size: --yak-css-import: url("./fontDefinitions:fonts:sm",mixin);
-  color: --yak-css-import: url("./colorDefinitions:colors:dark:primary",mixin);
-  border-color: --yak-css-import: url("./colorDefinitions:colors:shadows:dark:primary",mixin);
-  background-color: --yak-css-import: url("./colorDefinitions:colors:light:full%20opacity",mixin);
-  height: --yak-css-import: url("./sizeDefinitions:sizes:0",mixin);
-  padding: --yak-css-import: url("./otherConstants:spacing",mixin);
-  margin: --yak-css-import: url("./moreSizes:s:medium:top",mixin) --yak-css-import: url("./moreSizes:s:medium:right",mixin);
-  line-height: --yak-css-import: url("./betterFontSizes:default:lineHeight:0",mixin);
+  font-size: --yak-css-import: url("./fontDefinitions:fonts:sm",value);
+  color: --yak-css-import: url("./colorDefinitions:colors:dark:primary",value);
+  border-color: --yak-css-import: url("./colorDefinitions:colors:shadows:dark:primary",value);
+  background-color: --yak-css-import: url("./colorDefinitions:colors:light:full%20opacity",value);
+  height: --yak-css-import: url("./sizeDefinitions:sizes:0",value);
+  padding: --yak-css-import: url("./otherConstants:spacing",value);
+  margin: --yak-css-import: url("./moreSizes:s:medium:top",value) --yak-css-import: url("./moreSizes:s:medium:right",value);
+  line-height: --yak-css-import: url("./betterFontSizes:default:lineHeight:0",value);
 }
 */ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
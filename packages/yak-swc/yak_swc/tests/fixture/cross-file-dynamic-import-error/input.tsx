@@ -0,0 +1,10 @@
+import { lazy } from "react";
+import { styled } from "next-yak";
+
+const Icon = lazy(() => import("./Icon").then(loadIcon));
+
+export const Button = styled.button`
+  ${Icon} {
+    margin-right: 4px;
+  }
+`;
@@ -0,0 +1,12 @@
+import { lazy } from "react";
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Icon = lazy(()=>import("./Icon").then(loadIcon));
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+   {
+    margin-right: 4px;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
@@ -0,0 +1,28 @@
+import { lazy } from "react";
+import dynamic from "next/dynamic";
+import { styled } from "next-yak";
+
+const Icon = lazy(() => import("./Icon"));
+const Avatar = dynamic(() => import("./Avatar").then((mod) => mod.Avatar));
+const Badge = lazy(() =>
+  import("./Badge").then((mod) => ({ default: mod.Badge })),
+);
+const { Tooltip, Popover: Overlay } = require("./overlays");
+const Title = require("./Title").default;
+const colors = require("./colors");
+
+export const Button = styled.button`
+  color: ${colors.primary};
+  ${Icon} {
+    margin-right: 4px;
+  }
+  ${Avatar}, ${Badge} {
+    border-radius: 50%;
+  }
+  ${Tooltip}:hover, ${Overlay} {
+    display: block;
+  }
+  ${Title} & {
+    font-weight: bold;
+  }
+`;
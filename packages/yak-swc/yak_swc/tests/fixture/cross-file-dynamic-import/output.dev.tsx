@@ -0,0 +1,32 @@
+import { lazy } from "react";
+import dynamic from "next/dynamic";
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Icon = lazy(()=>import("./Icon"));
+const Avatar = dynamic(()=>import("./Avatar").then((mod)=>mod.Avatar));
+const Badge = lazy(()=>import("./Badge").then((mod)=>({
+            default: mod.Badge
+        })));
+const { Tooltip, Popover: Overlay } = require("./overlays");
+const Title = require("./Title").default;
+const colors = require("./colors");
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  color: --yak-css-import: url("./colors:primary",value);
+  --yak-css-import: url("./Icon:default",selector) {
+    margin-right: 4px;
+  }
+  --yak-css-import: url("./Avatar:Avatar",selector), --yak-css-import: url("./Badge:Badge",selector) {
+    border-radius: 50%;
+  }
+  --yak-css-import: url("./overlays:Tooltip",selector):hover, --yak-css-import: url("./overlays:Popover",selector) {
+    display: block;
+  }
+  --yak-css-import: url("./Title:default",selector) & {
+    font-weight: bold;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+    "displayName": "Button"
+});
@@ -0,0 +1,23 @@
+import { css, styled } from "next-yak";
+
+const Button = styled.button`
+  padding: 10px 20px;
+`;
+
+const Title = styled.h1`
+  font-size: 2rem;
+`;
+
+const typography = {
+  small: css`
+    font-size: 12px;
+  `,
+};
+
+const textStyles = css`
+  color: black;
+`;
+
+export { Button, Button as PrimaryButton, textStyles as text, typography };
+export { Icon } from "./Icon";
+export default Title;
@@ -0,0 +1,28 @@
+import { css, styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK EXPORTED STYLED:PrimaryButton:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  padding: 10px 20px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+    "displayName": "Button"
+});
+const Title = /*YAK EXPORTED STYLED:default:input_Title_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Title_m7uBBu) {
+  font-size: 2rem;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_h1("input_Title_m7uBBu"), {
+    "displayName": "Title"
+});
+const typography = {
+    small: /*YAK EXPORTED MIXIN:typography:small
+font-size: 12px;
+*/ /*#__PURE__*/ css()
+};
+const textStyles = /*YAK EXPORTED MIXIN:text
+color: black;
+*/ /*#__PURE__*/ css();
+export { Button, Button as PrimaryButton, textStyles as text, typography };
+export { Icon } from "./Icon";
+export default Title;
@@ -0,0 +1,24 @@
+import { css, styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK EXPORTED STYLED:PrimaryButton:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  padding: 10px 20px;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
+const Title = /*YAK EXPORTED STYLED:default:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  font-size: 2rem;
+}
+*/ /*#__PURE__*/ __yak.__yak_h1("ym7uBBu1");
+const typography = {
+    small: /*YAK EXPORTED MIXIN:typography:small
+font-size: 12px;
+*/ /*#__PURE__*/ css()
+};
+const textStyles = /*YAK EXPORTED MIXIN:text
+color: black;
+*/ /*#__PURE__*/ css();
+export { Button, Button as PrimaryButton, textStyles as text, typography };
+export { Icon } from "./Icon";
+export default Title;
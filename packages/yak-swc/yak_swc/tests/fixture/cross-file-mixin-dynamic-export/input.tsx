@@ -33,3 +33,8 @@ export const Button = styled.button`
     ${textStyles};
   }
 `;
+
+export const sizeStyles = css<{ $size: number }>`
+  width: ${({ $size }) => $size}px;
+  height: ${({ $size }) => $size}px;
+`;
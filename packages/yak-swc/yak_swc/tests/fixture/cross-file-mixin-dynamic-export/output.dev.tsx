@@ -1,4 +1,4 @@
-import { css, styled } from "next-yak/internal";
+import { css, styled, __yak_unitPostFix } from "next-yak/internal";
 import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
 const textColor = /*#__PURE__*/ css(({ $active })=>$active && /*#__PURE__*/ css("input_textColor__$active_m7uBBu"));
@@ -10,7 +10,10 @@ border-radius: 5px;
 cursor: pointer;
 font-size: 16px;
 color: black;
-color: red;
+*/ /*YAK DYNAMIC MIXIN:buttonStyles*/ /*YAK Extracted CSS:
+:global(.input_buttonStyles__\$active_m7uBBu) {
+  color: red;
+}
 */ /*#__PURE__*/ css(({ $active })=>$active && /*#__PURE__*/ css("input_buttonStyles__$active_m7uBBu"));
 export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
 :global(.input_Button_m7uBBu) {
@@ -55,3 +58,11 @@ export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Ex
 */ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", ({ $isSet })=>$isSet && true && true && true && /*#__PURE__*/ css("input_Button___m7uBBu", ({ $active })=>$active && /*#__PURE__*/ css("input_Button__-and-$active_m7uBBu")), ({ $active })=>$active && /*#__PURE__*/ css("input_Button__$active_m7uBBu"), ({ $active })=>$active && /*#__PURE__*/ css("input_Button__$active_m7uBBu-01")), {
     "displayName": "Button"
 });
+export const sizeStyles = /*YAK EXPORTED MIXIN:sizeStyles
+width: var(--input_sizeStyles__width_m7uBBu);
+height: var(--input_sizeStyles__width_m7uBBu);
+*/ /*YAK DYNAMIC MIXIN:sizeStyles*/ /*#__PURE__*/ css({
+    "style": {
+        "--input_sizeStyles__width_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $size })=>$size, "px")
+    }
+});
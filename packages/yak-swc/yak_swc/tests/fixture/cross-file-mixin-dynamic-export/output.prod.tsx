@@ -1,4 +1,4 @@
-import { css, styled } from "next-yak/internal";
+import { css, styled, __yak_unitPostFix } from "next-yak/internal";
 import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
 const textColor = /*#__PURE__*/ css(({ $active })=>$active && /*#__PURE__*/ css("ym7uBBu1"));
@@ -10,7 +10,10 @@ border-radius: 5px;
 cursor: pointer;
 font-size: 16px;
 color: black;
-color: red;
+*/ /*YAK DYNAMIC MIXIN:buttonStyles*/ /*YAK Extracted CSS:
+:global(.ym7uBBu5) {
+  color: red;
+}
 */ /*#__PURE__*/ css(({ $active })=>$active && /*#__PURE__*/ css("ym7uBBu5"));
 export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu6*//*YAK Extracted CSS:
 :global(.ym7uBBu6) {
@@ -53,3 +56,11 @@ export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu6*//*YAK Extracted CSS
   }
 }
 */ /*#__PURE__*/ __yak.__yak_button("ym7uBBu6", ({ $isSet })=>$isSet && true && true && true && /*#__PURE__*/ css("ym7uBBu7", ({ $active })=>$active && /*#__PURE__*/ css("ym7uBBu8")), ({ $active })=>$active && /*#__PURE__*/ css("ym7uBBu9"), ({ $active })=>$active && /*#__PURE__*/ css("ym7uBBuA"));
+export const sizeStyles = /*YAK EXPORTED MIXIN:sizeStyles
+width: var(--ym7uBBuC);
+height: var(--ym7uBBuC);
+*/ /*YAK DYNAMIC MIXIN:sizeStyles*/ /*#__PURE__*/ css({
+    "style": {
+        "--ym7uBBuC": /*#__PURE__*/ __yak_unitPostFix(({ $size })=>$size, "px")
+    }
+});
@@ -12,7 +12,7 @@ cursor: pointer;
 font-size: 16px;
 color: black;
 --yak-css-import: url("./typography:typogaphyMixin",mixin);
-*/ /*#__PURE__*/ css();
+*/ /*YAK DYNAMIC MIXIN:buttonStyles*/ /*#__PURE__*/ css(()=>typogaphyMixin);
 export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
 :global(.input_Button_m7uBBu) {
   padding: 10px 20px;
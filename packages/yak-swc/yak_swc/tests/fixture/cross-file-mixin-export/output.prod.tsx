@@ -12,7 +12,7 @@ cursor: pointer;
 font-size: 16px;
 color: black;
 --yak-css-import: url("./typography:typogaphyMixin",mixin);
-*/ /*#__PURE__*/ css();
+*/ /*YAK DYNAMIC MIXIN:buttonStyles*/ /*#__PURE__*/ css(()=>typogaphyMixin);
 export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu3*//*YAK Extracted CSS:
 :global(.ym7uBBu3) {
   padding: 10px 20px;
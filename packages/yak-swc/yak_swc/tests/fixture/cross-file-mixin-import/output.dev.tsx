@@ -11,14 +11,14 @@ export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Ex
 :global(.input_Button_m7uBBu) {
   --yak-css-import: url("./fonts:fonts:h1",mixin);
 }
-*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", ()=>fonts.h1), {
     "displayName": "Button"
 });
 export const Button2 = /*YAK EXPORTED STYLED:Button2:input_Button2_m7uBBu*//*YAK Extracted CSS:
 :global(.input_Button2_m7uBBu) {
   --yak-css-import: url("./fonts:fonts:h1",mixin);
 }
-*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button2_m7uBBu"), {
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button2_m7uBBu", ()=>fonts.h1), {
     "displayName": "Button2"
 });
 export const Button3 = /*YAK EXPORTED STYLED:Button3:input_Button3_m7uBBu*//*YAK Extracted CSS:
@@ -26,7 +26,7 @@ export const Button3 = /*YAK EXPORTED STYLED:Button3:input_Button3_m7uBBu*//*YAK
   --yak-css-import: url("./fonts:fonts:h1",mixin);
   color: green;
 }
-*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button3_m7uBBu"), {
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button3_m7uBBu", ()=>fonts.h1), {
     "displayName": "Button3"
 });
 export const Button4 = /*YAK EXPORTED STYLED:Button4:input_Button4_m7uBBu*//*YAK Extracted CSS:
@@ -35,7 +35,7 @@ export const Button4 = /*YAK EXPORTED STYLED:Button4:input_Button4_m7uBBu*//*YAK
 --yak-css-import: url("./fonts:fonts:underline",mixin);
   color: green;
 }
-*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button4_m7uBBu"), {
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button4_m7uBBu", ()=>fonts.h1, ()=>fonts.underline), {
     "displayName": "Button4"
 });
 export const Button5 = /*YAK EXPORTED STYLED:Button5:input_Button5_m7uBBu*//*YAK Extracted CSS:
@@ -44,15 +44,15 @@ export const Button5 = /*YAK EXPORTED STYLED:Button5:input_Button5_m7uBBu*//*YAK
   --yak-css-import: url("./fancy:fancy:mixins:specialEffect",mixin);
   color: green;
 }
-*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button5_m7uBBu"), {
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button5_m7uBBu", ()=>fonts.h1, ()=>fancy.mixins.specialEffect), {
     "displayName": "Button5"
 });
 export const Button6 = /*YAK EXPORTED STYLED:Button6:input_Button6_m7uBBu*//*YAK Extracted CSS:
 :global(.input_Button6_m7uBBu) {
   &:hover {
-    --yak-css-import: url("./constants.yak:yakMixin",mixin);
+    --yak-css-import: url("./constants.yak:yakMixin",static-mixin);
   }
-  --yak-css-import: url("./fancy:fancy:mixins:specialEffect",mixin)
+  --yak-css-import: url("./fancy:fancy:mixins:specialEffect",static-mixin)
 ;
   color: green;
 }
@@ -62,9 +62,9 @@ export const Button6 = /*YAK EXPORTED STYLED:Button6:input_Button6_m7uBBu*//*YAK
 export const Button7 = /*YAK EXPORTED STYLED:Button7:input_Button7_m7uBBu*//*YAK Extracted CSS:
 :global(.input_Button7_m7uBBu) {
   &:hover {
-    --yak-css-import: url("./constants.yak:yakMixin",mixin);
+    --yak-css-import: url("./constants.yak:yakMixin",static-mixin);
   }
-  --yak-css-import: url("./fancy:fancy:aspectRatio:16%3A9",mixin)
+  --yak-css-import: url("./fancy:fancy:aspectRatio:16%3A9",static-mixin)
 ;
   color: green;
 }
@@ -11,38 +11,38 @@ export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
 :global(.ym7uBBu) {
   --yak-css-import: url("./fonts:fonts:h1",mixin);
 }
-*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu");
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu", ()=>fonts.h1);
 export const Button2 = /*YAK EXPORTED STYLED:Button2:ym7uBBu1*//*YAK Extracted CSS:
 :global(.ym7uBBu1) {
   --yak-css-import: url("./fonts:fonts:h1",mixin);
 }
-*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu1");
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu1", ()=>fonts.h1);
 export const Button3 = /*YAK EXPORTED STYLED:Button3:ym7uBBu2*//*YAK Extracted CSS:
 :global(.ym7uBBu2) {
   --yak-css-import: url("./fonts:fonts:h1",mixin);
   color: green;
 }
-*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu2");
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu2", ()=>fonts.h1);
 export const Button4 = /*YAK EXPORTED STYLED:Button4:ym7uBBu3*//*YAK Extracted CSS:
 :global(.ym7uBBu3) {
   --yak-css-import: url("./fonts:fonts:h1",mixin)
 --yak-css-import: url("./fonts:fonts:underline",mixin);
   color: green;
 }
-*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu3");
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu3", ()=>fonts.h1, ()=>fonts.underline);
 export const Button5 = /*YAK EXPORTED STYLED:Button5:ym7uBBu4*//*YAK Extracted CSS:
 :global(.ym7uBBu4) {
   --yak-css-import: url("./fonts:fonts:h1",mixin);
   --yak-css-import: url("./fancy:fancy:mixins:specialEffect",mixin);
   color: green;
 }
-*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu4");
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu4", ()=>fonts.h1, ()=>fancy.mixins.specialEffect);
 export const Button6 = /*YAK EXPORTED STYLED:Button6:ym7uBBu5*//*YAK Extracted CSS:
 :global(.ym7uBBu5) {
   &:hover {
-    --yak-css-import: url("./constants.yak:yakMixin",mixin);
+    --yak-css-import: url("./constants.yak:yakMixin",static-mixin);
   }
-  --yak-css-import: url("./fancy:fancy:mixins:specialEffect",mixin)
+  --yak-css-import: url("./fancy:fancy:mixins:specialEffect",static-mixin)
 ;
   color: green;
 }
@@ -50,9 +50,9 @@ export const Button6 = /*YAK EXPORTED STYLED:Button6:ym7uBBu5*//*YAK Extracted C
 export const Button7 = /*YAK EXPORTED STYLED:Button7:ym7uBBu6*//*YAK Extracted CSS:
 :global(.ym7uBBu6) {
   &:hover {
-    --yak-css-import: url("./constants.yak:yakMixin",mixin);
+    --yak-css-import: url("./constants.yak:yakMixin",static-mixin);
   }
-  --yak-css-import: url("./fancy:fancy:aspectRatio:16%3A9",mixin)
+  --yak-css-import: url("./fancy:fancy:aspectRatio:16%3A9",static-mixin)
 ;
   color: green;
 }
@@ -0,0 +1,5 @@
+import { styled } from "next-yak";
+
+export const Icon = styled.div<{ $folder: string; $name: string }>`
+  background: url(/${({ $folder }) => $folder}/${({ $name }) => $name}.svg);
+`;
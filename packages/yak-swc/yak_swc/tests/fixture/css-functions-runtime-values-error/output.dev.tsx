@@ -0,0 +1,15 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Icon = /*YAK EXPORTED STYLED:Icon:input_Icon_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Icon_m7uBBu) {
+  background: url(/var(--input_Icon__background_m7uBBu)/var(--input_Icon__background_m7uBBu-01).svg);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Icon_m7uBBu", {
+    "style": {
+        "--input_Icon__background_m7uBBu": ({ $folder })=>$folder,
+        "--input_Icon__background_m7uBBu-01": ({ $name })=>$name
+    }
+}), {
+    "displayName": "Icon"
+});
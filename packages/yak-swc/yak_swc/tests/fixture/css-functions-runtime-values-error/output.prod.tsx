@@ -0,0 +1,13 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Icon = /*YAK EXPORTED STYLED:Icon:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  background: url(/var(--ym7uBBu1)/var(--ym7uBBu2).svg);
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu", {
+    "style": {
+        "--ym7uBBu1": ({ $folder })=>$folder,
+        "--ym7uBBu2": ({ $name })=>$name
+    }
+});
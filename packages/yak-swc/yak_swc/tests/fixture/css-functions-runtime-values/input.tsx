@@ -0,0 +1,26 @@
+import { styled } from "next-yak";
+
+export const Hero = styled.div<{
+  $src: string;
+  $name: string;
+  $label: string;
+  $x: number;
+  $y: number;
+  $columns: number;
+  $size: number;
+}>`
+  background: url(${({ $src }) => $src}) no-repeat;
+  mask-image: url("/icons/${({ $name }) => $name}.svg");
+  transform: translate(${({ $x }) => $x}px, ${({ $y }) => $y}px);
+  grid-template-columns: repeat(${({ $columns }) => $columns}, 1fr);
+  width: calc(${({ $size }) => $size}px + 2rem);
+  margin: ${({ $y }) => $y}px ${({ $x }) => $x}%;
+
+  &::before {
+    content: "Label: ${({ $label }) => $label}";
+  }
+
+  &::after {
+    content: "\f101  ${({ $label }) => $label} \"";
+  }
+`;
@@ -0,0 +1,33 @@
+import { styled, __yak_cssUrl, __yak_cssString, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Hero = /*YAK EXPORTED STYLED:Hero:input_Hero_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Hero_m7uBBu) {
+  background: var(--input_Hero__background_m7uBBu) no-repeat;
+  mask-image: var(--input_Hero__mask-image_m7uBBu);
+  transform: translate(var(--input_Hero__transform_m7uBBu), var(--input_Hero__transform_m7uBBu-01));
+  grid-template-columns: repeat(var(--input_Hero__grid-template-columns_m7uBBu), 1fr);
+  width: calc(var(--input_Hero__width_m7uBBu) + 2rem);
+  margin: var(--input_Hero__transform_m7uBBu-01) var(--input_Hero__margin_m7uBBu);
+  &::before {
+    content: var(--input_Hero__content_m7uBBu);
+  }
+  &::after {
+    content: var(--input_Hero__content_m7uBBu-01);
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Hero_m7uBBu", {
+    "style": {
+        "--input_Hero__background_m7uBBu": /*#__PURE__*/ __yak_cssUrl(({ $src })=>$src, "", ""),
+        "--input_Hero__content_m7uBBu": /*#__PURE__*/ __yak_cssString(({ $label })=>$label, "Label: ", ""),
+        "--input_Hero__content_m7uBBu-01": /*#__PURE__*/ __yak_cssString(({ $label })=>$label, " ", ' "'),
+        "--input_Hero__grid-template-columns_m7uBBu": ({ $columns })=>$columns,
+        "--input_Hero__margin_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $x })=>$x, "%"),
+        "--input_Hero__mask-image_m7uBBu": /*#__PURE__*/ __yak_cssUrl(({ $name })=>$name, "/icons/", ".svg"),
+        "--input_Hero__transform_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $x })=>$x, "px"),
+        "--input_Hero__transform_m7uBBu-01": /*#__PURE__*/ __yak_unitPostFix(({ $y })=>$y, "px"),
+        "--input_Hero__width_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $size })=>$size, "px")
+    }
+}), {
+    "displayName": "Hero"
+});
@@ -0,0 +1,31 @@
+import { styled, __yak_cssUrl, __yak_cssString, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Hero = /*YAK EXPORTED STYLED:Hero:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  background: var(--ym7uBBu1) no-repeat;
+  mask-image: var(--ym7uBBu2);
+  transform: translate(var(--ym7uBBu3), var(--ym7uBBu4));
+  grid-template-columns: repeat(var(--ym7uBBu5), 1fr);
+  width: calc(var(--ym7uBBu6) + 2rem);
+  margin: var(--ym7uBBu4) var(--ym7uBBu7);
+  &::before {
+    content: var(--ym7uBBu8);
+  }
+  &::after {
+    content: var(--ym7uBBu9);
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu", {
+    "style": {
+        "--ym7uBBu1": /*#__PURE__*/ __yak_cssUrl(({ $src })=>$src, "", ""),
+        "--ym7uBBu2": /*#__PURE__*/ __yak_cssUrl(({ $name })=>$name, "/icons/", ".svg"),
+        "--ym7uBBu3": /*#__PURE__*/ __yak_unitPostFix(({ $x })=>$x, "px"),
+        "--ym7uBBu4": /*#__PURE__*/ __yak_unitPostFix(({ $y })=>$y, "px"),
+        "--ym7uBBu5": ({ $columns })=>$columns,
+        "--ym7uBBu6": /*#__PURE__*/ __yak_unitPostFix(({ $size })=>$size, "px"),
+        "--ym7uBBu7": /*#__PURE__*/ __yak_unitPostFix(({ $x })=>$x, "%"),
+        "--ym7uBBu8": /*#__PURE__*/ __yak_cssString(({ $label })=>$label, "Label: ", ""),
+        "--ym7uBBu9": /*#__PURE__*/ __yak_cssString(({ $label })=>$label, " ", ' "')
+    }
+});
@@ -0,0 +1,45 @@
+import { css } from "next-yak";
+import clsx from "clsx";
+// @ts-ignore
+import { cn } from "./utils";
+
+const Elem = ({ active }: { active: boolean }) => (
+  <div
+    className={cn("base", active && "active")}
+    css={css`
+      color: red;
+    `}
+  />
+);
+
+const Elem2 = ({ active }: { active: boolean }) => (
+  <div
+    css={css`
+      color: blue;
+      ${active &&
+      css`
+        color: green;
+      `}
+    `}
+    className={clsx("base", { active })}
+  />
+);
+
+const Elem3 = ({ color }: { color: string }) => (
+  <div
+    className={cn("base")}
+    css={css`
+      color: ${() => color};
+    `}
+  />
+);
+
+const Elem4 = () => (
+  <div
+    className={cn("base")}
+    style={{ padding: 0 }}
+    css={css`
+      color: orange;
+    `}
+  />
+);
@@ -0,0 +1,49 @@
+import { css, __yak_mergeCssProp } from "next-yak/internal";
+import clsx from "clsx";
+// @ts-ignore
+import { cn } from "./utils";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ({ active }: {
+    active: boolean;
+})=><div className={cn("base", active && "active", /*YAK Extracted CSS:
+:global(.input_Elem_m7uBBu) {
+  color: red;
+}
+*/ "input_Elem_m7uBBu")}/>;
+const Elem2 = ({ active }: {
+    active: boolean;
+})=><div {...__yak_mergeCssProp({
+        className: clsx("base", {
+            active
+        })
+    }, /*YAK Extracted CSS:
+:global(.input_Elem2_m7uBBu) {
+  color: blue;
+}
+:global(.input_Elem2___m7uBBu) {
+  color: green;
+}
+*/ /*#__PURE__*/ css(active && /*#__PURE__*/ css("input_Elem2___m7uBBu"), "input_Elem2_m7uBBu"))}/>;
+const Elem3 = ({ color }: {
+    color: string;
+})=><div {...__yak_mergeCssProp({
+        className: cn("base")
+    }, /*YAK Extracted CSS:
+:global(.input_Elem3_m7uBBu) {
+  color: var(--input_Elem3__color_m7uBBu);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--input_Elem3__color_m7uBBu": ()=>color
+        }
+    }, "input_Elem3_m7uBBu"))}/>;
+const Elem4 = ()=><div {...__yak_mergeCssProp({
+        className: cn("base"),
+        style: {
+            padding: 0
+        }
+    }, /*YAK Extracted CSS:
+:global(.input_Elem4_m7uBBu) {
+  color: orange;
+}
+*/ /*#__PURE__*/ css("input_Elem4_m7uBBu"))}/>;
@@ -0,0 +1,49 @@
+import { css, __yak_mergeCssProp } from "next-yak/internal";
+import clsx from "clsx";
+// @ts-ignore
+import { cn } from "./utils";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ({ active }: {
+    active: boolean;
+})=><div className={cn("base", active && "active", /*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ "ym7uBBu")}/>;
+const Elem2 = ({ active }: {
+    active: boolean;
+})=><div {...__yak_mergeCssProp({
+        className: clsx("base", {
+            active
+        })
+    }, /*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: blue;
+}
+:global(.ym7uBBu2) {
+  color: green;
+}
+*/ /*#__PURE__*/ css(active && /*#__PURE__*/ css("ym7uBBu2"), "ym7uBBu1"))}/>;
+const Elem3 = ({ color }: {
+    color: string;
+})=><div {...__yak_mergeCssProp({
+        className: cn("base")
+    }, /*YAK Extracted CSS:
+:global(.ym7uBBu3) {
+  color: var(--ym7uBBu4);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--ym7uBBu4": ()=>color
+        }
+    }, "ym7uBBu3"))}/>;
+const Elem4 = ()=><div {...__yak_mergeCssProp({
+        className: cn("base"),
+        style: {
+            padding: 0
+        }
+    }, /*YAK Extracted CSS:
+:global(.ym7uBBu5) {
+  color: orange;
+}
+*/ /*#__PURE__*/ css("ym7uBBu5"))}/>;
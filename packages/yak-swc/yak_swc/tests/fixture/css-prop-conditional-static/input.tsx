@@ -0,0 +1,53 @@
+import { css } from "next-yak";
+
+const Elem = ({ a, b }: any) => (
+  <div
+    css={a ? css`
+      color: red;
+    ` : css`
+      color: blue;
+    `}
+  />
+);
+
+const Elem2 = ({ a, b }: any) => (
+  <div
+    css={a && b && css`
+      color: red;
+    `}
+  />
+);
+
+const Elem3 = ({ a, b }: any) => (
+  <div
+    css={(a && css`
+      color: red;
+    `) || css`
+      color: green;
+    `}
+  />
+);
+
+// runtime values still require mergeCssProp
+const Elem4 = ({ a, b }: any) => (
+  <div
+    css={a ? css`
+      color: ${() => b};
+    ` : css`
+      color: blue;
+    `}
+  />
+);
+
+// css calls in a condition stay at runtime
+const Elem5 = ({ a, b }: any) => (
+  <div
+    css={(a ? css`
+      color: blue;
+    ` : css`
+      color: ${() => b};
+    `) && css`
+      color: red;
+    `}
+  />
+);
@@ -0,0 +1,57 @@
+import { css, __yak_mergeCssProp } from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ({ a, b }: any)=><div className={a ? /*YAK Extracted CSS:
+:global(.input_Elem_m7uBBu) {
+  color: red;
+}
+*/ "input_Elem_m7uBBu" : /*YAK Extracted CSS:
+:global(.input_Elem_m7uBBu-01) {
+  color: blue;
+}
+*/ "input_Elem_m7uBBu-01"}/>;
+const Elem2 = ({ a, b }: any)=><div className={a && b ? /*YAK Extracted CSS:
+:global(.input_Elem2_m7uBBu) {
+  color: red;
+}
+*/ "input_Elem2_m7uBBu" : void 0}/>;
+const Elem3 = ({ a, b }: any)=><div className={(a ? /*YAK Extracted CSS:
+:global(.input_Elem3_m7uBBu) {
+  color: red;
+}
+*/ "input_Elem3_m7uBBu" : void 0) || /*YAK Extracted CSS:
+:global(.input_Elem3_m7uBBu-01) {
+  color: green;
+}
+*/ "input_Elem3_m7uBBu-01"}/>;
+// runtime values still require mergeCssProp
+const Elem4 = ({ a, b }: any)=><div {...__yak_mergeCssProp({}, a ? /*YAK Extracted CSS:
+:global(.input_Elem4_m7uBBu) {
+  color: var(--input_Elem4__color_m7uBBu);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--input_Elem4__color_m7uBBu": ()=>b
+        }
+    }, "input_Elem4_m7uBBu") : /*YAK Extracted CSS:
+:global(.input_Elem4_m7uBBu-01) {
+  color: blue;
+}
+*/ /*#__PURE__*/ css("input_Elem4_m7uBBu-01"))}/>;
+// css calls in a condition stay at runtime
+const Elem5 = ({ a, b }: any)=><div className={(a ? /*YAK Extracted CSS:
+:global(.input_Elem5_m7uBBu) {
+  color: blue;
+}
+*/ /*#__PURE__*/ css("input_Elem5_m7uBBu") : /*YAK Extracted CSS:
+:global(.input_Elem5_m7uBBu-01) {
+  color: var(--input_Elem5__color_m7uBBu);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--input_Elem5__color_m7uBBu": ()=>b
+        }
+    }, "input_Elem5_m7uBBu-01")) ? /*YAK Extracted CSS:
+:global(.input_Elem5_m7uBBu-02) {
+  color: red;
+}
+*/ "input_Elem5_m7uBBu-02" : void 0}/>;
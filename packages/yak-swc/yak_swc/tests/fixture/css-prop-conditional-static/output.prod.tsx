@@ -0,0 +1,57 @@
+import { css, __yak_mergeCssProp } from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ({ a, b }: any)=><div className={a ? /*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ "ym7uBBu" : /*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: blue;
+}
+*/ "ym7uBBu1"}/>;
+const Elem2 = ({ a, b }: any)=><div className={a && b ? /*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: red;
+}
+*/ "ym7uBBu2" : void 0}/>;
+const Elem3 = ({ a, b }: any)=><div className={(a ? /*YAK Extracted CSS:
+:global(.ym7uBBu3) {
+  color: red;
+}
+*/ "ym7uBBu3" : void 0) || /*YAK Extracted CSS:
+:global(.ym7uBBu4) {
+  color: green;
+}
+*/ "ym7uBBu4"}/>;
+// runtime values still require mergeCssProp
+const Elem4 = ({ a, b }: any)=><div {...__yak_mergeCssProp({}, a ? /*YAK Extracted CSS:
+:global(.ym7uBBu5) {
+  color: var(--ym7uBBu6);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--ym7uBBu6": ()=>b
+        }
+    }, "ym7uBBu5") : /*YAK Extracted CSS:
+:global(.ym7uBBu7) {
+  color: blue;
+}
+*/ /*#__PURE__*/ css("ym7uBBu7"))}/>;
+// css calls in a condition stay at runtime
+const Elem5 = ({ a, b }: any)=><div className={(a ? /*YAK Extracted CSS:
+:global(.ym7uBBu8) {
+  color: blue;
+}
+*/ /*#__PURE__*/ css("ym7uBBu8") : /*YAK Extracted CSS:
+:global(.ym7uBBu9) {
+  color: var(--ym7uBBuA);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--ym7uBBuA": ()=>b
+        }
+    }, "ym7uBBu9")) ? /*YAK Extracted CSS:
+:global(.ym7uBBuB) {
+  color: red;
+}
+*/ "ym7uBBuB" : void 0}/>;
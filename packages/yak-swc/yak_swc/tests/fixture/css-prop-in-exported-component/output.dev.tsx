@@ -1,10 +1,10 @@
-import { css, __yak_mergeCssProp } from "next-yak/internal";
+import { css } from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
-export const YakLogo = ()=><div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+export const YakLogo = ()=><div className={/*YAK Extracted CSS:
 :global(.input_YakLogo_m7uBBu) {
   display: flex;
   gap: 6px;
 }
-*/ /*#__PURE__*/ css("input_YakLogo_m7uBBu"))}>
+*/ "input_YakLogo_m7uBBu"}>
     Yak
   </div>;
@@ -1,20 +1,20 @@
-import { css, __yak_mergeCssProp } from "next-yak/internal";
+import { css } from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
-<div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+<div className={/*YAK Extracted CSS:
 :global(.input_yak_m7uBBu) {
   color: red;
 }
-*/ /*#__PURE__*/ css("input_yak_m7uBBu"))}>
-  <p {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+*/ "input_yak_m7uBBu"}>
+  <p className={/*YAK Extracted CSS:
 :global(.input_yak_m7uBBu-01) {
   color: blue;
 }
-*/ /*#__PURE__*/ css("input_yak_m7uBBu-01"))}>
-    <span {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+*/ "input_yak_m7uBBu-01"}>
+    <span className={/*YAK Extracted CSS:
 :global(.input_yak_m7uBBu-02) {
   color: green;
 }
-*/ /*#__PURE__*/ css("input_yak_m7uBBu-02"))}>
+*/ "input_yak_m7uBBu-02"}>
       hello
     </span>
     world
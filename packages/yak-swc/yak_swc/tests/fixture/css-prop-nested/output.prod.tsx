@@ -1,20 +1,20 @@
-import { css, __yak_mergeCssProp } from "next-yak/internal";
+import { css } from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
-<div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+<div className={/*YAK Extracted CSS:
 :global(.ym7uBBu) {
   color: red;
 }
-*/ /*#__PURE__*/ css("ym7uBBu"))}>
-  <p {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+*/ "ym7uBBu"}>
+  <p className={/*YAK Extracted CSS:
 :global(.ym7uBBu1) {
   color: blue;
 }
-*/ /*#__PURE__*/ css("ym7uBBu1"))}>
-    <span {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+*/ "ym7uBBu1"}>
+    <span className={/*YAK Extracted CSS:
 :global(.ym7uBBu2) {
   color: green;
 }
-*/ /*#__PURE__*/ css("ym7uBBu2"))}>
+*/ "ym7uBBu2"}>
       hello
     </span>
     world
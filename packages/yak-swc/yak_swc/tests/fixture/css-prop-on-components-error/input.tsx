@@ -0,0 +1,27 @@
+import { css } from "next-yak";
+// @ts-ignore
+import { Button, Icon, Layout } from "./components";
+
+const Elem = () => (
+  <Button
+    css={css`
+      color: red;
+    `}
+  />
+);
+
+const Elem2 = () => (
+  <Icon
+    css={css`
+      color: blue;
+    `}
+  />
+);
+
+const Elem3 = () => (
+  <Layout.Header
+    css={css`
+      color: green;
+    `}
+  />
+);
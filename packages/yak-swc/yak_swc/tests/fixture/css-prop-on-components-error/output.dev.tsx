@@ -0,0 +1,19 @@
+import { css } from "next-yak/internal";
+// @ts-ignore
+import { Button, Icon, Layout } from "./components";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ()=><Button className={/*YAK Extracted CSS:
+:global(.input_Elem_m7uBBu) {
+  color: red;
+}
+*/ "input_Elem_m7uBBu"}/>;
+const Elem2 = ()=><Icon className={/*YAK Extracted CSS:
+:global(.input_Elem2_m7uBBu) {
+  color: blue;
+}
+*/ "input_Elem2_m7uBBu"}/>;
+const Elem3 = ()=><Layout.Header className={/*YAK Extracted CSS:
+:global(.input_Elem3_m7uBBu) {
+  color: green;
+}
+*/ "input_Elem3_m7uBBu"}/>;
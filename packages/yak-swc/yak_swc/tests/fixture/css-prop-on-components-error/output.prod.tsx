@@ -0,0 +1,19 @@
+import { css } from "next-yak/internal";
+// @ts-ignore
+import { Button, Icon, Layout } from "./components";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ()=><Button className={/*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ "ym7uBBu"}/>;
+const Elem2 = ()=><Icon className={/*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: blue;
+}
+*/ "ym7uBBu1"}/>;
+const Elem3 = ()=><Layout.Header className={/*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: green;
+}
+*/ "ym7uBBu2"}/>;
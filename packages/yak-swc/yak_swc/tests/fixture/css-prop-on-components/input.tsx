@@ -0,0 +1,28 @@
+import { css } from "next-yak";
+// @ts-ignore
+import { Button, Layout } from "./components";
+
+const Elem = () => (
+  <Button
+    css={css`
+      color: red;
+    `}
+  />
+);
+
+const Elem2 = () => (
+  <Layout.Header
+    className="header"
+    css={css`
+      color: blue;
+    `}
+  />
+);
+
+const Elem3 = () => (
+  <div
+    css={css`
+      color: green;
+    `}
+  />
+);
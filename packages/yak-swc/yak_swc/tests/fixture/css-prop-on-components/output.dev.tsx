@@ -0,0 +1,21 @@
+import { css, __yak_mergeCssProp } from "next-yak/internal";
+// @ts-ignore
+import { Button, Layout } from "./components";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ()=><Button {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+:global(.input_Elem_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ css("input_Elem_m7uBBu"), "Button")}/>;
+const Elem2 = ()=><Layout.Header {...__yak_mergeCssProp({
+        className: "header"
+    }, /*YAK Extracted CSS:
+:global(.input_Elem2_m7uBBu) {
+  color: blue;
+}
+*/ /*#__PURE__*/ css("input_Elem2_m7uBBu"), "Layout.Header")}/>;
+const Elem3 = ()=><div className={/*YAK Extracted CSS:
+:global(.input_Elem3_m7uBBu) {
+  color: green;
+}
+*/ "input_Elem3_m7uBBu"}/>;
@@ -0,0 +1,21 @@
+import { css, __yak_mergeCssProp } from "next-yak/internal";
+// @ts-ignore
+import { Button, Layout } from "./components";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const Elem = ()=><Button {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ css("ym7uBBu"), "Button")}/>;
+const Elem2 = ()=><Layout.Header {...__yak_mergeCssProp({
+        className: "header"
+    }, /*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: blue;
+}
+*/ /*#__PURE__*/ css("ym7uBBu1"), "Layout.Header")}/>;
+const Elem3 = ()=><div className={/*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: green;
+}
+*/ "ym7uBBu2"}/>;
@@ -1,11 +1,11 @@
 import { css, styled, __yak_mergeCssProp } from "next-yak/internal";
 import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
-const Elem = ()=><div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+const Elem = ()=><div className={/*YAK Extracted CSS:
 :global(.input_Elem_m7uBBu) {
   color: red;
 }
-*/ /*#__PURE__*/ css("input_Elem_m7uBBu"))}/>;
+*/ "input_Elem_m7uBBu"}/>;
 const Elem2 = ()=><div {...__yak_mergeCssProp({
         className: "test-class"
     }, /*YAK Extracted CSS:
@@ -58,10 +58,10 @@ const Text = /*YAK Extracted CSS:
 */ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_p("input_Text_m7uBBu"), {
     "displayName": "Text"
 });
-const StyledComponentWithCSSProp = ()=><Text {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+const StyledComponentWithCSSProp = ()=><Text className={/*YAK Extracted CSS:
 :global(.input_StyledComponentWithCSSProp_m7uBBu) {
   color: red;
 }
-*/ /*#__PURE__*/ css("input_StyledComponentWithCSSProp_m7uBBu"))}>
+*/ "input_StyledComponentWithCSSProp_m7uBBu"}>
     test
   </Text>;
@@ -1,11 +1,11 @@
 import { css, styled, __yak_mergeCssProp } from "next-yak/internal";
 import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
-const Elem = ()=><div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+const Elem = ()=><div className={/*YAK Extracted CSS:
 :global(.ym7uBBu) {
   color: red;
 }
-*/ /*#__PURE__*/ css("ym7uBBu"))}/>;
+*/ "ym7uBBu"}/>;
 const Elem2 = ()=><div {...__yak_mergeCssProp({
         className: "test-class"
     }, /*YAK Extracted CSS:
@@ -56,10 +56,10 @@ const Text = /*YAK Extracted CSS:
   font-size: 20px;
 }
 */ /*#__PURE__*/ __yak.__yak_p("ym7uBBu7");
-const StyledComponentWithCSSProp = ()=><Text {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+const StyledComponentWithCSSProp = ()=><Text className={/*YAK Extracted CSS:
 :global(.ym7uBBu8) {
   color: red;
 }
-*/ /*#__PURE__*/ css("ym7uBBu8"))}>
+*/ "ym7uBBu8"}>
     test
   </Text>;
@@ -0,0 +1,36 @@
+import { styled, css } from "next-yak";
+
+const gapMixin = css<{ $gap: number }>`
+  column-gap: ${({ $gap }) => $gap}px;
+`;
+
+export const Grid = styled.div<{ $gap: number; $wide: boolean }>`
+  gap: ${({ $gap }) => $gap}px;
+  padding: ${({ $gap }) => $gap}px ${({ $gap }) => $gap * 2}px;
+  margin: ${({ $gap }) => $gap}%;
+  ${gapMixin};
+  ${({ $wide }) =>
+    $wide &&
+    css`
+      row-gap: ${({ $gap }) => $gap}px;
+      min-height: ${({ $gap }) => $gap}px;
+    `}
+`;
+
+let size = 4;
+export const resize = (next: number) => {
+  size = next;
+};
+
+export const Stack = styled.div<{ $scale: number; $dense: boolean }>`
+  gap: ${() => size}px;
+  ${({ $scale, $dense }) => {
+    const size = $scale * 2;
+    return (
+      $dense &&
+      css`
+        margin: ${() => size}px;
+      `
+    );
+  }}
+`;
@@ -0,0 +1,53 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const gapMixin = /*#__PURE__*/ css({
+    "style": {
+        "--input_gapMixin__column-gap_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap, "px")
+    }
+});
+export const Grid = /*YAK EXPORTED STYLED:Grid:input_Grid_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Grid_m7uBBu) {
+  gap: var(--input_Grid__gap_m7uBBu);
+  padding: var(--input_Grid__gap_m7uBBu) var(--input_Grid__padding_m7uBBu);
+  margin: var(--input_Grid__margin_m7uBBu);
+  column-gap: var(--input_Grid__gap_m7uBBu);
+}
+:global(.input_Grid__\$wide_m7uBBu) {
+  row-gap: var(--input_Grid__gap_m7uBBu);
+  min-height: var(--input_Grid__gap_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Grid_m7uBBu", ({ $wide })=>$wide && /*#__PURE__*/ css("input_Grid__$wide_m7uBBu"), {
+    "style": {
+        "--input_Grid__gap_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap, "px"),
+        "--input_Grid__margin_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap, "%"),
+        "--input_Grid__padding_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap * 2, "px")
+    }
+}), {
+    "displayName": "Grid"
+});
+let size = 4;
+export const resize = (next: number)=>{
+    size = next;
+};
+export const Stack = /*YAK EXPORTED STYLED:Stack:input_Stack_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Stack_m7uBBu) {
+  gap: var(--input_Stack__gap_m7uBBu);
+}
+:global(.input_Stack__\$dense_m7uBBu) {
+  margin: var(--input_Stack__margin_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Stack_m7uBBu", ({ $scale, $dense })=>{
+    const size = $scale * 2;
+    return $dense && /*#__PURE__*/ css("input_Stack__$dense_m7uBBu", {
+        "style": {
+            "--input_Stack__margin_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>size, "px")
+        }
+    });
+}, {
+    "style": {
+        "--input_Stack__gap_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>size, "px")
+    }
+}), {
+    "displayName": "Stack"
+});
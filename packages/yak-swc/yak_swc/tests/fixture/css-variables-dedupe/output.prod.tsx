@@ -0,0 +1,49 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const gapMixin = /*#__PURE__*/ css({
+    "style": {
+        "--ym7uBBu1": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap, "px")
+    }
+});
+export const Grid = /*YAK EXPORTED STYLED:Grid:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  gap: var(--ym7uBBu3);
+  padding: var(--ym7uBBu3) var(--ym7uBBu4);
+  margin: var(--ym7uBBu5);
+  column-gap: var(--ym7uBBu3);
+}
+:global(.ym7uBBu6) {
+  row-gap: var(--ym7uBBu3);
+  min-height: var(--ym7uBBu3);
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu2", ({ $wide })=>$wide && /*#__PURE__*/ css("ym7uBBu6"), {
+    "style": {
+        "--ym7uBBu3": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap, "px"),
+        "--ym7uBBu4": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap * 2, "px"),
+        "--ym7uBBu5": /*#__PURE__*/ __yak_unitPostFix(({ $gap })=>$gap, "%")
+    }
+});
+let size = 4;
+export const resize = (next: number)=>{
+    size = next;
+};
+export const Stack = /*YAK EXPORTED STYLED:Stack:ym7uBBu7*//*YAK Extracted CSS:
+:global(.ym7uBBu7) {
+  gap: var(--ym7uBBu8);
+}
+:global(.ym7uBBu9) {
+  margin: var(--ym7uBBuA);
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu7", ({ $scale, $dense })=>{
+    const size = $scale * 2;
+    return $dense && /*#__PURE__*/ css("ym7uBBu9", {
+        "style": {
+            "--ym7uBBuA": /*#__PURE__*/ __yak_unitPostFix(()=>size, "px")
+        }
+    });
+}, {
+    "style": {
+        "--ym7uBBu8": /*#__PURE__*/ __yak_unitPostFix(()=>size, "px")
+    }
+});
@@ -0,0 +1,29 @@
+import { styled, css, keyframes } from "next-yak";
+
+export const Title = styled.h1`
+  font-size: 2rem;
+  animation: ${fadeIn} 1s;
+`;
+
+export const Button = styled.button`
+  color: ${({ $color }) => $color};
+  ${({ $primary }) =>
+    $primary &&
+    css`
+      background: blue;
+    `}
+`;
+
+const fadeIn = keyframes`
+  from { opacity: 0; }
+  to { opacity: 1; }
+`;
+
+export const Link = styled.a`
+  color: ${({ $color }) => $color};
+  ${({ $primary }) =>
+    $primary &&
+    css`
+      background: blue;
+    `}
+`;
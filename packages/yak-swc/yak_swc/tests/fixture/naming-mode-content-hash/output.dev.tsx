@@ -0,0 +1,49 @@
+import { styled, css, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Title = /*YAK EXPORTED STYLED:Title:input_Title_iqd00L*//*YAK Extracted CSS:
+:global(.input_Title_iqd00L) {
+  font-size: 2rem;
+  animation: global(fadeIn_NbOMnr) 1s;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_h1("input_Title_iqd00L"), {
+    "displayName": "Title"
+});
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_ZiuVfr*//*YAK Extracted CSS:
+:global(.input_Button_ZiuVfr) {
+  color: var(--input_Button__color_cj7tJS);
+}
+:global(.input_Button__\$primary_dxLRt9) {
+  background: blue;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_ZiuVfr", ({ $primary })=>$primary && /*#__PURE__*/ css("input_Button__$primary_dxLRt9"), {
+    "style": {
+        "--input_Button__color_cj7tJS": ({ $color })=>$color
+    }
+}), {
+    "displayName": "Button"
+});
+const fadeIn = /*YAK Extracted CSS:
+@keyframes :global(fadeIn_NbOMnr) {
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+}
+*/ /*#__PURE__*/ keyframes("fadeIn_NbOMnr");
+export const Link = /*YAK EXPORTED STYLED:Link:input_Link_xJXyHe*//*YAK Extracted CSS:
+:global(.input_Link_xJXyHe) {
+  color: var(--input_Link__color_cZrFZ6);
+}
+:global(.input_Link__\$primary_ZTsSxS) {
+  background: blue;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_a("input_Link_xJXyHe", ({ $primary })=>$primary && /*#__PURE__*/ css("input_Link__$primary_ZTsSxS"), {
+    "style": {
+        "--input_Link__color_cZrFZ6": ({ $color })=>$color
+    }
+}), {
+    "displayName": "Link"
+});
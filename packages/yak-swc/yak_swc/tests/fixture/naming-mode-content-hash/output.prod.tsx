@@ -0,0 +1,43 @@
+import { styled, css, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Title = /*YAK EXPORTED STYLED:Title:yiqd00L*//*YAK Extracted CSS:
+:global(.yiqd00L) {
+  font-size: 2rem;
+  animation: global(yNbOMnr) 1s;
+}
+*/ /*#__PURE__*/ __yak.__yak_h1("yiqd00L");
+export const Button = /*YAK EXPORTED STYLED:Button:yZiuVfr*//*YAK Extracted CSS:
+:global(.yZiuVfr) {
+  color: var(--ycj7tJS);
+}
+:global(.ydxLRt9) {
+  background: blue;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("yZiuVfr", ({ $primary })=>$primary && /*#__PURE__*/ css("ydxLRt9"), {
+    "style": {
+        "--ycj7tJS": ({ $color })=>$color
+    }
+});
+const fadeIn = /*YAK Extracted CSS:
+@keyframes :global(yNbOMnr) {
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+}
+*/ /*#__PURE__*/ keyframes("yNbOMnr");
+export const Link = /*YAK EXPORTED STYLED:Link:yxJXyHe*//*YAK Extracted CSS:
+:global(.yxJXyHe) {
+  color: var(--ycZrFZ6);
+}
+:global(.yZTsSxS) {
+  background: blue;
+}
+*/ /*#__PURE__*/ __yak.__yak_a("yxJXyHe", ({ $primary })=>$primary && /*#__PURE__*/ css("yZTsSxS"), {
+    "style": {
+        "--ycZrFZ6": ({ $color })=>$color
+    }
+});
@@ -0,0 +1,32 @@
+import { styled, css } from "next-yak";
+import { getSpacing } from "./spacing";
+
+const flags = {
+  compact: true,
+};
+
+function getGap(compact: boolean) {
+  return compact ? 4 : 8;
+}
+
+export const Card = styled.div<{ $active?: boolean }>`
+  padding: ${() => getSpacing(flags.compact ? 1 : 2)}px;
+  gap: ${() => getGap(flags.compact)}px;
+  ${() =>
+    flags.compact &&
+    css`
+      font-size: 12px;
+    `}
+  ${({ $active }) =>
+    $active &&
+    css`
+      color: red;
+    `}
+`;
+
+export const Banner = styled.div`
+  width: ${() => window.innerWidth}px;
+  height: ${() => bannerHeight}px;
+`;
+
+const bannerHeight = 100;
@@ -0,0 +1,43 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import { getSpacing } from "./spacing";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const flags = {
+    compact: true
+};
+function getGap(compact: boolean) {
+    return compact ? 4 : 8;
+}
+export const Card = /*YAK EXPORTED STYLED:Card:input_Card_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Card_m7uBBu) {
+  padding: var(--input_Card__padding_m7uBBu);
+  gap: var(--input_Card__gap_m7uBBu);
+}
+:global(.input_Card__flags_compact_m7uBBu) {
+  font-size: 12px;
+}
+:global(.input_Card__\$active_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Card_m7uBBu", flags.compact && /*#__PURE__*/ css("input_Card__flags_compact_m7uBBu"), ({ $active })=>$active && /*#__PURE__*/ css("input_Card__$active_m7uBBu"), {
+    "style": {
+        "--input_Card__gap_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(getGap(flags.compact), "px"),
+        "--input_Card__padding_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>getSpacing(flags.compact ? 1 : 2), "px")
+    }
+}), {
+    "displayName": "Card"
+});
+export const Banner = /*YAK EXPORTED STYLED:Banner:input_Banner_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Banner_m7uBBu) {
+  width: var(--input_Banner__width_m7uBBu);
+  height: var(--input_Banner__height_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Banner_m7uBBu", {
+    "style": {
+        "--input_Banner__height_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>bannerHeight, "px"),
+        "--input_Banner__width_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>window.innerWidth, "px")
+    }
+}), {
+    "displayName": "Banner"
+});
+const bannerHeight = 100;
@@ -0,0 +1,39 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import { getSpacing } from "./spacing";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const flags = {
+    compact: true
+};
+function getGap(compact: boolean) {
+    return compact ? 4 : 8;
+}
+export const Card = /*YAK EXPORTED STYLED:Card:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  padding: var(--ym7uBBu1);
+  gap: var(--ym7uBBu2);
+}
+:global(.ym7uBBu3) {
+  font-size: 12px;
+}
+:global(.ym7uBBu4) {
+  color: red;
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu", flags.compact && /*#__PURE__*/ css("ym7uBBu3"), ({ $active })=>$active && /*#__PURE__*/ css("ym7uBBu4"), {
+    "style": {
+        "--ym7uBBu1": /*#__PURE__*/ __yak_unitPostFix(()=>getSpacing(flags.compact ? 1 : 2), "px"),
+        "--ym7uBBu2": /*#__PURE__*/ __yak_unitPostFix(getGap(flags.compact), "px")
+    }
+});
+export const Banner = /*YAK EXPORTED STYLED:Banner:ym7uBBu5*//*YAK Extracted CSS:
+:global(.ym7uBBu5) {
+  width: var(--ym7uBBu6);
+  height: var(--ym7uBBu7);
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu5", {
+    "style": {
+        "--ym7uBBu6": /*#__PURE__*/ __yak_unitPostFix(()=>window.innerWidth, "px"),
+        "--ym7uBBu7": /*#__PURE__*/ __yak_unitPostFix(()=>bannerHeight, "px")
+    }
+});
+const bannerHeight = 100;
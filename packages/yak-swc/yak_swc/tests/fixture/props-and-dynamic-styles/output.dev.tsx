@@ -1,14 +1,14 @@
-import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import { styled, css, __yak_unitPostFix, __yak_staticFallback } from "next-yak/internal";
 import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
 export const FlexContainer = /*YAK EXPORTED STYLED:FlexContainer:input_FlexContainer_m7uBBu*//*YAK Extracted CSS:
 :global(.input_FlexContainer_m7uBBu) {
   display: flex;
-  align-items: var(--input_FlexContainer__align-items_m7uBBu);
-  flex-direction: var(--input_FlexContainer__flex-direction_m7uBBu);
-  justify-content: var(--input_FlexContainer__justify-content_m7uBBu);
+  align-items: var(--input_FlexContainer__align-items_m7uBBu, stretch);
+  flex-direction: var(--input_FlexContainer__flex-direction_m7uBBu, row);
+  justify-content: var(--input_FlexContainer__justify-content_m7uBBu, flex-start);
   padding: 20px;
-  margin-bottom: var(--input_FlexContainer__margin-bottom_m7uBBu);
+  margin-bottom: var(--input_FlexContainer__margin-bottom_m7uBBu, 0px);
   top: var(--input_FlexContainer__top_m7uBBu);
   background-color: #f0f0f0;
 }
@@ -21,10 +21,10 @@ export const FlexContainer = /*YAK EXPORTED STYLED:FlexContainer:input_FlexConta
         }
     }), {
     "style": {
-        "--input_FlexContainer__align-items_m7uBBu": ({ $align })=>$align || 'stretch',
-        "--input_FlexContainer__flex-direction_m7uBBu": ({ $direction })=>$direction || 'row',
-        "--input_FlexContainer__justify-content_m7uBBu": ({ $justify })=>$justify || 'flex-start',
-        "--input_FlexContainer__margin-bottom_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $marginBottom })=>$marginBottom || '0', "px"),
+        "--input_FlexContainer__align-items_m7uBBu": /*#__PURE__*/ __yak_staticFallback(({ $align })=>$align || undefined),
+        "--input_FlexContainer__flex-direction_m7uBBu": /*#__PURE__*/ __yak_staticFallback(({ $direction })=>$direction || undefined),
+        "--input_FlexContainer__justify-content_m7uBBu": /*#__PURE__*/ __yak_staticFallback(({ $justify })=>$justify || undefined),
+        "--input_FlexContainer__margin-bottom_m7uBBu": /*#__PURE__*/ __yak_staticFallback(/*#__PURE__*/ __yak_unitPostFix(({ $marginBottom })=>$marginBottom || undefined, "px")),
         "--input_FlexContainer__top_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $top })=>$top * 20, "%")
     }
 }), {
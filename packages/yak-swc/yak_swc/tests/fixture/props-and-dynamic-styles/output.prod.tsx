@@ -1,14 +1,14 @@
-import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import { styled, css, __yak_unitPostFix, __yak_staticFallback } from "next-yak/internal";
 import * as __yak from "next-yak/internal";
 import "./input.yak.module.css!=!./input?./input.yak.module.css";
 export const FlexContainer = /*YAK EXPORTED STYLED:FlexContainer:ym7uBBu*//*YAK Extracted CSS:
 :global(.ym7uBBu) {
   display: flex;
-  align-items: var(--ym7uBBu1);
-  flex-direction: var(--ym7uBBu2);
-  justify-content: var(--ym7uBBu3);
+  align-items: var(--ym7uBBu1, stretch);
+  flex-direction: var(--ym7uBBu2, row);
+  justify-content: var(--ym7uBBu3, flex-start);
   padding: 20px;
-  margin-bottom: var(--ym7uBBu4);
+  margin-bottom: var(--ym7uBBu4, 0px);
   top: var(--ym7uBBu5);
   background-color: #f0f0f0;
 }
@@ -21,10 +21,10 @@ export const FlexContainer = /*YAK EXPORTED STYLED:FlexContainer:ym7uBBu*//*YAK
         }
     }), {
     "style": {
-        "--ym7uBBu1": ({ $align })=>$align || 'stretch',
-        "--ym7uBBu2": ({ $direction })=>$direction || 'row',
-        "--ym7uBBu3": ({ $justify })=>$justify || 'flex-start',
-        "--ym7uBBu4": /*#__PURE__*/ __yak_unitPostFix(({ $marginBottom })=>$marginBottom || '0', "px"),
+        "--ym7uBBu1": /*#__PURE__*/ __yak_staticFallback(({ $align })=>$align || undefined),
+        "--ym7uBBu2": /*#__PURE__*/ __yak_staticFallback(({ $direction })=>$direction || undefined),
+        "--ym7uBBu3": /*#__PURE__*/ __yak_staticFallback(({ $justify })=>$justify || undefined),
+        "--ym7uBBu4": /*#__PURE__*/ __yak_staticFallback(/*#__PURE__*/ __yak_unitPostFix(({ $marginBottom })=>$marginBottom || undefined, "px")),
         "--ym7uBBu5": /*#__PURE__*/ __yak_unitPostFix(({ $top })=>$top * 20, "%")
     }
 });
@@ -0,0 +1,19 @@
+import { styled } from "next-yak";
+
+const theme = {
+  primary: "hotpink",
+};
+
+const spacing = 8;
+
+export const Button = styled.button<{
+  $color?: string;
+  $background?: string;
+  $padding?: number;
+  $border?: string;
+}>`
+  color: ${({ $color }) => $color ?? "red"};
+  background: ${({ $background }) => $background || theme.primary};
+  padding: ${({ $padding }) => $padding ?? spacing}px;
+  border-color: ${({ $border, $color }) => $border ?? $color};
+`;
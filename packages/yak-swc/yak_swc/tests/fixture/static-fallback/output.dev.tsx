@@ -0,0 +1,24 @@
+import { styled, __yak_unitPostFix, __yak_staticFallback } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const theme = {
+    primary: "hotpink"
+};
+const spacing = 8;
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  color: var(--input_Button__color_m7uBBu, red);
+  background: var(--input_Button__background_m7uBBu, hotpink);
+  padding: var(--input_Button__padding_m7uBBu, 8px);
+  border-color: var(--input_Button__border-color_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", {
+    "style": {
+        "--input_Button__background_m7uBBu": /*#__PURE__*/ __yak_staticFallback(({ $background })=>$background || undefined),
+        "--input_Button__border-color_m7uBBu": ({ $border, $color })=>$border ?? $color,
+        "--input_Button__color_m7uBBu": /*#__PURE__*/ __yak_staticFallback(({ $color })=>$color),
+        "--input_Button__padding_m7uBBu": /*#__PURE__*/ __yak_staticFallback(/*#__PURE__*/ __yak_unitPostFix(({ $padding })=>$padding, "px"))
+    }
+}), {
+    "displayName": "Button"
+});
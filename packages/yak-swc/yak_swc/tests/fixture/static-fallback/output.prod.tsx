@@ -0,0 +1,22 @@
+import { styled, __yak_unitPostFix, __yak_staticFallback } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const theme = {
+    primary: "hotpink"
+};
+const spacing = 8;
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: var(--ym7uBBu1, red);
+  background: var(--ym7uBBu2, hotpink);
+  padding: var(--ym7uBBu3, 8px);
+  border-color: var(--ym7uBBu4);
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu", {
+    "style": {
+        "--ym7uBBu1": /*#__PURE__*/ __yak_staticFallback(({ $color })=>$color),
+        "--ym7uBBu2": /*#__PURE__*/ __yak_staticFallback(({ $background })=>$background || undefined),
+        "--ym7uBBu3": /*#__PURE__*/ __yak_staticFallback(/*#__PURE__*/ __yak_unitPostFix(({ $padding })=>$padding, "px")),
+        "--ym7uBBu4": ({ $border, $color })=>$border ?? $color
+    }
+});
@@ -0,0 +1,25 @@
+import { styled } from "next-yak";
+
+export const List = styled.ul`
+  ${Item}:hover & {
+    background: #eee;
+  }
+`;
+
+const Item = styled.li`
+  color: red;
+  ${Item} + & {
+    margin-top: 4px;
+  }
+`;
+
+const Parts = {
+  Label: styled.span`
+    ${Parts.Hint} + & {
+      font-weight: bold;
+    }
+  `,
+  Hint: styled.small`
+    color: gray;
+  `,
+};
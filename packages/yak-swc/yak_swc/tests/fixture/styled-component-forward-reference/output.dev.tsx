@@ -0,0 +1,40 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const List = /*YAK EXPORTED STYLED:List:input_List_m7uBBu*//*YAK Extracted CSS:
+:global(.input_List_m7uBBu) {
+  :global(.input_Item_m7uBBu):hover & {
+    background: #eee;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_ul("input_List_m7uBBu"), {
+    "displayName": "List"
+});
+const Item = /*YAK Extracted CSS:
+:global(.input_Item_m7uBBu) {
+  color: red;
+  :global(.input_Item_m7uBBu) + & {
+    margin-top: 4px;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_li("input_Item_m7uBBu"), {
+    "displayName": "Item"
+});
+const Parts = {
+    Label: /*YAK Extracted CSS:
+:global(.input_Parts_Label_m7uBBu) {
+  :global(.input_Parts_Hint_m7uBBu) + & {
+    font-weight: bold;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_span("input_Parts_Label_m7uBBu"), {
+        "displayName": "Label"
+    }),
+    Hint: /*YAK Extracted CSS:
+:global(.input_Parts_Hint_m7uBBu) {
+  color: gray;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_small("input_Parts_Hint_m7uBBu"), {
+        "displayName": "Hint"
+    })
+};
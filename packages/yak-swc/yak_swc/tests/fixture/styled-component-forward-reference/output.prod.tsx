@@ -0,0 +1,32 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const List = /*YAK EXPORTED STYLED:List:ym7uBBu3*//*YAK Extracted CSS:
+:global(.ym7uBBu3) {
+  :global(.ym7uBBu):hover & {
+    background: #eee;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_ul("ym7uBBu3");
+const Item = /*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+  :global(.ym7uBBu) + & {
+    margin-top: 4px;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_li("ym7uBBu");
+const Parts = {
+    Label: /*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  :global(.ym7uBBu2) + & {
+    font-weight: bold;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_span("ym7uBBu1"),
+    Hint: /*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: gray;
+}
+*/ /*#__PURE__*/ __yak.__yak_small("ym7uBBu2")
+};
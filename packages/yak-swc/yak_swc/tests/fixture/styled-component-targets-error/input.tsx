@@ -0,0 +1,23 @@
+import { styled } from "next-yak";
+// @ts-ignore
+import { Button } from "./button";
+
+export const Missing = styled()`
+  color: red;
+`;
+
+export const TooMany = styled(Button, Button)`
+  color: red;
+`;
+
+export const Element = styled(<Button />)`
+  color: red;
+`;
+
+export const Number = styled(42)`
+  color: red;
+`;
+
+export const Undefined = styled(undefined)`
+  color: red;
+`;
@@ -0,0 +1,39 @@
+import { styled } from "next-yak/internal";
+// @ts-ignore
+import { Button } from "./button";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Missing = /*YAK EXPORTED STYLED:Missing:input_Missing_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Missing_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled()("input_Missing_m7uBBu"), {
+    "displayName": "Missing"
+});
+export const TooMany = /*YAK EXPORTED STYLED:TooMany:input_TooMany_m7uBBu*//*YAK Extracted CSS:
+:global(.input_TooMany_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(Button, Button)("input_TooMany_m7uBBu"), {
+    "displayName": "TooMany"
+});
+export const Element = /*YAK EXPORTED STYLED:Element:input_Element_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Element_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(<Button/>)("input_Element_m7uBBu"), {
+    "displayName": "Element"
+});
+export const Number = /*YAK EXPORTED STYLED:Number:input_Number_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Number_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(42)("input_Number_m7uBBu"), {
+    "displayName": "Number"
+});
+export const Undefined = /*YAK EXPORTED STYLED:Undefined:input_Undefined_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Undefined_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(undefined)("input_Undefined_m7uBBu"), {
+    "displayName": "Undefined"
+});
@@ -0,0 +1,29 @@
+import { styled } from "next-yak/internal";
+// @ts-ignore
+import { Button } from "./button";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Missing = /*YAK EXPORTED STYLED:Missing:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ styled()("ym7uBBu");
+export const TooMany = /*YAK EXPORTED STYLED:TooMany:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: red;
+}
+*/ /*#__PURE__*/ styled(Button, Button)("ym7uBBu1");
+export const Element = /*YAK EXPORTED STYLED:Element:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: red;
+}
+*/ /*#__PURE__*/ styled(<Button/>)("ym7uBBu2");
+export const Number = /*YAK EXPORTED STYLED:Number:ym7uBBu3*//*YAK Extracted CSS:
+:global(.ym7uBBu3) {
+  color: red;
+}
+*/ /*#__PURE__*/ styled(42)("ym7uBBu3");
+export const Undefined = /*YAK EXPORTED STYLED:Undefined:ym7uBBu4*//*YAK Extracted CSS:
+:global(.ym7uBBu4) {
+  color: red;
+}
+*/ /*#__PURE__*/ styled(undefined)("ym7uBBu4");
@@ -0,0 +1,44 @@
+import { styled, css } from "next-yak";
+import { forwardRef, lazy, memo } from "react";
+// @ts-ignore
+import { Layout } from "./layout";
+
+export const Header = styled(Layout.Header)`
+  color: red;
+`;
+
+export const Footer = styled(Layout["Footer"]).attrs({ role: "contentinfo" })`
+  color: blue;
+`;
+
+export const Input = styled(
+  forwardRef<HTMLInputElement, any>((props, ref) => (
+    <input
+      ref={ref}
+      css={css`
+        outline: none;
+      `}
+      {...props}
+    />
+  )),
+)`
+  border: 1px solid black;
+`;
+
+export const Icon = styled(lazy(() => import("./Icon")))`
+  width: 16px;
+`;
+
+const Inline = styled((props: any) => <span {...props} />)`
+  display: inline-block;
+`;
+
+export const Memo = styled(memo(Inline))`
+  display: block;
+`;
+
+export function createButton(Component: any) {
+  return styled(memo(Component))`
+    cursor: pointer;
+  `;
+}
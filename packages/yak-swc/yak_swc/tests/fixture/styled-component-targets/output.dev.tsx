@@ -0,0 +1,67 @@
+import { styled, css, __yak_mergeCssProp } from "next-yak/internal";
+import { forwardRef, lazy, memo } from "react";
+// @ts-ignore
+import { Layout } from "./layout";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Header = /*YAK EXPORTED STYLED:Header:input_Header_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Header_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(Layout.Header)("input_Header_m7uBBu"), {
+    "displayName": "Header"
+});
+export const Footer = /*YAK EXPORTED STYLED:Footer:input_Footer_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Footer_m7uBBu) {
+  color: blue;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(Layout["Footer"]).attrs({
+    role: "contentinfo"
+})("input_Footer_m7uBBu"), {
+    "displayName": "Footer"
+});
+const __yak_Input_target = /*#__PURE__*/ forwardRef<HTMLInputElement, any>((props, ref)=><input ref={ref} {...__yak_mergeCssProp({
+        ...props
+    }, /*YAK Extracted CSS:
+:global(.input_Input_m7uBBu) {
+  outline: none;
+}
+*/ /*#__PURE__*/ css("input_Input_m7uBBu"))}/>);
+export const Input = /*YAK EXPORTED STYLED:Input:input_Input_m7uBBu-01*//*YAK Extracted CSS:
+:global(.input_Input_m7uBBu-01) {
+  border: 1px solid black;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(__yak_Input_target)("input_Input_m7uBBu-01"), {
+    "displayName": "Input"
+});
+const __yak_Icon_target = /*#__PURE__*/ lazy(()=>import("./Icon"));
+export const Icon = /*YAK EXPORTED STYLED:Icon:input_Icon_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Icon_m7uBBu) {
+  width: 16px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(__yak_Icon_target)("input_Icon_m7uBBu"), {
+    "displayName": "Icon"
+});
+const Inline = /*YAK Extracted CSS:
+:global(.input_Inline_m7uBBu) {
+  display: inline-block;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled((props: any)=><span {...props}/>)("input_Inline_m7uBBu"), {
+    "displayName": "Inline"
+});
+const __yak_Memo_target = /*#__PURE__*/ memo(Inline);
+export const Memo = /*YAK EXPORTED STYLED:Memo:input_Memo_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Memo_m7uBBu) {
+  display: block;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(__yak_Memo_target)("input_Memo_m7uBBu"), {
+    "displayName": "Memo"
+});
+export function createButton(Component: any) {
+    return(/*YAK EXPORTED STYLED:yak:input_yak_m7uBBu*//*YAK Extracted CSS:
+:global(.input_yak_m7uBBu) {
+  cursor: pointer;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled(/*#__PURE__*/ memo(Component))("input_yak_m7uBBu"), {
+        "displayName": "yak"
+    }));
+}
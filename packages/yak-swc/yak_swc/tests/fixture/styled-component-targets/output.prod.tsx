@@ -0,0 +1,53 @@
+import { styled, css, __yak_mergeCssProp } from "next-yak/internal";
+import { forwardRef, lazy, memo } from "react";
+// @ts-ignore
+import { Layout } from "./layout";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Header = /*YAK EXPORTED STYLED:Header:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ styled(Layout.Header)("ym7uBBu");
+export const Footer = /*YAK EXPORTED STYLED:Footer:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: blue;
+}
+*/ /*#__PURE__*/ styled(Layout["Footer"]).attrs({
+    role: "contentinfo"
+})("ym7uBBu1");
+const __yak_Input_target = /*#__PURE__*/ forwardRef<HTMLInputElement, any>((props, ref)=><input ref={ref} {...__yak_mergeCssProp({
+        ...props
+    }, /*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  outline: none;
+}
+*/ /*#__PURE__*/ css("ym7uBBu2"))}/>);
+export const Input = /*YAK EXPORTED STYLED:Input:ym7uBBu3*//*YAK Extracted CSS:
+:global(.ym7uBBu3) {
+  border: 1px solid black;
+}
+*/ /*#__PURE__*/ styled(__yak_Input_target)("ym7uBBu3");
+const __yak_Icon_target = /*#__PURE__*/ lazy(()=>import("./Icon"));
+export const Icon = /*YAK EXPORTED STYLED:Icon:ym7uBBu4*//*YAK Extracted CSS:
+:global(.ym7uBBu4) {
+  width: 16px;
+}
+*/ /*#__PURE__*/ styled(__yak_Icon_target)("ym7uBBu4");
+const Inline = /*YAK Extracted CSS:
+:global(.ym7uBBu5) {
+  display: inline-block;
+}
+*/ /*#__PURE__*/ styled((props: any)=><span {...props}/>)("ym7uBBu5");
+const __yak_Memo_target = /*#__PURE__*/ memo(Inline);
+export const Memo = /*YAK EXPORTED STYLED:Memo:ym7uBBu6*//*YAK Extracted CSS:
+:global(.ym7uBBu6) {
+  display: block;
+}
+*/ /*#__PURE__*/ styled(__yak_Memo_target)("ym7uBBu6");
+export function createButton(Component: any) {
+    return(/*YAK EXPORTED STYLED:yak:ym7uBBu7*//*YAK Extracted CSS:
+:global(.ym7uBBu7) {
+  cursor: pointer;
+}
+*/ /*#__PURE__*/ styled(/*#__PURE__*/ memo(Component))("ym7uBBu7"));
+}
@@ -0,0 +1,13 @@
+import { styled } from "next-yak";
+
+export const Widget = styled["my-widget"]`
+  display: block;
+`;
+
+export const View = styled.View`
+  display: flex;
+`;
+
+export const Text = styled.Text`
+  color: blue;
+`;
@@ -0,0 +1,25 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+const __yak_my_widget = /*#__PURE__*/ styled("my-widget");
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Widget = /*YAK EXPORTED STYLED:Widget:input_Widget_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Widget_m7uBBu) {
+  display: block;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak_my_widget("input_Widget_m7uBBu"), {
+    "displayName": "Widget"
+});
+export const View = /*YAK EXPORTED STYLED:View:input_View_m7uBBu*//*YAK Extracted CSS:
+:global(.input_View_m7uBBu) {
+  display: flex;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ styled("View")("input_View_m7uBBu"), {
+    "displayName": "View"
+});
+export const Text = /*YAK EXPORTED STYLED:Text:input_Text_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Text_m7uBBu) {
+  color: blue;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_span("input_Text_m7uBBu"), {
+    "displayName": "Text"
+});
@@ -0,0 +1,19 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+const __yak_my_widget = /*#__PURE__*/ styled("my-widget");
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Widget = /*YAK EXPORTED STYLED:Widget:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  display: block;
+}
+*/ /*#__PURE__*/ __yak_my_widget("ym7uBBu");
+export const View = /*YAK EXPORTED STYLED:View:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  display: flex;
+}
+*/ /*#__PURE__*/ styled("View")("ym7uBBu1");
+export const Text = /*YAK EXPORTED STYLED:Text:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: blue;
+}
+*/ /*#__PURE__*/ __yak.__yak_span("ym7uBBu2");
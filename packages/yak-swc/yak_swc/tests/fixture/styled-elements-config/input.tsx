@@ -0,0 +1,23 @@
+import { styled } from "next-yak";
+
+export const Widget = styled["my-widget"]`
+  display: block;
+`;
+
+export const OtherWidget = styled("my-widget")`
+  display: inline-block;
+`;
+
+export const Blur = styled.feGaussianBlur`
+  color: red;
+`;
+
+export const View = styled.View`
+  display: flex;
+`;
+
+export const Button = styled.button`
+  color: blue;
+`;
+
+export const __yak_my_widget = "user code";
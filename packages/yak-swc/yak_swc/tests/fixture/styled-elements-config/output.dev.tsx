@@ -0,0 +1,42 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+const __yak_my_widget = /*#__PURE__*/ styled("my-widget");
+const __yak_feGaussianBlur = /*#__PURE__*/ styled("feGaussianBlur");
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Widget = /*YAK EXPORTED STYLED:Widget:input_Widget_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Widget_m7uBBu) {
+  display: block;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak_my_widget("input_Widget_m7uBBu"), {
+    "displayName": "Widget"
+});
+export const OtherWidget = /*YAK EXPORTED STYLED:OtherWidget:input_OtherWidget_m7uBBu*//*YAK Extracted CSS:
+:global(.input_OtherWidget_m7uBBu) {
+  display: inline-block;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak_my_widget("input_OtherWidget_m7uBBu"), {
+    "displayName": "OtherWidget"
+});
+export const Blur = /*YAK EXPORTED STYLED:Blur:input_Blur_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Blur_m7uBBu) {
+  color: red;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak_feGaussianBlur("input_Blur_m7uBBu"), {
+    "displayName": "Blur"
+});
+export const View = /*YAK EXPORTED STYLED:View:input_View_m7uBBu*//*YAK Extracted CSS:
+:global(.input_View_m7uBBu) {
+  display: flex;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_View_m7uBBu"), {
+    "displayName": "View"
+});
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  color: blue;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+    "displayName": "Button"
+});
+const __yak_my_widget1 = "user code";
+export { __yak_my_widget1 as __yak_my_widget };
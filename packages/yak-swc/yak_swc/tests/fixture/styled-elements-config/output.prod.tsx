@@ -0,0 +1,32 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+const __yak_my_widget = /*#__PURE__*/ styled("my-widget");
+const __yak_feGaussianBlur = /*#__PURE__*/ styled("feGaussianBlur");
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Widget = /*YAK EXPORTED STYLED:Widget:ym7uBBu*//*YAK Extracted CSS:
+:global(.ym7uBBu) {
+  display: block;
+}
+*/ /*#__PURE__*/ __yak_my_widget("ym7uBBu");
+export const OtherWidget = /*YAK EXPORTED STYLED:OtherWidget:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  display: inline-block;
+}
+*/ /*#__PURE__*/ __yak_my_widget("ym7uBBu1");
+export const Blur = /*YAK EXPORTED STYLED:Blur:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  color: red;
+}
+*/ /*#__PURE__*/ __yak_feGaussianBlur("ym7uBBu2");
+export const View = /*YAK EXPORTED STYLED:View:ym7uBBu3*//*YAK Extracted CSS:
+:global(.ym7uBBu3) {
+  display: flex;
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu3");
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu4*//*YAK Extracted CSS:
+:global(.ym7uBBu4) {
+  color: blue;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu4");
+const __yak_my_widget1 = "user code";
+export { __yak_my_widget1 as __yak_my_widget };
@@ -0,0 +1,23 @@
+import { styled, css } from "next-yak";
+
+const red = css`
+  color: red;
+`;
+
+export const Title = styled.h1`
+  color: red;
+  color: blue;
+  ${red};
+  font-weight: bold;
+`;
+
+export const Box = styled.div`
+  display: -webkit-box;
+  display: flex;
+  &:hover {
+    opacity: 0.5;
+    opacity: 0.8;
+  }
+  padding: 4px;
+  padding: 4px;
+`;
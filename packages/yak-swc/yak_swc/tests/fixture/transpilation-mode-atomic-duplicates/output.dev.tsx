@@ -0,0 +1,31 @@
+import { styled, css } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const red = /*#__PURE__*/ css();
+export const Title = /*YAK EXPORTED STYLED:Title:input_Title_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Title_m7uBBu) {
+  color: red;
+  color: blue;
+  color: red;
+}
+:global(.font-weight_KN89T4) {
+  font-weight: bold;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_h1("input_Title_m7uBBu font-weight_KN89T4"), {
+    "displayName": "Title"
+});
+export const Box = /*YAK EXPORTED STYLED:Box:input_Box_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Box_m7uBBu) {
+  display: -webkit-box;
+  display: flex;
+  &:hover {
+    opacity: 0.5;
+    opacity: 0.8;
+  }
+}
+:global(.padding_mTD58w) {
+  padding: 4px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Box_m7uBBu padding_mTD58w"), {
+    "displayName": "Box"
+});
@@ -0,0 +1,27 @@
+import { styled, css } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const red = /*#__PURE__*/ css();
+export const Title = /*YAK EXPORTED STYLED:Title:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  color: red;
+  color: blue;
+  color: red;
+}
+:global(.yKN89T4) {
+  font-weight: bold;
+}
+*/ /*#__PURE__*/ __yak.__yak_h1("ym7uBBu1 yKN89T4");
+export const Box = /*YAK EXPORTED STYLED:Box:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  display: -webkit-box;
+  display: flex;
+  &:hover {
+    opacity: 0.5;
+    opacity: 0.8;
+  }
+}
+:global(.ymTD58w) {
+  padding: 4px;
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu2 ymTD58w");
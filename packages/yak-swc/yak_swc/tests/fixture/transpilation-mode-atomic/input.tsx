@@ -0,0 +1,34 @@
+import { styled, css } from "next-yak";
+
+const centered = css`
+  display: flex;
+  align-items: center;
+`;
+
+export const Button = styled.button`
+  ${centered};
+  color: red;
+  padding: 4px 8px;
+  &:hover {
+    color: blue;
+  }
+  & + & {
+    margin-left: 4px;
+  }
+  ${({ $primary }) =>
+    $primary &&
+    css`
+      color: white;
+    `}
+`;
+
+export const Link = styled.a`
+  display: flex;
+  color: red;
+  @media (min-width: 640px) {
+    padding: 4px 8px;
+  }
+  ${Button}:hover & {
+    color: blue;
+  }
+`;
@@ -0,0 +1,48 @@
+import { styled, css } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const centered = /*#__PURE__*/ css();
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.display_smUVCw) {
+  display: flex;
+}
+:global(.align-items_gLvP18) {
+  align-items: center;
+}
+:global(.input_Button_m7uBBu) {
+  color: red;
+}
+:global(.padding_aKpXoY) {
+  padding: 4px 8px;
+}
+:global(.input_Button_m7uBBu) {
+  &:hover {
+    color: blue;
+  }
+  & + & {
+    margin-left: 4px;
+  }
+}
+:global(.input_Button__\$primary_m7uBBu) {
+  color: white;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu display_smUVCw align-items_gLvP18 padding_aKpXoY", ({ $primary })=>$primary && /*#__PURE__*/ css("input_Button__$primary_m7uBBu")), {
+    "displayName": "Button"
+});
+export const Link = /*YAK EXPORTED STYLED:Link:input_Link_m7uBBu*//*YAK Extracted CSS:
+:global(.color_jelEhj) {
+  color: red;
+}
+:global(.padding_SwU5ay) {
+  @media (min-width: 640px) {
+    padding: 4px 8px;
+  }
+}
+:global(.color_QfJJQw) {
+  :global(.input_Button_m7uBBu):hover & {
+    color: blue;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_a("input_Link_m7uBBu display_smUVCw color_jelEhj padding_SwU5ay color_QfJJQw"), {
+    "displayName": "Link"
+});
@@ -0,0 +1,44 @@
+import { styled, css } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const centered = /*#__PURE__*/ css();
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ysmUVCw) {
+  display: flex;
+}
+:global(.ygLvP18) {
+  align-items: center;
+}
+:global(.ym7uBBu1) {
+  color: red;
+}
+:global(.yaKpXoY) {
+  padding: 4px 8px;
+}
+:global(.ym7uBBu1) {
+  &:hover {
+    color: blue;
+  }
+  & + & {
+    margin-left: 4px;
+  }
+}
+:global(.ym7uBBu2) {
+  color: white;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu1 ysmUVCw ygLvP18 yaKpXoY", ({ $primary })=>$primary && /*#__PURE__*/ css("ym7uBBu2"));
+export const Link = /*YAK EXPORTED STYLED:Link:ym7uBBu3*//*YAK Extracted CSS:
+:global(.yjelEhj) {
+  color: red;
+}
+:global(.ySwU5ay) {
+  @media (min-width: 640px) {
+    padding: 4px 8px;
+  }
+}
+:global(.ysWeIOX) {
+  :global(.ym7uBBu1):hover & {
+    color: blue;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_a("ym7uBBu3 ysmUVCw yjelEhj ySwU5ay ysWeIOX");
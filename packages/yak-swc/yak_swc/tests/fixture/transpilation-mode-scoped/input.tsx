@@ -0,0 +1,24 @@
+import { styled, keyframes } from "next-yak";
+
+const fadeIn = keyframes`
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+`;
+
+export const Button = styled.button`
+  color: red;
+  animation: ${fadeIn} 1s;
+  &:hover {
+    color: blue;
+  }
+`;
+
+export const Icon = styled.svg`
+  ${Button}:hover & {
+    fill: currentColor;
+  }
+`;
@@ -0,0 +1,33 @@
+import { styled, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.css!=!./input?./input.yak.css";
+const fadeIn = /*YAK Extracted CSS:
+@keyframes yak-scope_fadeIn_m7uBBu {
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+}
+*/ /*#__PURE__*/ keyframes("yak-scope_fadeIn_m7uBBu");
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+.input_Button_m7uBBu:where([data-yak-scope], [data-yak-scope] *) {
+  color: red;
+  animation: yak-scope_fadeIn_m7uBBu 1s;
+  &:hover {
+    color: blue;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu"), {
+    "displayName": "Button"
+});
+export const Icon = /*YAK EXPORTED STYLED:Icon:input_Icon_m7uBBu*//*YAK Extracted CSS:
+.input_Icon_m7uBBu:where([data-yak-scope], [data-yak-scope] *) {
+  .input_Button_m7uBBu:where([data-yak-scope], [data-yak-scope] *):hover & {
+    fill: currentColor;
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_svg("input_Icon_m7uBBu"), {
+    "displayName": "Icon"
+});
@@ -0,0 +1,29 @@
+import { styled, keyframes } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.css!=!./input?./input.yak.css";
+const fadeIn = /*YAK Extracted CSS:
+@keyframes yak-scope_ym7uBBu {
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+}
+*/ /*#__PURE__*/ keyframes("yak-scope_ym7uBBu");
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu1*//*YAK Extracted CSS:
+.ym7uBBu1:where([data-yak-scope], [data-yak-scope] *) {
+  color: red;
+  animation: yak-scope_ym7uBBu 1s;
+  &:hover {
+    color: blue;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu1");
+export const Icon = /*YAK EXPORTED STYLED:Icon:ym7uBBu2*//*YAK Extracted CSS:
+.ym7uBBu2:where([data-yak-scope], [data-yak-scope] *) {
+  .ym7uBBu1:where([data-yak-scope], [data-yak-scope] *):hover & {
+    fill: currentColor;
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_svg("ym7uBBu2");
@@ -0,0 +1,14 @@
+import { styled } from "next-yak";
+
+export const Card = styled.div<{
+  $color?: string;
+  $opacity?: number | string;
+}>`
+  opacity: ${({ $opacity }) => $opacity};
+  & > span {
+    color: ${({ $color }) => $color};
+  }
+  @media (min-width: 640px) {
+    background-color: ${({ $color }) => $color};
+  }
+`;
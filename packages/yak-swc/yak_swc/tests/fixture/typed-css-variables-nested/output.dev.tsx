@@ -0,0 +1,34 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Card = /*YAK EXPORTED STYLED:Card:input_Card_m7uBBu*//*YAK Extracted CSS:
+@property --input_Card__opacity_m7uBBu {
+  syntax: "<number> | <percentage>";
+  inherits: true;
+  initial-value: 1;
+}
+
+@property --input_Card__background-color_m7uBBu {
+  syntax: "<color>";
+  inherits: true;
+  initial-value: transparent;
+}
+
+:global(.input_Card_m7uBBu) {
+  opacity: var(--input_Card__opacity_m7uBBu);
+  & > span {
+    color: var(--input_Card__color_m7uBBu);
+  }
+  @media (min-width: 640px) {
+    background-color: var(--input_Card__background-color_m7uBBu);
+  }
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Card_m7uBBu", {
+    "style": {
+        "--input_Card__background-color_m7uBBu": ({ $color })=>$color,
+        "--input_Card__color_m7uBBu": ({ $color })=>$color,
+        "--input_Card__opacity_m7uBBu": ({ $opacity })=>$opacity
+    }
+}), {
+    "displayName": "Card"
+});
@@ -0,0 +1,32 @@
+import { styled } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Card = /*YAK EXPORTED STYLED:Card:ym7uBBu*//*YAK Extracted CSS:
+@property --ym7uBBu1 {
+  syntax: "<number> | <percentage>";
+  inherits: true;
+  initial-value: 1;
+}
+
+@property --ym7uBBu3 {
+  syntax: "<color>";
+  inherits: true;
+  initial-value: transparent;
+}
+
+:global(.ym7uBBu) {
+  opacity: var(--ym7uBBu1);
+  & > span {
+    color: var(--ym7uBBu2);
+  }
+  @media (min-width: 640px) {
+    background-color: var(--ym7uBBu3);
+  }
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu", {
+    "style": {
+        "--ym7uBBu1": ({ $opacity })=>$opacity,
+        "--ym7uBBu2": ({ $color })=>$color,
+        "--ym7uBBu3": ({ $color })=>$color
+    }
+});
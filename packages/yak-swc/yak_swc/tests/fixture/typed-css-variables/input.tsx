@@ -0,0 +1,20 @@
+import { styled, css } from "next-yak";
+
+export const Slider = styled.div<{
+  $x: number;
+  $color: string;
+  $opacity: number;
+  $rotate: number;
+}>`
+  left: ${({ $x }) => $x}px;
+  color: ${({ $color }) => $color};
+  opacity: ${({ $opacity }) => $opacity};
+  transform: rotate(${({ $rotate }) => $rotate}deg);
+  transition: left 0.3s, color 0.3s;
+  ${({ $x }) =>
+    $x > 100 &&
+    css`
+      width: ${() => $x / 2}%;
+    `}
+  border: 1px solid ${({ $color }) => $color};
+`;
@@ -0,0 +1,62 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Slider = /*YAK EXPORTED STYLED:Slider:input_Slider_m7uBBu*//*YAK Extracted CSS:
+@property --input_Slider__left_m7uBBu {
+  syntax: "<length>";
+  inherits: true;
+  initial-value: 0px;
+}
+
+@property --input_Slider__color_m7uBBu {
+  syntax: "<color>";
+  inherits: true;
+  initial-value: transparent;
+}
+
+@property --input_Slider__opacity_m7uBBu {
+  syntax: "<number> | <percentage>";
+  inherits: true;
+  initial-value: 1;
+}
+
+@property --input_Slider__transform_m7uBBu {
+  syntax: "<angle>";
+  inherits: true;
+  initial-value: 0deg;
+}
+
+@property --input_Slider__width_m7uBBu {
+  syntax: "<percentage>";
+  inherits: true;
+  initial-value: 0%;
+}
+
+:global(.input_Slider_m7uBBu) {
+  left: var(--input_Slider__left_m7uBBu);
+  color: var(--input_Slider__color_m7uBBu);
+  opacity: var(--input_Slider__opacity_m7uBBu);
+  transform: rotate(var(--input_Slider__transform_m7uBBu));
+  transition: left 0.3s, color 0.3s;
+}
+:global(.input_Slider___m7uBBu) {
+  width: var(--input_Slider__width_m7uBBu);
+}
+:global(.input_Slider_m7uBBu) {
+  border: 1px solid var(--input_Slider__border_m7uBBu);
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_div("input_Slider_m7uBBu", ({ $x })=>$x > 100 && /*#__PURE__*/ css("input_Slider___m7uBBu", {
+        "style": {
+            "--input_Slider__width_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>$x / 2, "%")
+        }
+    }), {
+    "style": {
+        "--input_Slider__border_m7uBBu": ({ $color })=>$color,
+        "--input_Slider__color_m7uBBu": ({ $color })=>$color,
+        "--input_Slider__left_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $x })=>$x, "px"),
+        "--input_Slider__opacity_m7uBBu": ({ $opacity })=>$opacity,
+        "--input_Slider__transform_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $rotate })=>$rotate, "deg")
+    }
+}), {
+    "displayName": "Slider"
+});
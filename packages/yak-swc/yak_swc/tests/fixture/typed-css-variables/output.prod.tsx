@@ -0,0 +1,60 @@
+import { styled, css, __yak_unitPostFix } from "next-yak/internal";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+export const Slider = /*YAK EXPORTED STYLED:Slider:ym7uBBu*//*YAK Extracted CSS:
+@property --ym7uBBu1 {
+  syntax: "<length>";
+  inherits: true;
+  initial-value: 0px;
+}
+
+@property --ym7uBBu2 {
+  syntax: "<color>";
+  inherits: true;
+  initial-value: transparent;
+}
+
+@property --ym7uBBu3 {
+  syntax: "<number> | <percentage>";
+  inherits: true;
+  initial-value: 1;
+}
+
+@property --ym7uBBu4 {
+  syntax: "<angle>";
+  inherits: true;
+  initial-value: 0deg;
+}
+
+@property --ym7uBBu6 {
+  syntax: "<percentage>";
+  inherits: true;
+  initial-value: 0%;
+}
+
+:global(.ym7uBBu) {
+  left: var(--ym7uBBu1);
+  color: var(--ym7uBBu2);
+  opacity: var(--ym7uBBu3);
+  transform: rotate(var(--ym7uBBu4));
+  transition: left 0.3s, color 0.3s;
+}
+:global(.ym7uBBu5) {
+  width: var(--ym7uBBu6);
+}
+:global(.ym7uBBu) {
+  border: 1px solid var(--ym7uBBu7);
+}
+*/ /*#__PURE__*/ __yak.__yak_div("ym7uBBu", ({ $x })=>$x > 100 && /*#__PURE__*/ css("ym7uBBu5", {
+        "style": {
+            "--ym7uBBu6": /*#__PURE__*/ __yak_unitPostFix(()=>$x / 2, "%")
+        }
+    }), {
+    "style": {
+        "--ym7uBBu1": /*#__PURE__*/ __yak_unitPostFix(({ $x })=>$x, "px"),
+        "--ym7uBBu2": ({ $color })=>$color,
+        "--ym7uBBu3": ({ $opacity })=>$opacity,
+        "--ym7uBBu4": /*#__PURE__*/ __yak_unitPostFix(({ $rotate })=>$rotate, "deg"),
+        "--ym7uBBu7": ({ $color })=>$color
+    }
+});
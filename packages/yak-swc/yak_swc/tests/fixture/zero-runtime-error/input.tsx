@@ -0,0 +1,31 @@
+import { styled, css } from "next-yak";
+import { fonts } from "./fonts";
+
+const spacing = 4;
+
+const highlight = css`
+  color: orange;
+`;
+
+export const Title = styled.h1`
+  ${fonts.h1};
+  ${highlight};
+  margin: ${spacing}px;
+`;
+
+export const Button = styled.button<{ $primary: boolean; $size: number }>`
+  padding: ${({ $size }) => $size}px;
+  ${({ $primary }) =>
+    $primary &&
+    css`
+      color: white;
+    `}
+`;
+
+export const Card = () => (
+  <div
+    css={css`
+      width: ${() => window.innerWidth}px;
+    `}
+  />
+);
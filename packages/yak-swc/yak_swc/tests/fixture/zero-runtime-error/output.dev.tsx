@@ -0,0 +1,38 @@
+import { styled, css, __yak_unitPostFix, __yak_mergeCssProp } from "next-yak/internal";
+import { fonts } from "./fonts";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const spacing = 4;
+const highlight = /*#__PURE__*/ css();
+export const Title = /*YAK EXPORTED STYLED:Title:input_Title_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Title_m7uBBu) {
+  --yak-css-import: url("./fonts:fonts:h1",static-mixin);
+  color: orange;
+  margin: 4px;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_h1("input_Title_m7uBBu"), {
+    "displayName": "Title"
+});
+export const Button = /*YAK EXPORTED STYLED:Button:input_Button_m7uBBu*//*YAK Extracted CSS:
+:global(.input_Button_m7uBBu) {
+  padding: var(--input_Button__padding_m7uBBu);
+}
+:global(.input_Button__\$primary_m7uBBu) {
+  color: white;
+}
+*/ /*#__PURE__*/ Object.assign(/*#__PURE__*/ __yak.__yak_button("input_Button_m7uBBu", ({ $primary })=>$primary && /*#__PURE__*/ css("input_Button__$primary_m7uBBu"), {
+    "style": {
+        "--input_Button__padding_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(({ $size })=>$size, "px")
+    }
+}), {
+    "displayName": "Button"
+});
+export const Card = ()=><div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+:global(.input_Card_m7uBBu) {
+  width: var(--input_Card__width_m7uBBu);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--input_Card__width_m7uBBu": /*#__PURE__*/ __yak_unitPostFix(()=>window.innerWidth, "px")
+        }
+    }, "input_Card_m7uBBu"))}/>;
@@ -0,0 +1,34 @@
+import { styled, css, __yak_unitPostFix, __yak_mergeCssProp } from "next-yak/internal";
+import { fonts } from "./fonts";
+import * as __yak from "next-yak/internal";
+import "./input.yak.module.css!=!./input?./input.yak.module.css";
+const spacing = 4;
+const highlight = /*#__PURE__*/ css();
+export const Title = /*YAK EXPORTED STYLED:Title:ym7uBBu1*//*YAK Extracted CSS:
+:global(.ym7uBBu1) {
+  --yak-css-import: url("./fonts:fonts:h1",static-mixin);
+  color: orange;
+  margin: 4px;
+}
+*/ /*#__PURE__*/ __yak.__yak_h1("ym7uBBu1");
+export const Button = /*YAK EXPORTED STYLED:Button:ym7uBBu2*//*YAK Extracted CSS:
+:global(.ym7uBBu2) {
+  padding: var(--ym7uBBu3);
+}
+:global(.ym7uBBu4) {
+  color: white;
+}
+*/ /*#__PURE__*/ __yak.__yak_button("ym7uBBu2", ({ $primary })=>$primary && /*#__PURE__*/ css("ym7uBBu4"), {
+    "style": {
+        "--ym7uBBu3": /*#__PURE__*/ __yak_unitPostFix(({ $size })=>$size, "px")
+    }
+});
+export const Card = ()=><div {...__yak_mergeCssProp({}, /*YAK Extracted CSS:
+:global(.ym7uBBu5) {
+  width: var(--ym7uBBu6);
+}
+*/ /*#__PURE__*/ css({
+        "style": {
+            "--ym7uBBu6": /*#__PURE__*/ __yak_unitPostFix(()=>window.innerWidth, "px")
+        }
+    }, "ym7uBBu5"))}/>;